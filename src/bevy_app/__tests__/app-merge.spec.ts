@@ -0,0 +1,149 @@
+/**
+ * @fileoverview App 合并测试
+ */
+
+import { App } from "../app";
+import { MergeConflict } from "../app-merge";
+import { BuiltinSchedules } from "../main-schedule";
+import { BasePlugin } from "../plugin";
+import type { World } from "@rbxts/matter";
+import type { Context } from "../../bevy_ecs";
+import { intoSystemConfigs } from "../../bevy_ecs/schedule/system-configs";
+import { advanceTime, GenericTimeResource, TimePlugin } from "../../bevy_time";
+
+/**
+ * 战斗模块的资源
+ */
+class CombatConfig {
+	readonly __brand = "Resource" as const;
+	constructor(public readonly damage: number) {}
+}
+
+/**
+ * 背包模块的资源
+ */
+class InventoryConfig {
+	readonly __brand = "Resource" as const;
+	constructor(public readonly slots: number) {}
+}
+
+/**
+ * 插入 CombatConfig 的测试插件
+ */
+class CombatPlugin extends BasePlugin {
+	build(app: App): void {
+		app.insertResource(new CombatConfig(10));
+	}
+
+	name(): string {
+		return "CombatPlugin";
+	}
+}
+
+export = () => {
+	describe("App.merge", () => {
+		let runs: Map<string, number>;
+
+		const createSystem = (name: string) => {
+			return (world: World, context: Context) => {
+				runs.set(name, (runs.get(name) ?? 0) + 1);
+			};
+		};
+
+		beforeEach(() => {
+			runs = new Map();
+		});
+
+		it("合并后两个 App 的系统都应运行，资源和插件都应存在", () => {
+			const app = App.create();
+			app.insertResource(new InventoryConfig(20));
+			app.addSystems(BuiltinSchedules.UPDATE, createSystem("inventory"));
+
+			const combat = App.create().addPlugin(new CombatPlugin());
+			combat.addSystems(BuiltinSchedules.STARTUP, createSystem("combatStartup"));
+			combat.addSystems(BuiltinSchedules.UPDATE, createSystem("combat"));
+
+			expect(app.merge(combat)).to.equal(undefined);
+
+			app.update();
+			app.update();
+			app.update();
+
+			expect(runs.get("inventory")).to.equal(3);
+			expect(runs.get("combat")).to.equal(3);
+			// 启动系统只运行一次
+			expect(runs.get("combatStartup")).to.equal(1);
+			expect(app.getResource<InventoryConfig>()!.slots).to.equal(20);
+			expect(app.getResource<CombatConfig>()!.damage).to.equal(10);
+			expect(app.isPluginAdded(CombatPlugin)).to.equal(true);
+		});
+
+		it("两个 App 中都注册的同一系统只应运行一次", () => {
+			const shared = createSystem("shared");
+			const app = App.create();
+			app.addSystems(BuiltinSchedules.UPDATE, shared);
+			const other = App.create();
+			other.addSystems(BuiltinSchedules.UPDATE, shared);
+
+			app.merge(other);
+			app.update();
+
+			expect(runs.get("shared")).to.equal(1);
+		});
+
+		it("两个 App 中都存在的非默认资源应作为冲突返回并保留目标的实例", () => {
+			const app = App.create();
+			app.insertResource(new CombatConfig(1));
+			const other = App.create();
+			other.insertResource(new CombatConfig(99));
+
+			const conflict = app.merge(other);
+
+			expect(conflict instanceof MergeConflict).to.equal(true);
+			expect(conflict!.resources.size()).to.equal(1);
+			expect(app.getResource<CombatConfig>()!.damage).to.equal(1);
+		});
+
+		it("合并带有 TimePlugin 的 App 后目标 App 的时间应推进", () => {
+			const app = App.create();
+			const timed = App.create().addPlugin(new TimePlugin());
+
+			expect(app.merge(timed)).to.equal(undefined);
+			expect(app.isPluginAdded(TimePlugin)).to.equal(true);
+
+			app.update();
+			advanceTime(app, 0.1);
+			app.update();
+
+			const elapsed = app.getResource<GenericTimeResource>()!.value.getElapsedSecs();
+			expect(elapsed).to.be.near(0.1, 0.0001);
+			// 源 App 的时间系统没有被复制，源 App 的时间不受目标 update 影响
+			expect(timed.getResource<GenericTimeResource>()!.value.getElapsedSecs()).to.equal(0);
+		});
+
+		it("目标中已存在但没有排序约束的系统集应接收源 App 的排序约束", () => {
+			const order: string[] = [];
+			const app = App.create();
+			app.editSchedule(BuiltinSchedules.UPDATE, (schedule) => schedule.configureSet({ name: "Movement" }));
+			app.addSystems(BuiltinSchedules.UPDATE, intoSystemConfigs(() => order.push("movement")).inSet("Movement"));
+
+			const other = App.create();
+			other.editSchedule(BuiltinSchedules.UPDATE, (schedule) =>
+				schedule.configureSet({ name: "Movement", after: ["Input"] }),
+			);
+			other.addSystems(BuiltinSchedules.UPDATE, intoSystemConfigs(() => order.push("input")).inSet("Input"));
+
+			app.merge(other);
+			app.update();
+
+			expect(order.join(",")).to.equal("input,movement");
+		});
+
+		it("已经 update 过的 App 不能合并", () => {
+			const app = App.create();
+			app.update();
+
+			expect(() => app.merge(App.create())).to.throw();
+		});
+	});
+};
@@ -0,0 +1,114 @@
+/**
+ * @fileoverview 有界消息测试
+ */
+
+import { App } from "../app";
+import { BoundedMessageStats, DROPPED_MESSAGE_ID, Message, MessageOverflowPolicy } from "../../bevy_ecs/message";
+
+class SpamMessage implements Message {
+	constructor(public readonly value: number) {}
+}
+
+export = () => {
+	describe("Bounded Messages", () => {
+		let app: App;
+
+		beforeEach(() => {
+			app = App.create();
+		});
+
+		function writeSpam(count: number): void {
+			const writer = app.world().world.messages.createWriter<SpamMessage>();
+			for (let index = 0; index < count; index++) {
+				writer.write(new SpamMessage(index));
+			}
+		}
+
+		function readSpam(): SpamMessage[] {
+			const messages = app.world().world.messages.getMessages<SpamMessage>()!;
+			return messages.getCursor().read(messages);
+		}
+
+		it("DropNewest 应只保留前 capacity 条消息并记录丢弃数量", () => {
+			app.addBoundedMessage<SpamMessage>(3, MessageOverflowPolicy.DropNewest);
+
+			writeSpam(5);
+
+			const received = readSpam();
+			expect(received.size()).to.equal(3);
+			expect(received[0].value).to.equal(0);
+			expect(received[2].value).to.equal(2);
+
+			const stats = app.getResource<BoundedMessageStats<SpamMessage>>()!;
+			expect(stats).to.be.ok();
+			expect(stats.getDroppedThisFrame()).to.equal(2);
+			expect(stats.getDroppedTotal()).to.equal(2);
+		});
+
+		it("DropNewest 丢弃的消息应返回 DROPPED_MESSAGE_ID", () => {
+			app.addBoundedMessage<SpamMessage>(1);
+
+			const writer = app.world().world.messages.createWriter<SpamMessage>();
+			expect(writer.write(new SpamMessage(0)).id).never.to.equal(DROPPED_MESSAGE_ID);
+			expect(writer.write(new SpamMessage(1)).id).to.equal(DROPPED_MESSAGE_ID);
+		});
+
+		it("DropOldest 应保留最新的 capacity 条消息", () => {
+			app.addBoundedMessage<SpamMessage>(3, MessageOverflowPolicy.DropOldest);
+
+			writeSpam(5);
+
+			const received = readSpam();
+			expect(received.size()).to.equal(3);
+			expect(received[0].value).to.equal(2);
+			expect(received[1].value).to.equal(3);
+			expect(received[2].value).to.equal(4);
+			expect(app.getResource<BoundedMessageStats<SpamMessage>>()!.getDroppedTotal()).to.equal(2);
+		});
+
+		it("DropOldest 溢出后已读到末尾的读取器应只读到新消息", () => {
+			app.addBoundedMessage<SpamMessage>(3, MessageOverflowPolicy.DropOldest);
+			const messages = app.world().world.messages.getOrCreateMessages<SpamMessage>();
+			const cursor = messages.getCursor();
+
+			writeSpam(3);
+			expect(cursor.read(messages).size()).to.equal(3);
+
+			const writer = app.world().world.messages.createWriter<SpamMessage>();
+			const firstId = writer.write(new SpamMessage(3)).id;
+			const secondId = writer.write(new SpamMessage(4)).id;
+			expect(secondId).to.equal(firstId + 1);
+			expect(cursor.len(messages)).to.equal(2);
+
+			const received = cursor.read(messages);
+			expect(received.size()).to.equal(2);
+			expect(received[0].value).to.equal(3);
+			expect(received[1].value).to.equal(4);
+			expect(cursor.read(messages).size()).to.equal(0);
+
+			// 跨帧后缓冲区不变量仍应成立
+			app.world().world.messages.updateAll();
+			expect(readSpam().size()).to.equal(3);
+		});
+
+		it("Error 策略超出容量时应报错", () => {
+			app.addBoundedMessage<SpamMessage>(2, MessageOverflowPolicy.Error);
+
+			writeSpam(2);
+			expect(() => writeSpam(1)).to.throw();
+		});
+
+		it("容量按帧计算，新的一帧可以继续写入", () => {
+			app.addBoundedMessage<SpamMessage>(2, MessageOverflowPolicy.DropNewest);
+
+			writeSpam(3);
+			app.world().world.messages.updateAll();
+			writeSpam(2);
+
+			const stats = app.getResource<BoundedMessageStats<SpamMessage>>()!;
+			expect(stats.getDroppedThisFrame()).to.equal(0);
+			expect(stats.getDroppedTotal()).to.equal(1);
+			expect(readSpam().size()).to.equal(4);
+		});
+	});
+};
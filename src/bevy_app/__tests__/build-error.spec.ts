@@ -0,0 +1,150 @@
+/**
+ * @fileoverview App 构建错误测试
+ */
+
+import { App } from "../app";
+import { FrameworkBuildErrorKind } from "../build-error";
+import { BuiltinSchedules } from "../main-schedule";
+import { OrderingCycleError } from "../ordering-cycle";
+import { intoSystemConfigs } from "../../bevy_ecs/schedule/system-configs";
+import { BasePlugin, MissingDependencyError, PluginId } from "../plugin";
+import type { World } from "@rbxts/matter";
+import type { Context } from "../../bevy_ecs";
+
+/**
+ * 测试用资源
+ */
+class ConfigResource {
+	readonly __brand = "Resource" as const;
+	constructor(public readonly value: number) {}
+}
+
+/**
+ * 声明依赖的测试插件
+ */
+class DependentPlugin extends BasePlugin {
+	constructor(
+		private readonly pluginName: string,
+		private readonly deps: PluginId[],
+	) {
+		super();
+	}
+
+	build(app: App): void {}
+
+	name(): string {
+		return this.pluginName;
+	}
+
+	dependencies(): PluginId[] {
+		return this.deps;
+	}
+}
+
+export = () => {
+	describe("App tryBuild", () => {
+		let app: App;
+
+		beforeEach(() => {
+			app = App.create();
+		});
+
+		it("配置正确时应返回 undefined", () => {
+			app.addPlugin(new DependentPlugin("A", []));
+			app.addPlugin(new DependentPlugin("B", ["A"]));
+			app.requireResource<ConfigResource>();
+			app.insertResource(new ConfigResource(1));
+
+			expect(app.tryBuild()).to.equal(undefined);
+		});
+
+		it("依赖缺失时应返回 MissingDependency 错误", () => {
+			app.addPlugin(new DependentPlugin("Renderer", ["Window"]));
+
+			const err = app.tryBuild();
+			expect(err).to.be.ok();
+			expect(err!.kind).to.equal(FrameworkBuildErrorKind.MissingDependency);
+			expect(err!.source() instanceof MissingDependencyError).to.equal(true);
+			expect((err!.source() as MissingDependencyError).dependency).to.equal("Window");
+		});
+
+		it("独占资源重复插入时应返回 DuplicateResource 错误且不覆盖", () => {
+			app.insertExclusiveResource(new ConfigResource(1));
+			app.insertExclusiveResource(new ConfigResource(2));
+
+			const err = app.tryBuild();
+			expect(err).to.be.ok();
+			expect(err!.kind).to.equal(FrameworkBuildErrorKind.DuplicateResource);
+			expect(app.getResource<ConfigResource>()!.value).to.equal(1);
+		});
+
+		it("必需资源缺失时应返回 MissingResource 错误", () => {
+			app.requireResource<ConfigResource>();
+
+			const err = app.tryBuild();
+			expect(err).to.be.ok();
+			expect(err!.kind).to.equal(FrameworkBuildErrorKind.MissingResource);
+		});
+
+		it("多个错误应合并为 Multiple", () => {
+			app.addPlugin(new DependentPlugin("Renderer", ["Window"]));
+			app.requireResource<ConfigResource>();
+
+			const err = app.tryBuild();
+			expect(err).to.be.ok();
+			expect(err!.kind).to.equal(FrameworkBuildErrorKind.Multiple);
+			expect(err!.errors.size()).to.equal(2);
+		});
+
+		it("run 在构建失败时应报错", () => {
+			app.addPlugin(new DependentPlugin("Renderer", ["Window"]));
+			expect(() => app.run()).to.throw();
+		});
+
+		it("经由系统集的排序环应在编译前返回 OrderingCycle 错误", () => {
+			const systemA = (world: World, context: Context) => {};
+			const systemB = (world: World, context: Context) => {};
+			app.systemGroup(BuiltinSchedules.UPDATE, "A").after("B").add(systemA);
+			app.systemGroup(BuiltinSchedules.UPDATE, "B").after("A").add(systemB);
+
+			const err = app.tryBuild();
+			expect(err).to.be.ok();
+			expect(err!.kind).to.equal(FrameworkBuildErrorKind.OrderingCycle);
+			expect(err!.source() instanceof OrderingCycleError).to.equal(true);
+		});
+
+		it("OrderingCycle 错误应按顺序列出环上的系统", () => {
+			function physicsStep(world: World, context: Context) {}
+			function applyForces(world: World, context: Context) {}
+			app.addSystems(
+				BuiltinSchedules.UPDATE,
+				intoSystemConfigs(physicsStep).before(applyForces),
+				intoSystemConfigs(applyForces).before(physicsStep),
+			);
+
+			const err = app.tryBuild();
+			expect(err).to.be.ok();
+			expect(err!.kind).to.equal(FrameworkBuildErrorKind.OrderingCycle);
+
+			const cycle = (err!.source() as OrderingCycleError).cycle;
+			expect(cycle.size()).to.equal(3);
+			expect(cycle[0]).to.equal("physicsStep");
+			expect(cycle[1]).to.equal("applyForces");
+			expect(cycle[2]).to.equal("physicsStep");
+			expect(err!.message.find("physicsStep -> applyForces -> physicsStep", 1, true)[0]).to.be.ok();
+			expect(err!.message.find(BuiltinSchedules.UPDATE, 1, true)[0]).to.be.ok();
+		});
+
+		it("确定性模式下与注册顺序矛盾的约束应返回 ScheduleBuildFailed 错误", () => {
+			const first = (world: World, context: Context) => {};
+			const second = (world: World, context: Context) => {};
+			app.deterministicMode();
+			app.addSystems(BuiltinSchedules.UPDATE, first, intoSystemConfigs(second).before(first));
+
+			const err = app.tryBuild();
+			expect(err).to.be.ok();
+			expect(err!.kind).to.equal(FrameworkBuildErrorKind.ScheduleBuildFailed);
+			expect(err!.source()).to.be.ok();
+		});
+	});
+};
@@ -0,0 +1,70 @@
+/**
+ * @fileoverview 系统间通道测试
+ */
+
+import { App } from "../app";
+import { BuiltinSchedules } from "../main-schedule";
+import { ChannelReceiver, ChannelSender } from "../../bevy_ecs/channel";
+import type { World } from "../../bevy_ecs/bevy-world";
+
+/**
+ * 测试用通道值
+ */
+interface PathRequest {
+	readonly id: number;
+}
+
+export = () => {
+	describe("addChannel", () => {
+		it("跨帧发送的值应按顺序被完整消费", () => {
+			const app = App.create().addChannel<PathRequest>();
+
+			let frame = 0;
+			let nextId = 1;
+			const received: number[] = [];
+
+			// 生产者每帧发送 frame 个值
+			app.addSystems(BuiltinSchedules.UPDATE, (world: World) => {
+				const sender = world.resources.getResource<ChannelSender<PathRequest>>()!;
+				for (let index = 0; index < frame; index++) {
+					sender.send({ id: nextId++ });
+				}
+			});
+
+			// 消费者只在偶数帧读取，奇数帧的值应保留到下一次读取
+			app.addSystems(BuiltinSchedules.POST_UPDATE, (world: World) => {
+				if (frame % 2 !== 0) {
+					return;
+				}
+				for (const request of world.resources.getResource<ChannelReceiver<PathRequest>>()!.tryIter()) {
+					received.push(request.id);
+				}
+			});
+
+			for (frame = 1; frame <= 4; frame++) {
+				app.update();
+			}
+
+			const total = 1 + 2 + 3 + 4;
+			expect(received.size()).to.equal(total);
+			for (let index = 0; index < total; index++) {
+				expect(received[index]).to.equal(index + 1);
+			}
+			expect(app.getResource<ChannelReceiver<PathRequest>>()!.isEmpty()).to.equal(true);
+		});
+
+		it("tryRecv 应逐个取出值", () => {
+			const app = App.create().addChannel<PathRequest>();
+			const sender = app.getResource<ChannelSender<PathRequest>>()!;
+			const receiver = app.getResource<ChannelReceiver<PathRequest>>()!;
+
+			sender.send({ id: 1 });
+			sender.send({ id: 2 });
+
+			expect(receiver.len()).to.equal(2);
+			expect(receiver.tryRecv()!.id).to.equal(1);
+			expect(receiver.tryRecv()!.id).to.equal(2);
+			expect(receiver.tryRecv()).to.equal(undefined);
+		});
+	});
+};
@@ -0,0 +1,86 @@
+/**
+ * @fileoverview 启动参数解析测试
+ */
+
+import { App } from "../app";
+import { BasePlugin } from "../plugin";
+import { CliArgs } from "../cli-args";
+
+/**
+ * 在 finish 阶段读取启动参数的测试插件
+ */
+class PortPlugin extends BasePlugin {
+	port?: number;
+
+	build(app: App): void {}
+
+	finish(app: App): void {
+		this.port = app.getResource<CliArgs>()!.getNumber("port");
+	}
+
+	name(): string {
+		return "PortPlugin";
+	}
+}
+
+export = () => {
+	describe("CliArgs", () => {
+		it("withArgs 应替换 CliArgs 资源并支持类型化读取", () => {
+			const app = App.create().withArgs(["--port", "8080", "--name=server", "--verbose", "--ratio", "-0.5"]);
+
+			const args = app.getResource<CliArgs>()!;
+			expect(args).to.be.ok();
+			expect(args.getNumber("port")).to.equal(8080);
+			expect(args.getString("--name")).to.equal("server");
+			expect(args.getNumber("ratio")).to.equal(-0.5);
+			expect(args.flagPresent("verbose")).to.equal(true);
+			expect(args.getBoolean("verbose")).to.equal(true);
+			expect(args.flagPresent("missing")).to.equal(false);
+			expect(args.getNumber("missing")).to.equal(undefined);
+			expect(args.warnings().size()).to.equal(0);
+		});
+
+		it("插件应能在 finish 中读取启动参数", () => {
+			const plugin = new PortPlugin();
+			const app = App.create().withArgs(["--port", "25565"]);
+			app.addPlugin(plugin);
+
+			app.finish();
+
+			expect(plugin.port).to.equal(25565);
+		});
+
+		it("短标志与位置参数应被正确区分", () => {
+			const args = new CliArgs(["map.rbxl", "-dv", "--", "--not-a-flag"]);
+
+			expect(args.flagPresent("d")).to.equal(true);
+			expect(args.flagPresent("v")).to.equal(true);
+			expect(args.flagPresent("not-a-flag")).to.equal(false);
+			expect(args.positional().size()).to.equal(2);
+			expect(args.positional()[0]).to.equal("map.rbxl");
+			expect(args.positional()[1]).to.equal("--not-a-flag");
+		});
+
+		it("格式错误的参数应记录为警告而不是失败", () => {
+			const args = new CliArgs(["---bad", "--=value", "-", "--ok"]);
+
+			expect(args.flagPresent("ok")).to.equal(true);
+			expect(args.warnings().size()).to.equal(3);
+		});
+
+		it("无法解析的值应返回 undefined 并记录警告", () => {
+			const args = new CliArgs(["--port", "abc", "--debug", "maybe"]);
+
+			expect(args.getNumber("port")).to.equal(undefined);
+			expect(args.getBoolean("debug")).to.equal(undefined);
+			expect(args.warnings().size()).to.equal(2);
+		});
+
+		it("重复的参数应使用最后的值并记录警告", () => {
+			const args = new CliArgs(["--level", "1", "--level", "2"]);
+
+			expect(args.getNumber("level")).to.equal(2);
+			expect(args.warnings().size()).to.equal(1);
+		});
+	});
+};
@@ -0,0 +1,69 @@
+/**
+ * @fileoverview 组件生命周期回调测试
+ */
+
+import { AnyEntity, component } from "@rbxts/matter";
+import { App } from "../app";
+
+const Marker = component<{ value: number }>("HookMarker");
+const Tagged = component<{}>("HookTagged");
+
+export = () => {
+	describe("Component Hooks", () => {
+		let app: App;
+
+		beforeEach(() => {
+			app = App.create();
+		});
+
+		it("添加与移除组件时应以正确的实体调用回调", () => {
+			const added: AnyEntity[] = [];
+			const removed: AnyEntity[] = [];
+			app.onComponentAdded(Marker, (entity) => added.push(entity));
+			app.onComponentRemoved(Marker, (entity) => removed.push(entity));
+
+			// 首次运行让检测系统开始跟踪
+			app.update();
+
+			const world = app.getWorld();
+			const entity = world.spawn(Marker({ value: 1 }));
+			app.update();
+
+			expect(added.size()).to.equal(1);
+			expect(added[0]).to.equal(entity);
+			expect(removed.size()).to.equal(0);
+
+			world.remove(entity, Marker);
+			app.update();
+
+			expect(removed.size()).to.equal(1);
+			expect(removed[0]).to.equal(entity);
+			expect(added.size()).to.equal(1);
+		});
+
+		it("修改组件值不应触发添加回调", () => {
+			let addedCount = 0;
+			app.onComponentAdded(Marker, () => addedCount++);
+			app.update();
+
+			const world = app.getWorld();
+			const entity = world.spawn(Marker({ value: 1 }));
+			app.update();
+			world.insert(entity, Marker({ value: 2 }));
+			app.update();
+
+			expect(addedCount).to.equal(1);
+		});
+
+		it("回调中的命令应在本帧执行", () => {
+			app.onComponentAdded(Marker, (entity, commands) => commands.addComponent(entity, Tagged({})));
+			app.update();
+
+			const world = app.getWorld();
+			const entity = world.spawn(Marker({ value: 1 }));
+			app.update();
+
+			expect(world.get(entity, Tagged)).to.be.ok();
+		});
+	});
+};
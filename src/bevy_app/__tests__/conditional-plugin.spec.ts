@@ -0,0 +1,103 @@
+/**
+ * @fileoverview 条件插件加载测试
+ */
+
+import { App } from "../app";
+import { BuildEnv } from "../build-env";
+import { BuiltinSchedules } from "../main-schedule";
+import { BasePlugin } from "../plugin";
+import { SystemRegistry } from "../system-registry";
+import type { World } from "@rbxts/matter";
+import type { Context } from "../../bevy_ecs";
+
+/**
+ * 测试用资源
+ */
+class ConditionalResource {
+	readonly __brand = "Resource" as const;
+}
+
+/**
+ * 注册一个系统和一个资源的测试插件
+ */
+class ConditionalPlugin extends BasePlugin {
+	public buildCalled = false;
+
+	build(app: App): void {
+		this.buildCalled = true;
+		app.insertResource(new ConditionalResource());
+		app.addSystems(BuiltinSchedules.UPDATE, conditionalSystem);
+	}
+
+	name(): string {
+		return "ConditionalPlugin";
+	}
+}
+
+function conditionalSystem(world: World, context: Context) {}
+
+export = () => {
+	describe("Conditional Plugin Loading", () => {
+		let app: App;
+
+		beforeEach(() => {
+			app = App.create();
+		});
+
+		describe("addPluginIf", () => {
+			it("条件为 true 时应构建插件", () => {
+				const plugin = new ConditionalPlugin();
+				app.addPluginIf(true, plugin);
+
+				expect(plugin.buildCalled).to.equal(true);
+				expect(app.getResource<ConditionalResource>()).to.be.ok();
+				expect(app.getResource<SystemRegistry>()!.find(BuiltinSchedules.UPDATE, conditionalSystem)).to.be.ok();
+			});
+
+			it("条件为 false 时不应注册任何系统或资源", () => {
+				const plugin = new ConditionalPlugin();
+				app.addPluginIf(false, plugin);
+
+				expect(plugin.buildCalled).to.equal(false);
+				expect(app.isPluginAdded(ConditionalPlugin)).to.equal(false);
+				expect(app.getResource<ConditionalResource>()).to.equal(undefined);
+				expect(app.getResource<SystemRegistry>()!.systemsIn(BuiltinSchedules.UPDATE).size()).to.equal(0);
+			});
+		});
+
+		describe("addPluginWith", () => {
+			it("工厂返回插件时应构建插件", () => {
+				app.setBuildEnv(new BuildEnv({ args: ["--conditional"] }));
+
+				const plugin = new ConditionalPlugin();
+				app.addPluginWith((env) => (env.hasFlag("--conditional") ? plugin : undefined));
+
+				expect(plugin.buildCalled).to.equal(true);
+				expect(app.getResource<SystemRegistry>()!.find(BuiltinSchedules.UPDATE, conditionalSystem)).to.be.ok();
+			});
+
+			it("工厂返回 undefined 时不应注册任何系统或资源", () => {
+				const plugin = new ConditionalPlugin();
+				app.addPluginWith((env) => (env.hasFlag("--conditional") ? plugin : undefined));
+
+				expect(plugin.buildCalled).to.equal(false);
+				expect(app.getResource<ConditionalResource>()).to.equal(undefined);
+				expect(app.getResource<SystemRegistry>()!.systemsIn(BuiltinSchedules.UPDATE).size()).to.equal(0);
+			});
+
+			it("工厂应能读取环境变量", () => {
+				const vars = new Map<string, string>();
+				vars.set("MODE", "server");
+				app.setBuildEnv(new BuildEnv({ vars }));
+
+				let observedMode: string | undefined;
+				app.addPluginWith((env) => {
+					observedMode = env.getVar("MODE");
+					return undefined;
+				});
+
+				expect(observedMode).to.equal("server");
+			});
+		});
+	});
+};
@@ -0,0 +1,82 @@
+/**
+ * @fileoverview 控制台命令测试
+ */
+
+import { component } from "@rbxts/matter";
+import { App } from "../app";
+import { ConsoleCommands, ConsoleError, ConsoleErrorKind, parseCommandLine } from "../console-commands";
+import { World } from "../../bevy_ecs/bevy-world";
+
+const Enemy = component<{ level: number }>("ConsoleEnemy");
+
+/**
+ * 统计世界中的敌人数量
+ * @param world - 游戏世界
+ */
+function countEnemies(world: World): number {
+	let count = 0;
+	for (const _ of world.query(Enemy)) {
+		count++;
+	}
+	return count;
+}
+
+export = () => {
+	describe("ConsoleCommands", () => {
+		let app: App;
+
+		beforeEach(() => {
+			app = App.create().registerCommand("spawn_enemy", (world, args) => {
+				const count = tonumber(args[0]) ?? 1;
+				for (let index = 0; index < count; index++) {
+					world.spawn(Enemy({ level: 1 }));
+				}
+				return `Spawned ${count} enemies`;
+			});
+		});
+
+		it("execute 应解析参数并调用处理函数", () => {
+			const result = app.executeCommand("spawn_enemy 3");
+
+			expect(result).to.equal("Spawned 3 enemies");
+			expect(countEnemies(app.getWorld())).to.equal(3);
+		});
+
+		it("未注册的命令应返回列出可用命令的错误", () => {
+			app.registerCommand("heal", () => {});
+
+			const result = app.executeCommand("teleport 0 0");
+
+			expect(result instanceof ConsoleError).to.equal(true);
+			const err = result as ConsoleError;
+			expect(err.kind).to.equal(ConsoleErrorKind.UnknownCommand);
+			expect(err.message.find("heal, spawn_enemy", 1, true)[0]).to.be.ok();
+		});
+
+		it("处理函数报错时应返回 HandlerFailed 错误", () => {
+			app.registerCommand("crash", () => {
+				error("boom");
+			});
+
+			const result = app.executeCommand("crash");
+
+			expect((result as ConsoleError).kind).to.equal(ConsoleErrorKind.HandlerFailed);
+		});
+
+		it("空输入应返回 EmptyInput 错误", () => {
+			expect((new ConsoleCommands().execute(new World(), "   ") as ConsoleError).kind).to.equal(
+				ConsoleErrorKind.EmptyInput,
+			);
+		});
+
+		it("双引号包裹的参数应保留空白", () => {
+			const words = parseCommandLine('say "hello world"  twice')!;
+
+			expect(words.size()).to.equal(3);
+			expect(words[0]).to.equal("say");
+			expect(words[1]).to.equal("hello world");
+			expect(words[2]).to.equal("twice");
+			expect(parseCommandLine('say "oops')).to.equal(undefined);
+		});
+	});
+};
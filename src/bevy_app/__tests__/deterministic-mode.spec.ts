@@ -0,0 +1,76 @@
+/**
+ * @fileoverview 确定性执行模式测试
+ */
+
+import { App } from "../app";
+import { BuiltinSchedules } from "../main-schedule";
+import { system } from "../../bevy_ecs/schedule";
+import type { World } from "../../bevy_ecs/bevy-world";
+
+/**
+ * 记录系统执行顺序的资源
+ */
+class ExecutionLog {
+	readonly __brand = "Resource" as const;
+	readonly entries: string[] = [];
+}
+
+/**
+ * 创建向 ExecutionLog 追加名称的系统
+ * @param name - 系统名称
+ */
+function createLogSystem(name: string) {
+	return (world: World) => {
+		world.resources.getResource<ExecutionLog>()!.entries.push(name);
+	};
+}
+
+export = () => {
+	describe("Deterministic Mode", () => {
+		it("系统应在每一帧都严格按注册顺序执行", () => {
+			const app = App.create().deterministicMode();
+			const log = new ExecutionLog();
+			app.insertResource(log);
+
+			app.addSystems(BuiltinSchedules.UPDATE, createLogSystem("a"));
+			app.addSystems(BuiltinSchedules.UPDATE, createLogSystem("b"));
+			app.addSystems(BuiltinSchedules.UPDATE, createLogSystem("c"));
+
+			for (let frame = 0; frame < 100; frame++) {
+				app.update();
+			}
+
+			expect(log.entries.size()).to.equal(300);
+			const expected = ["a", "b", "c"];
+			for (let index = 0; index < log.entries.size(); index++) {
+				expect(log.entries[index]).to.equal(expected[index % 3]);
+			}
+		});
+
+		it("与注册顺序一致的约束应被接受", () => {
+			const app = App.create().deterministicMode();
+			const log = new ExecutionLog();
+			app.insertResource(log);
+
+			const first = createLogSystem("first");
+			const second = createLogSystem("second");
+			app.addSystems(BuiltinSchedules.UPDATE, first, system(second).after(first));
+
+			app.update();
+
+			expect(log.entries[0]).to.equal("first");
+			expect(log.entries[1]).to.equal("second");
+		});
+
+		it("与注册顺序矛盾的约束应报错", () => {
+			const app = App.create().deterministicMode();
+			app.insertResource(new ExecutionLog());
+
+			const first = createLogSystem("first");
+			const second = createLogSystem("second");
+			app.addSystems(BuiltinSchedules.UPDATE, system(first).after(second), second);
+
+			expect(() => app.update()).to.throw();
+		});
+	});
+};
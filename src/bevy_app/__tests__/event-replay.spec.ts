@@ -0,0 +1,103 @@
+/**
+ * @fileoverview 事件录制与回放测试
+ */
+
+import { App } from "../app";
+import { BuiltinSchedules } from "../main-schedule";
+import { EventLog } from "../event-replay";
+import { Message } from "../../bevy_ecs/message";
+import type { World } from "../../bevy_ecs/bevy-world";
+import { Duration, runFixed, TimePlugin } from "../../bevy_time";
+
+class DamageEvent implements Message {
+	constructor(public readonly amount: number) {}
+}
+
+/**
+ * 观察到的事件及其所在帧
+ */
+interface ObservedEvent {
+	readonly frame: number;
+	readonly amount: number;
+}
+
+/**
+ * 创建按帧记录收到的 DamageEvent 的系统
+ * @param observed - 记录列表
+ */
+function createObserverSystem(observed: ObservedEvent[]) {
+	let frame = 0;
+	return (world: World) => {
+		const messages = world.messages.getMessages<DamageEvent>()!;
+		for (const event of messages.iterCurrentUpdateMessages()) {
+			observed.push({ frame, amount: event.amount });
+		}
+		frame++;
+	};
+}
+
+export = () => {
+	describe("Event Replay", () => {
+		it("回放应在相同的帧重现相同的事件序列", () => {
+			const recording = App.create().deterministicMode().addPlugin(new TimePlugin());
+			recording.addMessage<DamageEvent>();
+			recording.recordEvents<DamageEvent>();
+
+			let frame = 0;
+			const script = new Map<number, number[]>([
+				[1, [10]],
+				[3, [5, 7]],
+				[6, [1]],
+			]);
+			const recordedObserved: ObservedEvent[] = [];
+			recording.addSystems(
+				BuiltinSchedules.UPDATE,
+				(world: World) => {
+					const writer = world.messages.createWriter<DamageEvent>();
+					for (const amount of script.get(frame) ?? []) {
+						writer.write(new DamageEvent(amount));
+					}
+					frame++;
+				},
+				createObserverSystem(recordedObserved),
+			);
+
+			runFixed(recording, 8, Duration.fromSecs(1 / 60));
+
+			const log = recording.getResource<EventLog<DamageEvent>>()!;
+			expect(log.size()).to.equal(4);
+			expect(log.getEntries()[0].frame).to.equal(1);
+			expect(log.eventsAt(3).size()).to.equal(2);
+			expect(log.getEntries()[3].frame).to.equal(6);
+
+			const replay = App.create().deterministicMode().addPlugin(new TimePlugin());
+			replay.addMessage<DamageEvent>();
+			replay.replayEvents<DamageEvent>(log);
+			const replayedObserved: ObservedEvent[] = [];
+			replay.addSystems(BuiltinSchedules.UPDATE, createObserverSystem(replayedObserved));
+
+			runFixed(replay, 8, Duration.fromSecs(1 / 60));
+
+			expect(recordedObserved.size()).to.equal(4);
+			expect(replayedObserved.size()).to.equal(recordedObserved.size());
+			for (let index = 0; index < recordedObserved.size(); index++) {
+				expect(replayedObserved[index].frame).to.equal(recordedObserved[index].frame);
+				expect(replayedObserved[index].amount).to.equal(recordedObserved[index].amount);
+			}
+		});
+
+		it("重复调用 recordEvents 不应重复录制", () => {
+			const app = App.create();
+			app.addMessage<DamageEvent>();
+			app.recordEvents<DamageEvent>();
+			app.recordEvents<DamageEvent>();
+
+			app.addSystems(BuiltinSchedules.UPDATE, (world: World) => {
+				world.messages.createWriter<DamageEvent>().write(new DamageEvent(1));
+			});
+			app.update();
+
+			expect(app.getResource<EventLog<DamageEvent>>()!.size()).to.equal(1);
+		});
+	});
+};
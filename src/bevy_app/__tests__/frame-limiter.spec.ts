@@ -0,0 +1,40 @@
+/**
+ * @fileoverview 帧率限制测试
+ */
+
+import { App } from "../app";
+import { FrameStats } from "../frame-limiter";
+import { BuiltinSchedules } from "../main-schedule";
+
+export = () => {
+	describe("limitFps", () => {
+		it("固定时间窗口内的帧数应接近目标帧率", () => {
+			const targetFps = 10;
+			const windowSeconds = 1;
+			const app = App.create().limitFps(targetFps);
+
+			let startTime: number | undefined;
+			let frames = 0;
+			app.addSystems(BuiltinSchedules.UPDATE, () => {
+				startTime ??= os.clock();
+				frames++;
+				if (os.clock() - startTime >= windowSeconds) {
+					app.exit();
+				}
+			});
+
+			app.run();
+
+			const expected = targetFps * windowSeconds;
+			expect(math.abs(frames - expected) <= 3).to.equal(true);
+
+			const stats = app.getResource<FrameStats>()!;
+			expect(stats.frameCount).to.equal(frames);
+			expect(math.abs(stats.fps() - targetFps) <= 3).to.equal(true);
+		});
+
+		it("负数目标帧率应报错", () => {
+			expect(() => App.create().limitFps(-1)).to.throw();
+		});
+	});
+};
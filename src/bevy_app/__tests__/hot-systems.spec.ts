@@ -0,0 +1,87 @@
+/**
+ * @fileoverview 系统热替换测试
+ */
+
+import { App } from "../app";
+import { BuiltinSchedules } from "../main-schedule";
+import { HotSystems } from "../hot-systems";
+import { SystemRegistry } from "../system-registry";
+import { system } from "../../bevy_ecs/schedule";
+import type { Resource } from "../../bevy_ecs/resource";
+import type { BevyWorld, Context } from "../../bevy_ecs";
+
+class WrittenValue implements Resource {
+	readonly __brand = "Resource" as const;
+	value = 0;
+}
+
+export = () => {
+	describe("Hot Systems", () => {
+		let app: App;
+
+		function writeOne(world: BevyWorld, context: Context) {
+			world.resources.getResource<WrittenValue>()!.value = 1;
+		}
+
+		function writeTwo(world: BevyWorld, context: Context) {
+			world.resources.getResource<WrittenValue>()!.value = 2;
+		}
+
+		function getSystemId(system: typeof writeOne): string {
+			return app.getResource<SystemRegistry>()!.find(BuiltinSchedules.UPDATE, system)!.id;
+		}
+
+		beforeEach(() => {
+			app = App.create();
+			app.insertResource(new WrittenValue());
+		});
+
+		it("替换后的系统应在下一帧生效", () => {
+			app.addSystems(BuiltinSchedules.UPDATE, writeOne);
+			app.update();
+			expect(app.getResource<WrittenValue>()!.value).to.equal(1);
+
+			app.getResource<HotSystems>()!.replaceSystem(getSystemId(writeOne), writeTwo);
+			app.update();
+
+			expect(app.getResource<WrittenValue>()!.value).to.equal(2);
+		});
+
+		it("替换后应保留 before/after 关系", () => {
+			const observed: number[] = [];
+			const readValue = (world: BevyWorld) => {
+				observed.push(world.resources.getResource<WrittenValue>()!.value);
+			};
+			const resetValue = (world: BevyWorld) => {
+				world.resources.getResource<WrittenValue>()!.value = 0;
+			};
+
+			app.addSystems(BuiltinSchedules.UPDATE, resetValue);
+			app.addSystems(BuiltinSchedules.UPDATE, readValue);
+			app.addSystems(BuiltinSchedules.UPDATE, system(writeOne).after(resetValue).before(readValue));
+
+			app.getResource<HotSystems>()!.replaceSystem(getSystemId(writeOne), writeTwo);
+			app.update();
+
+			expect(observed.size()).to.equal(1);
+			expect(observed[0]).to.equal(2);
+		});
+
+		it("替换后注册表应指向新系统", () => {
+			app.addSystems(BuiltinSchedules.UPDATE, writeOne);
+			const systemId = getSystemId(writeOne);
+
+			app.getResource<HotSystems>()!.replaceSystem(systemId, writeTwo);
+
+			const registry = app.getResource<SystemRegistry>()!;
+			expect(registry.find(BuiltinSchedules.UPDATE, writeOne)).to.equal(undefined);
+			expect(registry.find(BuiltinSchedules.UPDATE, writeTwo)!.id).to.equal(systemId);
+		});
+
+		it("替换不存在的系统应报错", () => {
+			expect(() => {
+				app.getResource<HotSystems>()!.replaceSystem("Update::missing::1", writeTwo);
+			}).to.throw();
+		});
+	});
+};
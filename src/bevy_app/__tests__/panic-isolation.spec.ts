@@ -0,0 +1,74 @@
+/**
+ * @fileoverview 系统错误隔离测试
+ */
+
+import { App } from "../app";
+import { BuiltinSchedules } from "../main-schedule";
+import { SystemPanicLog } from "../panic-isolation";
+import type { World } from "@rbxts/matter";
+import type { Context } from "../../bevy_ecs";
+
+export = () => {
+	describe("isolatePanics", () => {
+		it("系统出错后 App 应继续运行并记录错误", () => {
+			const app = App.create().isolatePanics();
+
+			let frame = 0;
+			let failingCalls = 0;
+			let healthyCalls = 0;
+
+			function failingSystem(world: World, context: Context) {
+				failingCalls++;
+				if (frame === 2) {
+					error("boom");
+				}
+			}
+
+			function healthySystem(world: World, context: Context) {
+				healthyCalls++;
+			}
+
+			app.addSystems(BuiltinSchedules.UPDATE, failingSystem, healthySystem);
+
+			for (let index = 1; index <= 5; index++) {
+				frame = index;
+				expect(() => app.update()).never.to.throw();
+			}
+
+			expect(healthyCalls).to.equal(5);
+			// 第 2 帧出错后被禁用
+			expect(failingCalls).to.equal(2);
+
+			const panics = app.getResource<SystemPanicLog>()!.getPanics();
+			expect(panics.size()).to.equal(1);
+			expect(panics[0].name).to.equal("failingSystem");
+			expect(panics[0].schedule).to.equal(BuiltinSchedules.UPDATE);
+			expect(panics[0].message.find("boom", 1, true)[0]).to.be.ok();
+		});
+
+		it("重新启用后系统应恢复执行", () => {
+			const app = App.create().isolatePanics();
+
+			let shouldFail = true;
+			let calls = 0;
+			app.addSystems(BuiltinSchedules.UPDATE, () => {
+				calls++;
+				if (shouldFail) {
+					error("boom");
+				}
+			});
+
+			app.update();
+			app.update();
+			expect(calls).to.equal(1);
+
+			const log = app.getResource<SystemPanicLog>()!;
+			shouldFail = false;
+			log.enable(log.getPanics()[0].id);
+			app.update();
+
+			expect(calls).to.equal(2);
+			expect(log.isDisabled(log.getPanics()[0].id)).to.equal(false);
+		});
+	});
+};
@@ -0,0 +1,77 @@
+/**
+ * @fileoverview 分组暂停测试
+ */
+
+import { App } from "../app";
+import { BuiltinSchedules } from "../main-schedule";
+import { PauseControl } from "../pause-control";
+import { intoSystemConfigs } from "../../bevy_ecs/schedule/system-configs";
+import type { World } from "@rbxts/matter";
+import type { Context } from "../../bevy_ecs";
+
+export = () => {
+	describe("PauseControl", () => {
+		let app: App;
+		let gameplayRuns: number;
+		let uiRuns: number;
+
+		function gameplaySystem(world: World, context: Context) {
+			gameplayRuns++;
+		}
+
+		function uiSystem(world: World, context: Context) {
+			uiRuns++;
+		}
+
+		beforeEach(() => {
+			app = App.create();
+			gameplayRuns = 0;
+			uiRuns = 0;
+		});
+
+		it("暂停的组不应运行，其他组应继续运行，恢复后应再次运行", () => {
+			app.addPausableSystem("gameplay", gameplaySystem);
+			app.addPausableSystem("always", uiSystem);
+			const control = app.getResource<PauseControl>()!;
+
+			app.update();
+			expect(gameplayRuns).to.equal(1);
+			expect(uiRuns).to.equal(1);
+
+			control.pause("gameplay");
+			expect(control.isRunning("gameplay")).to.equal(false);
+			app.update();
+			app.update();
+			expect(gameplayRuns).to.equal(1);
+			expect(uiRuns).to.equal(3);
+
+			control.resume("gameplay");
+			app.update();
+			expect(gameplayRuns).to.equal(2);
+			expect(uiRuns).to.equal(4);
+		});
+
+		it("帧中途暂停应对本帧之后运行的系统立即生效", () => {
+			function pauseMenuSystem(world: World, context: Context) {
+				world.resources.getResource<PauseControl>()!.pause("gameplay");
+			}
+
+			app.addPausableSystem("gameplay", gameplaySystem);
+			app.addSystems(BuiltinSchedules.UPDATE, intoSystemConfigs(pauseMenuSystem).before(gameplaySystem));
+
+			app.update();
+
+			expect(gameplayRuns).to.equal(0);
+			expect(app.getResource<PauseControl>()!.getPausedGroups()[0]).to.equal("gameplay");
+		});
+
+		it("toggle 应切换暂停状态并返回是否在运行", () => {
+			const control = new PauseControl();
+
+			expect(control.toggle("gameplay")).to.equal(false);
+			expect(control.isRunning("gameplay")).to.equal(false);
+			expect(control.toggle("gameplay")).to.equal(true);
+			expect(control.isRunning("gameplay")).to.equal(true);
+		});
+	});
+};
@@ -0,0 +1,73 @@
+/**
+ * @fileoverview 插件配置测试
+ */
+
+import { App } from "../app";
+import { BuiltinSchedules } from "../main-schedule";
+import { BasePlugin } from "../plugin";
+import { ConfigError, ConfiguredPlugin, PluginConfig } from "../plugin-config";
+import { SystemRegistry } from "../system-registry";
+import type { World } from "../../bevy_ecs/bevy-world";
+
+/**
+ * 背包插件配置
+ */
+class InventoryConfig implements PluginConfig {
+	readonly __brand = "Resource" as const;
+	constructor(public readonly capacity: number) {}
+
+	validate(): ConfigError | undefined {
+		if (this.capacity < 0) {
+			return new ConfigError(`capacity must not be negative, got ${this.capacity}`, "capacity");
+		}
+		return undefined;
+	}
+}
+
+/**
+ * 读取配置的测试插件
+ */
+class InventoryPlugin extends BasePlugin implements ConfiguredPlugin<InventoryConfig> {
+	capacitySeen?: number;
+
+	build(app: App): void {
+		app.addSystems(BuiltinSchedules.UPDATE, (world: World) => {
+			this.capacitySeen = world.resources.getResource<InventoryConfig>()!.capacity;
+		});
+	}
+
+	name(): string {
+		return "InventoryPlugin";
+	}
+}
+
+export = () => {
+	describe("Configured Plugin", () => {
+		let app: App;
+
+		beforeEach(() => {
+			app = App.create();
+		});
+
+		it("有效配置应成功构建，系统可以读取配置资源", () => {
+			const plugin = new InventoryPlugin();
+			const err = app.addConfiguredPlugin(plugin, new InventoryConfig(32));
+
+			expect(err).to.equal(undefined);
+			expect(app.getResource<InventoryConfig>()!.capacity).to.equal(32);
+
+			app.update();
+			expect(plugin.capacitySeen).to.equal(32);
+		});
+
+		it("无效配置应返回校验错误且不注册任何系统", () => {
+			const err = app.addConfiguredPlugin(new InventoryPlugin(), new InventoryConfig(-1));
+
+			expect(err).to.be.ok();
+			expect(err!.field).to.equal("capacity");
+			expect(app.getResource<InventoryConfig>()).to.equal(undefined);
+			expect(app.isPluginAdded(InventoryPlugin)).to.equal(false);
+			expect(app.getResource<SystemRegistry>()!.systemsIn(BuiltinSchedules.UPDATE).size()).to.equal(0);
+		});
+	});
+};
@@ -0,0 +1,103 @@
+/**
+ * @fileoverview 插件依赖排序测试
+ */
+
+import { App } from "../app";
+import { BasePlugin, CyclicDependencyError, MissingDependencyError, PluginId } from "../plugin";
+
+/**
+ * 可配置依赖的测试插件
+ */
+class DependentPlugin extends BasePlugin {
+	constructor(
+		private readonly pluginName: string,
+		private readonly deps: PluginId[],
+		private readonly buildOrder: string[],
+	) {
+		super();
+	}
+
+	build(app: App): void {
+		this.buildOrder.push(this.pluginName);
+	}
+
+	name(): string {
+		return this.pluginName;
+	}
+
+	dependencies(): PluginId[] {
+		return this.deps;
+	}
+}
+
+export = () => {
+	describe("Plugin Dependencies", () => {
+		let app: App;
+		let buildOrder: string[];
+
+		beforeEach(() => {
+			app = App.create();
+			buildOrder = [];
+		});
+
+		it("应按依赖顺序构建插件，与添加顺序无关", () => {
+			const pluginA = new DependentPlugin("A", [], buildOrder);
+			const pluginB = new DependentPlugin("B", ["A"], buildOrder);
+			const pluginC = new DependentPlugin("C", ["B"], buildOrder);
+
+			const err = app.addPluginsWithDeps(pluginC, pluginB, pluginA);
+
+			expect(err).to.equal(undefined);
+			expect(buildOrder.size()).to.equal(3);
+			expect(buildOrder[0]).to.equal("A");
+			expect(buildOrder[1]).to.equal("B");
+			expect(buildOrder[2]).to.equal("C");
+		});
+
+		it("无依赖关系的插件应保持添加顺序", () => {
+			const err = app.addPluginsWithDeps(
+				new DependentPlugin("X", [], buildOrder),
+				new DependentPlugin("Y", [], buildOrder),
+			);
+
+			expect(err).to.equal(undefined);
+			expect(buildOrder[0]).to.equal("X");
+			expect(buildOrder[1]).to.equal("Y");
+		});
+
+		it("已添加到 App 的插件应满足依赖", () => {
+			app.addPlugin(new DependentPlugin("A", [], buildOrder));
+
+			const err = app.addPluginsWithDeps(new DependentPlugin("B", ["A"], buildOrder));
+
+			expect(err).to.equal(undefined);
+			expect(buildOrder.size()).to.equal(2);
+			expect(buildOrder[1]).to.equal("B");
+		});
+
+		it("循环依赖应返回 CyclicDependencyError 且不构建任何插件", () => {
+			const err = app.addPluginsWithDeps(
+				new DependentPlugin("A", ["C"], buildOrder),
+				new DependentPlugin("B", ["A"], buildOrder),
+				new DependentPlugin("C", ["B"], buildOrder),
+			);
+
+			expect(err).to.be.ok();
+			expect(err instanceof CyclicDependencyError).to.equal(true);
+
+			const cycle = (err as CyclicDependencyError).cycle;
+			expect(cycle.size()).to.equal(4);
+			expect(cycle[0]).to.equal(cycle[cycle.size() - 1]);
+			expect(buildOrder.size()).to.equal(0);
+		});
+
+		it("缺失依赖应返回 MissingDependencyError", () => {
+			const err = app.addPluginsWithDeps(new DependentPlugin("B", ["A"], buildOrder));
+
+			expect(err instanceof MissingDependencyError).to.equal(true);
+			expect((err as MissingDependencyError).dependency).to.equal("A");
+			expect((err as MissingDependencyError).pluginName).to.equal("B");
+			expect(buildOrder.size()).to.equal(0);
+		});
+	});
+};
@@ -0,0 +1,93 @@
+/**
+ * @fileoverview 插件关闭钩子测试
+ */
+
+import { App } from "../app";
+import { BuiltinSchedules } from "../main-schedule";
+import { BasePlugin } from "../plugin";
+
+/**
+ * 关闭时记录调用的测试插件
+ */
+class ShutdownPlugin extends BasePlugin {
+	constructor(
+		private readonly pluginName: string,
+		private readonly shutdownOrder: string[],
+	) {
+		super();
+	}
+
+	build(app: App): void {}
+
+	name(): string {
+		return this.pluginName;
+	}
+
+	onShutdown(app: App): void {
+		this.shutdownOrder.push(this.pluginName);
+	}
+}
+
+export = () => {
+	describe("Plugin Shutdown", () => {
+		let app: App;
+		let shutdownOrder: string[];
+
+		beforeEach(() => {
+			app = App.create();
+			shutdownOrder = [];
+		});
+
+		it("未请求退出时不应调用 onShutdown", () => {
+			app.addPlugin(new ShutdownPlugin("A", shutdownOrder));
+
+			app.update();
+			app.update();
+
+			expect(shutdownOrder.size()).to.equal(0);
+			expect(app.isShutdown()).to.equal(false);
+		});
+
+		it("AppExit 后 onShutdown 只触发一次", () => {
+			app.addPlugin(new ShutdownPlugin("A", shutdownOrder));
+
+			app.addSystems(BuiltinSchedules.UPDATE, () => {
+				app.exit();
+				app.exit();
+			});
+
+			app.update();
+			expect(shutdownOrder.size()).to.equal(1);
+			expect(app.isShutdown()).to.equal(true);
+
+			app.exit();
+			app.update();
+			app.shutdown();
+			expect(shutdownOrder.size()).to.equal(1);
+		});
+
+		it("应按插件注册的逆序调用 onShutdown", () => {
+			app.addPlugin(new ShutdownPlugin("First", shutdownOrder));
+			app.addPlugin(new ShutdownPlugin("Second", shutdownOrder));
+			app.addPlugin(new ShutdownPlugin("Third", shutdownOrder));
+
+			app.exitWithCode(1);
+			app.update();
+
+			expect(shutdownOrder.size()).to.equal(3);
+			expect(shutdownOrder[0]).to.equal("Third");
+			expect(shutdownOrder[1]).to.equal("Second");
+			expect(shutdownOrder[2]).to.equal("First");
+		});
+
+		it("关闭检测不应影响 shouldExit", () => {
+			app.addPlugin(new ShutdownPlugin("A", shutdownOrder));
+
+			app.exit();
+			app.update();
+
+			expect(shutdownOrder.size()).to.equal(1);
+			expect(app.shouldExit()).to.be.ok();
+		});
+	});
+};
@@ -0,0 +1,108 @@
+/**
+ * @fileoverview 插件版本检查测试
+ */
+
+import { App } from "../app";
+import { FrameworkBuildErrorKind } from "../build-error";
+import { BasePlugin, PluginId, VersionMismatchError } from "../plugin";
+import { Version, VersionReq } from "../plugin-version";
+
+/**
+ * 报告版本并声明版本要求的测试插件
+ */
+class VersionedPlugin extends BasePlugin {
+	constructor(
+		private readonly pluginName: string,
+		private readonly pluginVersion: string,
+		private readonly requirements: Array<[PluginId, string]> = [],
+	) {
+		super();
+	}
+
+	build(app: App): void {}
+
+	name(): string {
+		return this.pluginName;
+	}
+
+	version(): Version {
+		return Version.parse(this.pluginVersion);
+	}
+
+	requires(): Array<[PluginId, VersionReq]> {
+		return this.requirements.map(([id, req]): [PluginId, VersionReq] => [id, VersionReq.parse(req)]);
+	}
+}
+
+export = () => {
+	describe("插件版本检查", () => {
+		it("依赖版本不满足要求时应返回 VersionMismatch 错误", () => {
+			const app = App.create();
+			app.addPlugin(new VersionedPlugin("Physics", "1.5"));
+			app.addPlugin(new VersionedPlugin("Vehicles", "1.0.0", [["Physics", "^2.0"]]));
+
+			const err = app.tryBuild();
+			expect(err).to.be.ok();
+			expect(err!.kind).to.equal(FrameworkBuildErrorKind.VersionMismatch);
+
+			const source = err!.source() as VersionMismatchError;
+			expect(source instanceof VersionMismatchError).to.equal(true);
+			expect(source.pluginName).to.equal("Vehicles");
+			expect(source.dependency).to.equal("Physics");
+			expect(source.required.toString()).to.equal("^2.0");
+			expect(source.found!.toString()).to.equal("1.5.0");
+		});
+
+		it("依赖版本满足要求时应构建成功", () => {
+			const app = App.create();
+			app.addPlugin(new VersionedPlugin("Physics", "2.3.1"));
+			app.addPlugin(new VersionedPlugin("Vehicles", "1.0.0", [["Physics", "^2.0"]]));
+
+			expect(app.tryBuild()).to.equal(undefined);
+		});
+
+		it("要求的插件未添加时应返回 MissingDependency 错误", () => {
+			const app = App.create();
+			app.addPlugin(new VersionedPlugin("Vehicles", "1.0.0", [["Physics", "^2.0"]]));
+
+			const err = app.tryBuild();
+			expect(err).to.be.ok();
+			expect(err!.kind).to.equal(FrameworkBuildErrorKind.MissingDependency);
+		});
+	});
+
+	describe("VersionReq", () => {
+		it("应支持 Cargo 风格的版本要求", () => {
+			expect(VersionReq.parse("^2.0").matches(Version.parse("2.9.9"))).to.equal(true);
+			expect(VersionReq.parse("^2.0").matches(Version.parse("3.0.0"))).to.equal(false);
+			expect(VersionReq.parse("^0.2.1").matches(Version.parse("0.2.5"))).to.equal(true);
+			expect(VersionReq.parse("^0.2.1").matches(Version.parse("0.3.0"))).to.equal(false);
+			expect(VersionReq.parse("~1.2").matches(Version.parse("1.2.7"))).to.equal(true);
+			expect(VersionReq.parse("~1.2").matches(Version.parse("1.3.0"))).to.equal(false);
+			expect(VersionReq.parse(">=1.2, <1.5").matches(Version.parse("1.4.0"))).to.equal(true);
+			expect(VersionReq.parse(">=1.2, <1.5").matches(Version.parse("1.5.0"))).to.equal(false);
+			expect(VersionReq.parse("=1.2.3").matches(Version.parse("1.2.3"))).to.equal(true);
+			expect(VersionReq.parse("*").matches(Version.parse("0.0.1"))).to.equal(true);
+		});
+
+		it("省略段的 > 应要求高于该前缀下的所有版本", () => {
+			expect(VersionReq.parse(">1.2").matches(Version.parse("1.2.1"))).to.equal(false);
+			expect(VersionReq.parse(">1.2").matches(Version.parse("1.3.0"))).to.equal(true);
+			expect(VersionReq.parse(">1").matches(Version.parse("1.9.9"))).to.equal(false);
+			expect(VersionReq.parse(">1").matches(Version.parse("2.0.0"))).to.equal(true);
+			expect(VersionReq.parse(">1.2.3").matches(Version.parse("1.2.4"))).to.equal(true);
+		});
+
+		it("省略段的 <= 应包含该前缀下的所有版本", () => {
+			expect(VersionReq.parse("<=1.2").matches(Version.parse("1.2.5"))).to.equal(true);
+			expect(VersionReq.parse("<=1.2").matches(Version.parse("1.3.0"))).to.equal(false);
+			expect(VersionReq.parse("<=1").matches(Version.parse("1.9.9"))).to.equal(true);
+			expect(VersionReq.parse("<=1.2.3").matches(Version.parse("1.2.4"))).to.equal(false);
+		});
+
+		it("格式错误的版本要求应报错", () => {
+			expect(() => VersionReq.parse("^two")).to.throw();
+			expect(() => Version.parse("1.2.3.4")).to.throw();
+		});
+	});
+};
@@ -0,0 +1,76 @@
+/**
+ * @fileoverview 系统性能分析测试
+ */
+
+import { App } from "../app";
+import { BuiltinSchedules } from "../main-schedule";
+import { ProfilingStats } from "../profiling";
+import type { World } from "@rbxts/matter";
+import type { Context } from "../../bevy_ecs";
+
+export = () => {
+	describe("Profiling", () => {
+		let app: App;
+
+		function slowSystem(world: World, context: Context) {
+			const startTime = os.clock();
+			while (os.clock() - startTime < 0.002) {
+				// 忙等待约 2ms
+			}
+		}
+
+		function trivialSystem(world: World, context: Context) {}
+
+		beforeEach(() => {
+			app = App.create();
+		});
+
+		it("未开启时不应创建 ProfilingStats", () => {
+			app.addSystems(BuiltinSchedules.UPDATE, trivialSystem);
+			app.update();
+
+			expect(app.getResource<ProfilingStats>()).to.equal(undefined);
+		});
+
+		it("慢系统的总耗时应超过简单系统", () => {
+			app.enableProfiling();
+			app.addSystems(BuiltinSchedules.UPDATE, slowSystem, trivialSystem);
+
+			for (let frame = 0; frame < 3; frame++) {
+				app.update();
+			}
+
+			const stats = app.getResource<ProfilingStats>()!;
+			const slow = stats.getTiming("slowSystem")!;
+			const trivial = stats.getTiming("trivialSystem")!;
+
+			expect(slow).to.be.ok();
+			expect(trivial).to.be.ok();
+			expect(slow.calls).to.equal(3);
+			expect(trivial.calls).to.equal(3);
+			expect(slow.total > trivial.total).to.equal(true);
+			expect(slow.min <= slow.mean).to.equal(true);
+			expect(slow.mean <= slow.max).to.equal(true);
+		});
+
+		it("报告应按总耗时降序排列", () => {
+			app.enableProfiling();
+			app.addSystems(BuiltinSchedules.UPDATE, trivialSystem, slowSystem);
+
+			app.update();
+			app.update();
+
+			const report = app.getResource<ProfilingStats>()!.report();
+			expect(report.size() >= 2).to.equal(true);
+			expect(report[0].name).to.equal("slowSystem");
+			for (let index = 1; index < report.size(); index++) {
+				expect(report[index - 1].total >= report[index].total).to.equal(true);
+			}
+		});
+
+		it("编译后开启应报错", () => {
+			app.update();
+			expect(() => app.enableProfiling()).to.throw();
+		});
+	});
+};
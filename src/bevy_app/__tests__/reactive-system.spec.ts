@@ -0,0 +1,53 @@
+/**
+ * @fileoverview 响应式系统测试
+ */
+
+import { component } from "@rbxts/matter";
+import { App } from "../app";
+
+const Obstacle = component<{ size: number }>("ReactiveObstacle");
+
+export = () => {
+	describe("addReactiveSystem", () => {
+		it("系统应只在组件变更的帧以及首帧运行", () => {
+			const app = App.create();
+			const world = app.getWorld();
+
+			let frame = 0;
+			const runFrames: number[] = [];
+			app.addReactiveSystem(Obstacle, () => {
+				runFrames.push(frame);
+			});
+
+			const entity = world.spawn(Obstacle({ size: 1 }));
+
+			for (frame = 1; frame <= 6; frame++) {
+				if (frame === 3) {
+					world.insert(entity, Obstacle({ size: 2 }));
+				} else if (frame === 5) {
+					world.spawn(Obstacle({ size: 3 }));
+				}
+				app.update();
+			}
+
+			expect(runFrames.size()).to.equal(3);
+			expect(runFrames[0]).to.equal(1);
+			expect(runFrames[1]).to.equal(3);
+			expect(runFrames[2]).to.equal(5);
+		});
+
+		it("没有匹配实体时首帧不应运行", () => {
+			const app = App.create();
+
+			let runs = 0;
+			app.addReactiveSystem(Obstacle, () => {
+				runs++;
+			});
+
+			app.update();
+			app.update();
+
+			expect(runs).to.equal(0);
+		});
+	});
+};
@@ -0,0 +1,91 @@
+/**
+ * @fileoverview 有序资源初始化测试
+ */
+
+import { App } from "../app";
+import { FrameworkBuildErrorKind } from "../build-error";
+import { BasePlugin } from "../plugin";
+
+/**
+ * 被依赖的资源
+ */
+class ResourceA {
+	readonly __brand = "Resource" as const;
+	constructor(public readonly value: number) {}
+}
+
+/**
+ * 默认值依赖 ResourceA 的资源
+ */
+class ResourceB {
+	readonly __brand = "Resource" as const;
+	constructor(public readonly doubled: number) {}
+}
+
+/**
+ * 插入 ResourceA 的插件
+ */
+class PluginA extends BasePlugin {
+	build(app: App): void {
+		app.insertResource(new ResourceA(21));
+	}
+
+	name(): string {
+		return "PluginA";
+	}
+}
+
+/**
+ * 声明 ResourceB 在 ResourceA 之后初始化的插件
+ */
+class PluginB extends BasePlugin {
+	build(app: App): void {
+		app.initResourceAfter<ResourceB, ResourceA>((app) => new ResourceB(app.getResource<ResourceA>()!.value * 2));
+	}
+
+	name(): string {
+		return "PluginB";
+	}
+}
+
+export = () => {
+	describe("initResourceAfter", () => {
+		it("依赖插件先添加时应正确初始化", () => {
+			const app = App.create();
+			app.addPlugin(new PluginA());
+			app.addPlugin(new PluginB());
+
+			expect(app.tryBuild()).to.equal(undefined);
+			expect(app.getResource<ResourceB>()!.doubled).to.equal(42);
+		});
+
+		it("依赖插件后添加时应正确初始化", () => {
+			const app = App.create();
+			app.addPlugin(new PluginB());
+			app.addPlugin(new PluginA());
+
+			expect(app.tryBuild()).to.equal(undefined);
+			expect(app.getResource<ResourceB>()!.doubled).to.equal(42);
+		});
+
+		it("依赖始终不存在时应返回 MissingResourceDependency 错误", () => {
+			const app = App.create();
+			app.addPlugin(new PluginB());
+
+			const err = app.tryBuild();
+			expect(err).to.be.ok();
+			expect(err!.kind).to.equal(FrameworkBuildErrorKind.MissingResourceDependency);
+			expect(app.getResource<ResourceB>()).to.equal(undefined);
+		});
+
+		it("已存在的资源不应被覆盖", () => {
+			const app = App.create();
+			app.insertResource(new ResourceB(7));
+			app.addPlugin(new PluginB());
+			app.addPlugin(new PluginA());
+
+			expect(app.tryBuild()).to.equal(undefined);
+			expect(app.getResource<ResourceB>()!.doubled).to.equal(7);
+		});
+	});
+};
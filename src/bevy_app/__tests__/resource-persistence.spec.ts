@@ -0,0 +1,143 @@
+/**
+ * @fileoverview 资源持久化测试
+ */
+
+import { App } from "../app";
+import { MemoryPersistenceStore, ResourcePersistence } from "../resource-persistence";
+import { ResourceManager } from "../../bevy_ecs/resource";
+import type { Resource } from "../../bevy_ecs/resource";
+import { getTypeDescriptor } from "../../bevy_core/reflect";
+
+/**
+ * 测试用设置资源
+ */
+class GameSettings implements Resource {
+	readonly __brand = "Resource" as const;
+	volume = 1;
+	difficulty = "normal";
+}
+
+/**
+ * 创建持久化 GameSettings 的 App
+ * @param store - 存储
+ * @param saveOnChange - 是否自动写回
+ */
+function createApp(store: MemoryPersistenceStore, saveOnChange = false): App {
+	return App.create().persistResource<GameSettings>("settings.json", () => new GameSettings(), {
+		store,
+		saveOnChange,
+		debounce: 0,
+	});
+}
+
+export = () => {
+	describe("persistResource", () => {
+		let store: MemoryPersistenceStore;
+
+		beforeEach(() => {
+			store = new MemoryPersistenceStore();
+		});
+
+		it("关闭后重新加载应得到相同的值", () => {
+			const first = createApp(store);
+			const settings = first.getResource<GameSettings>()!;
+			settings.volume = 0.25;
+			settings.difficulty = "hard";
+			first.shutdown();
+
+			const reloaded = createApp(store).getResource<GameSettings>()!;
+
+			expect(reloaded.volume).to.equal(0.25);
+			expect(reloaded.difficulty).to.equal("hard");
+		});
+
+		it("存储中没有内容时应使用默认值", () => {
+			const settings = createApp(store).getResource<GameSettings>()!;
+
+			expect(settings.volume).to.equal(1);
+			expect(settings.difficulty).to.equal("normal");
+		});
+
+		it("内容无法解析时应回退到默认值而不是报错", () => {
+			store.write("settings.json", "{not json");
+
+			const settings = createApp(store).getResource<GameSettings>()!;
+
+			expect(settings.volume).to.equal(1);
+			expect(settings.difficulty).to.equal("normal");
+		});
+
+		it("字段类型与默认值不一致时应保留该字段的默认值", () => {
+			store.write("settings.json", '{"volume":"x","difficulty":"hard"}');
+
+			const settings = createApp(store).getResource<GameSettings>()!;
+
+			expect(settings.volume).to.equal(1);
+			expect(settings.difficulty).to.equal("hard");
+		});
+
+		it("内容不是 JSON 对象时应回退到默认值", () => {
+			store.write("settings.json", "[1]");
+
+			const settings = createApp(store).getResource<GameSettings>()!;
+
+			expect(settings.volume).to.equal(1);
+			expect(settings.difficulty).to.equal("normal");
+			expect((settings as unknown as Record<number, unknown>)[1]).to.equal(undefined);
+		});
+
+		it("未开启 saveOnChange 时只在关闭时写回", () => {
+			const app = createApp(store);
+			app.getResource<GameSettings>()!.volume = 0.5;
+			app.update();
+
+			expect(store.read("settings.json")).to.equal(undefined);
+
+			app.shutdown();
+			expect(store.read("settings.json")).to.be.ok();
+		});
+
+		it("开启 saveOnChange 时值变化后应自动写回", () => {
+			const app = createApp(store, true);
+			app.update();
+			expect(store.read("settings.json")).to.equal(undefined);
+
+			app.getResource<GameSettings>()!.volume = 0.75;
+			app.update();
+
+			const reloaded = createApp(store).getResource<GameSettings>()!;
+			expect(reloaded.volume).to.equal(0.75);
+		});
+
+		it("值在 debounce 时间内保持不变后才应写回", () => {
+			let now = 0;
+			const resources = new ResourceManager();
+			const persistence = new ResourcePersistence(resources, () => now);
+			const descriptor = getTypeDescriptor("GameSettings", "GameSettings")!;
+			const settings = persistence.load("settings.json", descriptor, () => new GameSettings(), {
+				store,
+				saveOnChange: true,
+				debounce: 2,
+			});
+			resources.insertResourceByTypeDescriptor(settings, descriptor);
+
+			settings.volume = 0.5;
+			persistence.saveChanged();
+			now = 1;
+			persistence.saveChanged();
+			expect(store.read("settings.json")).to.equal(undefined);
+
+			// 值再次变化，重新开始计时
+			settings.volume = 0.25;
+			persistence.saveChanged();
+			now = 2.5;
+			persistence.saveChanged();
+			expect(store.read("settings.json")).to.equal(undefined);
+
+			now = 3;
+			persistence.saveChanged();
+			const reloaded = createApp(store).getResource<GameSettings>()!;
+			expect(reloaded.volume).to.equal(0.25);
+		});
+	});
+};
@@ -0,0 +1,100 @@
+/**
+ * @fileoverview 只运行一次的启动系统测试
+ */
+
+import { component, World } from "@rbxts/matter";
+import { App } from "../app";
+import { BasePlugin } from "../plugin";
+import { RunOnceFlags } from "../run-once";
+
+const Spawned = component<{ value: number }>("RunOnceSpawned");
+
+/**
+ * 生成一个实体的启动系统
+ */
+function spawnOnce(world: World): void {
+	world.spawn(Spawned({ value: 1 }));
+}
+
+/**
+ * 注册启动系统的测试插件
+ */
+class SpawnPlugin extends BasePlugin {
+	build(app: App): void {
+		app.addStartupOnce(spawnOnce);
+	}
+
+	name(): string {
+		return "SpawnPlugin";
+	}
+
+	isUnique(): boolean {
+		return false;
+	}
+}
+
+/**
+ * 统计 Spawned 实体数量
+ */
+function countSpawned(app: App): number {
+	let count = 0;
+	for (const _ of app.getWorld().query(Spawned)) {
+		count++;
+	}
+	return count;
+}
+
+export = () => {
+	describe("addStartupOnce", () => {
+		it("重复注册的启动系统应只执行一次", () => {
+			const app = App.create();
+			app.addStartupOnce(spawnOnce);
+			app.addStartupOnce(spawnOnce);
+
+			app.update();
+			app.update();
+
+			expect(countSpawned(app)).to.equal(1);
+			expect(app.getResource<RunOnceFlags>()!.hasRun("spawnOnce")).to.equal(true);
+		});
+
+		it("插件被重复添加时启动系统应只执行一次", () => {
+			const app = App.create();
+			app.addPlugin(new SpawnPlugin());
+			app.addPlugin(new SpawnPlugin());
+
+			app.update();
+
+			expect(countSpawned(app)).to.equal(1);
+		});
+
+		it("相同键的不同函数实例不应再次执行", () => {
+			const app = App.create();
+			const createSpawnSystem = (value: number) => (world: World) => {
+				world.spawn(Spawned({ value }));
+			};
+
+			app.addStartupOnce(createSpawnSystem(1), "spawnLevel");
+			app.addStartupOnce(createSpawnSystem(2), "spawnLevel");
+
+			app.update();
+			app.update();
+
+			expect(countSpawned(app)).to.equal(1);
+			for (const [, spawned] of app.getWorld().query(Spawned)) {
+				expect(spawned.value).to.equal(1);
+			}
+			expect(app.getResource<RunOnceFlags>()!.hasRun("spawnLevel")).to.equal(true);
+		});
+
+		it("未提供键时应按函数名识别系统", () => {
+			const app = App.create();
+			const flags = new RunOnceFlags();
+			app.insertResource(flags);
+
+			app.addStartupOnce(spawnOnce);
+
+			expect(flags.register("spawnOnce")).to.equal(false);
+		});
+	});
+};
@@ -0,0 +1,53 @@
+/**
+ * @fileoverview 调度图 DOT 导出测试
+ */
+
+import { App } from "../app";
+import { BuiltinSchedules } from "../main-schedule";
+import { system } from "../../bevy_ecs/schedule";
+import type { World } from "@rbxts/matter";
+import type { Context } from "../../bevy_ecs";
+
+/**
+ * 检查文本是否包含子串
+ * @param text - 文本
+ * @param pattern - 子串
+ */
+function contains(text: string, pattern: string): boolean {
+	return text.find(pattern, 1, true)[0] !== undefined;
+}
+
+export = () => {
+	describe("dumpScheduleDot", () => {
+		function readInput(world: World, context: Context) {}
+		function applyForces(world: World, context: Context) {}
+		function integrate(world: World, context: Context) {}
+
+		it("应包含系统节点、约束边和系统集 cluster", () => {
+			const app = App.create();
+			app.addSystems(
+				BuiltinSchedules.UPDATE,
+				readInput,
+				system(applyForces).after(readInput).inSet("Physics"),
+				system(integrate).inSet("Physics"),
+			);
+
+			const dot = app.dumpScheduleDot(BuiltinSchedules.UPDATE);
+
+			expect(contains(dot, 'digraph "Update"')).to.equal(true);
+			expect(contains(dot, '[label="readInput"]')).to.equal(true);
+			expect(contains(dot, '[label="applyForces"]')).to.equal(true);
+			expect(contains(dot, '[label="integrate"]')).to.equal(true);
+			expect(contains(dot, 'label="Physics";')).to.equal(true);
+
+			const [readInputId] = dot.match('"([^"]+)" %[label="readInput"%]');
+			const [applyForcesId] = dot.match('"([^"]+)" %[label="applyForces"%]');
+			expect(readInputId).to.be.ok();
+			expect(contains(dot, `"${readInputId}" -> "${applyForcesId}" [label="after"];`)).to.equal(true);
+		});
+
+		it("调度不存在时应报错", () => {
+			expect(() => App.create().dumpScheduleDot("MissingSchedule")).to.throw();
+		});
+	});
+};
@@ -0,0 +1,89 @@
+/**
+ * @fileoverview 自定义阶段测试
+ */
+
+import { App } from "../app";
+import { BuiltinSchedules } from "../main-schedule";
+import { StageError } from "../stages";
+import { inStage } from "../../bevy_ecs/schedule";
+import type { World } from "@rbxts/matter";
+import type { Context } from "../../bevy_ecs";
+
+export = () => {
+	describe("Custom Stages", () => {
+		let app: App;
+		let executionOrder: string[];
+
+		beforeEach(() => {
+			app = App.create();
+			executionOrder = [];
+		});
+
+		it("系统应按声明的阶段顺序执行", () => {
+			expect(app.addStage("Input")).to.equal(undefined);
+			expect(app.addStage("Render", "Input")).to.equal(undefined);
+			// 插入到 Input 与 Render 之间
+			expect(app.addStage("Simulate", "Input")).to.equal(undefined);
+
+			const render = (world: World, context: Context) => {
+				executionOrder.push("render");
+			};
+			const simulate = (world: World, context: Context) => {
+				executionOrder.push("simulate");
+			};
+			const input = (world: World, context: Context) => {
+				executionOrder.push("input");
+			};
+
+			app.addSystems(
+				BuiltinSchedules.UPDATE,
+				inStage(render, "Render"),
+				inStage(simulate, "Simulate"),
+				inStage(input, "Input"),
+			);
+			app.update();
+
+			expect(executionOrder.size()).to.equal(3);
+			expect(executionOrder[0]).to.equal("input");
+			expect(executionOrder[1]).to.equal("simulate");
+			expect(executionOrder[2]).to.equal("render");
+
+			const stages = app.main().getStages(BuiltinSchedules.UPDATE);
+			expect(stages[0]).to.equal("Input");
+			expect(stages[1]).to.equal("Simulate");
+			expect(stages[2]).to.equal("Render");
+		});
+
+		it("省略 after 时应追加到最后一个阶段之后", () => {
+			app.addStage("First");
+			app.addStage("Second");
+
+			const second = (world: World, context: Context) => {
+				executionOrder.push("second");
+			};
+			const first = (world: World, context: Context) => {
+				executionOrder.push("first");
+			};
+
+			app.addSystems(BuiltinSchedules.UPDATE, inStage(second, "Second"), inStage(first, "First"));
+			app.update();
+
+			expect(executionOrder[0]).to.equal("first");
+			expect(executionOrder[1]).to.equal("second");
+		});
+
+		it("after 指向不存在的阶段时应返回错误", () => {
+			const err = app.addStage("Simulate", "Missing");
+
+			expect(err instanceof StageError).to.equal(true);
+			expect(err!.stage).to.equal("Simulate");
+			expect(app.main().getStages(BuiltinSchedules.UPDATE).size()).to.equal(0);
+		});
+
+		it("重复添加阶段应返回错误", () => {
+			app.addStage("Input");
+
+			expect(app.addStage("Input") instanceof StageError).to.equal(true);
+		});
+	});
+};
@@ -0,0 +1,61 @@
+/**
+ * @fileoverview 资源替换与变更检测测试
+ */
+
+import { App } from "../app";
+import { BuiltinSchedules } from "../main-schedule";
+import { resourceChanged, system } from "../../bevy_ecs/schedule";
+
+/**
+ * 测试用配置资源
+ */
+class DifficultyConfig {
+	readonly __brand = "Resource" as const;
+	constructor(public readonly level: number) {}
+}
+
+/**
+ * 未插入的测试资源
+ */
+class MissingConfig {
+	readonly __brand = "Resource" as const;
+}
+
+export = () => {
+	describe("swapResource", () => {
+		it("应返回旧值并使依赖系统在替换后只运行一次", () => {
+			const app = App.create();
+			app.insertResource(new DifficultyConfig(1));
+
+			const observed: number[] = [];
+			app.addSystems(
+				BuiltinSchedules.UPDATE,
+				system(() => {
+					observed.push(app.getResource<DifficultyConfig>()!.level);
+				}).runIf(resourceChanged<DifficultyConfig>()),
+			);
+
+			// 首次运行时资源已存在，视为变更
+			app.update();
+			app.update();
+			expect(observed.size()).to.equal(1);
+
+			const previous = app.swapResource(new DifficultyConfig(3));
+			expect(previous.level).to.equal(1);
+			expect(app.getResource<DifficultyConfig>()!.level).to.equal(3);
+
+			app.update();
+			app.update();
+			app.update();
+
+			expect(observed.size()).to.equal(2);
+			expect(observed[1]).to.equal(3);
+		});
+
+		it("资源不存在时应报错", () => {
+			const app = App.create();
+			expect(() => app.swapResource(new MissingConfig())).to.throw();
+			expect(app.getResource<MissingConfig>()).to.equal(undefined);
+		});
+	});
+};
@@ -0,0 +1,78 @@
+/**
+ * @fileoverview 系统耗时预算测试
+ */
+
+import { App } from "../app";
+import { BuiltinSchedules } from "../main-schedule";
+import { SystemBudget } from "../system-budget";
+import { ProfilingStats, SystemTimingHooks } from "../profiling";
+import { Duration } from "../../bevy_time";
+import type { World } from "@rbxts/matter";
+import type { Context } from "../../bevy_ecs";
+
+export = () => {
+	describe("SystemBudget", () => {
+		function slowSystem(world: World, context: Context) {
+			const startTime = os.clock();
+			while (os.clock() - startTime < 0.002) {
+				// 忙等待约 2ms
+			}
+		}
+
+		function trivialSystem(world: World, context: Context) {}
+
+		it("未设置预算时不应创建 SystemBudget", () => {
+			const app = App.create();
+			app.addSystems(BuiltinSchedules.UPDATE, slowSystem);
+			app.update();
+
+			expect(app.getResource<SystemBudget>()).to.equal(undefined);
+		});
+
+		it("超出预算的系统应被记录，且每秒只警告一次", () => {
+			const app = App.create().setSystemBudget(Duration.fromMicros(500));
+			app.addSystems(BuiltinSchedules.UPDATE, slowSystem, trivialSystem);
+
+			for (let frame = 0; frame < 3; frame++) {
+				app.update();
+			}
+
+			const violations = app.getResource<SystemBudget>()!.getViolations();
+			expect(violations.size()).to.equal(1);
+			expect(violations[0].name).to.equal("slowSystem");
+			expect(violations[0].schedule).to.equal(BuiltinSchedules.UPDATE);
+			expect(violations[0].elapsed > 0.5).to.equal(true);
+		});
+
+		it("与性能分析同时开启时应共用同一次计时", () => {
+			const app = App.create().enableProfiling().setSystemBudget(Duration.fromMicros(500));
+			app.addSystems(BuiltinSchedules.UPDATE, slowSystem);
+
+			app.update();
+
+			const violations = app.getResource<SystemBudget>()!.getViolations();
+			const timing = app.getResource<ProfilingStats>()!.getTiming("slowSystem")!;
+			expect(app.getResource<SystemTimingHooks>()).to.be.ok();
+			expect(violations.size()).to.equal(1);
+			expect(violations[0].elapsed).to.equal(timing.total);
+		});
+
+		it("再次设置预算时只修改预算", () => {
+			const app = App.create().setSystemBudget(Duration.fromMillis(2));
+			app.setSystemBudget(Duration.fromMillis(5));
+
+			expect(app.getResource<SystemBudget>()!.getBudget()).to.equal(5);
+		});
+
+		it("间隔超过一秒后应再次警告", () => {
+			const budget = new SystemBudget(1);
+
+			expect(budget.check("a", "a", BuiltinSchedules.UPDATE, 5, 10)).to.equal(true);
+			expect(budget.check("a", "a", BuiltinSchedules.UPDATE, 5, 10.5)).to.equal(false);
+			expect(budget.check("b", "b", BuiltinSchedules.UPDATE, 5, 10.5)).to.equal(true);
+			expect(budget.check("a", "a", BuiltinSchedules.UPDATE, 0.5, 12)).to.equal(false);
+			expect(budget.check("a", "a", BuiltinSchedules.UPDATE, 5, 12)).to.equal(true);
+			expect(budget.getViolations().size()).to.equal(3);
+		});
+	});
+};
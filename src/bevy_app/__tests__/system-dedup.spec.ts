@@ -0,0 +1,117 @@
+/**
+ * @fileoverview 系统去重策略测试
+ */
+
+import { App } from "../app";
+import { BuiltinSchedules } from "../main-schedule";
+import { DedupPolicy } from "../system-dedup";
+import type { World } from "@rbxts/matter";
+import type { Context } from "../../bevy_ecs";
+
+export = () => {
+	describe("System Dedup", () => {
+		let app: App;
+		let runCount = 0;
+
+		const countingSystem = (world: World, context: Context) => {
+			runCount++;
+		};
+
+		beforeEach(() => {
+			app = App.create();
+			runCount = 0;
+		});
+
+		it("未设置策略时应保持原有行为：重复添加报错", () => {
+			app.addSystems(BuiltinSchedules.UPDATE, countingSystem);
+			expect(() => app.addSystems(BuiltinSchedules.UPDATE, countingSystem)).to.throw(
+				"was registered more than once in schedule",
+			);
+		});
+
+		it("Skip 策略下重复注册的系统每帧只运行一次", () => {
+			app.dedupSystems(DedupPolicy.Skip);
+			app.addSystems(BuiltinSchedules.UPDATE, countingSystem);
+			app.addSystems(BuiltinSchedules.UPDATE, countingSystem);
+			app.addSystems(BuiltinSchedules.UPDATE, countingSystem);
+
+			app.update();
+			expect(runCount).to.equal(1);
+
+			app.update();
+			expect(runCount).to.equal(2);
+		});
+
+		it("Skip 策略应处理同一次调用中的重复系统", () => {
+			app.dedupSystems(DedupPolicy.Skip);
+			app.addSystems(BuiltinSchedules.UPDATE, countingSystem, countingSystem);
+
+			app.update();
+			expect(runCount).to.equal(1);
+		});
+
+		it("Skip 策略只在同一调度内去重", () => {
+			app.dedupSystems(DedupPolicy.Skip);
+			app.addSystems(BuiltinSchedules.UPDATE, countingSystem);
+			app.addSystems(BuiltinSchedules.POST_UPDATE, countingSystem);
+
+			app.update();
+			expect(runCount).to.equal(2);
+		});
+
+		it("Warn 策略应保留额外的运行槽位", () => {
+			app.dedupSystems(DedupPolicy.Warn);
+			app.addSystems(BuiltinSchedules.UPDATE, countingSystem);
+			app.addSystems(BuiltinSchedules.UPDATE, countingSystem);
+			app.addSystems(BuiltinSchedules.UPDATE, countingSystem);
+
+			app.update();
+			expect(runCount).to.equal(3);
+		});
+
+		it("Panic 策略下重复注册应报错", () => {
+			app.dedupSystems(DedupPolicy.Panic);
+			app.addSystems(BuiltinSchedules.UPDATE, countingSystem);
+
+			expect(() => app.addSystems(BuiltinSchedules.UPDATE, countingSystem)).to.throw(
+				"was registered more than once in schedule",
+			);
+		});
+	});
+
+	describe("Cross Schedule Duplicates", () => {
+		let app: App;
+
+		const movePlayer = (world: World, context: Context) => {};
+
+		beforeEach(() => {
+			app = App.create().warnCrossScheduleDuplicates();
+		});
+
+		it("同一系统注册到两个调度时应记录两个调度标签", () => {
+			app.addSystems(BuiltinSchedules.UPDATE, movePlayer);
+			app.addSystems(BuiltinSchedules.FIXED_UPDATE, movePlayer);
+
+			const duplicates = app.main().getSystemDedup().getCrossScheduleDuplicates();
+			expect(duplicates.size()).to.equal(1);
+			expect(duplicates[0].existing).to.equal(BuiltinSchedules.UPDATE);
+			expect(duplicates[0].schedule).to.equal(BuiltinSchedules.FIXED_UPDATE);
+		});
+
+		it("allowMultiSchedule 后不应警告", () => {
+			app.allowMultiSchedule(movePlayer);
+			app.addSystems(BuiltinSchedules.UPDATE, movePlayer);
+			app.addSystems(BuiltinSchedules.FIXED_UPDATE, movePlayer);
+
+			expect(app.main().getSystemDedup().getCrossScheduleDuplicates().size()).to.equal(0);
+		});
+
+		it("未开启检查时不应记录跨调度重复", () => {
+			const plain = App.create();
+			plain.addSystems(BuiltinSchedules.UPDATE, movePlayer);
+			plain.addSystems(BuiltinSchedules.FIXED_UPDATE, movePlayer);
+
+			expect(plain.main().getSystemDedup().getCrossScheduleDuplicates().size()).to.equal(0);
+		});
+	});
+};
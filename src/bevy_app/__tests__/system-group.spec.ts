@@ -0,0 +1,146 @@
+/**
+ * @fileoverview 系统组测试
+ */
+
+import { App } from "../app";
+import { BuiltinSchedules } from "../main-schedule";
+import { DedupPolicy } from "../system-dedup";
+import { forceSingleThread } from "../system-group";
+import { intoSystemConfigs } from "../../bevy_ecs/schedule/system-configs";
+import type { World } from "@rbxts/matter";
+import type { Context } from "../../bevy_ecs";
+
+export = () => {
+	describe("System Group", () => {
+		let app: App;
+		let executionOrder: string[];
+
+		const createSystem = (name: string) => {
+			return (world: World, context: Context) => {
+				executionOrder.push(name);
+			};
+		};
+
+		beforeEach(() => {
+			app = App.create();
+			executionOrder = [];
+		});
+
+		it("同一调度内同名的组应返回同一个实例", () => {
+			const group = app.systemGroup(BuiltinSchedules.UPDATE, "Physics");
+			expect(app.systemGroup(BuiltinSchedules.UPDATE, "Physics")).to.equal(group);
+			expect(app.systemGroup(BuiltinSchedules.POST_UPDATE, "Physics")).never.to.equal(group);
+		});
+
+		it("链式组内系统应按添加顺序执行", () => {
+			app.systemGroup(BuiltinSchedules.UPDATE, "Sequence")
+				.chain()
+				.add(createSystem("first"), createSystem("second"), createSystem("third"));
+
+			app.update();
+
+			expect(executionOrder.size()).to.equal(3);
+			expect(executionOrder[0]).to.equal("first");
+			expect(executionOrder[1]).to.equal("second");
+			expect(executionOrder[2]).to.equal("third");
+		});
+
+		it("组之间的 before/after 顺序应在多帧中保持一致", () => {
+			// 故意以与执行顺序相反的顺序注册
+			app.systemGroup(BuiltinSchedules.UPDATE, "Render")
+				.after("Physics")
+				.add(createSystem("render"));
+			app.systemGroup(BuiltinSchedules.UPDATE, "Physics")
+				.chain()
+				.add(createSystem("integrate"), createSystem("collide"));
+			app.systemGroup(BuiltinSchedules.UPDATE, "Input")
+				.before("Physics")
+				.add(createSystem("input"));
+
+			for (let frame = 0; frame < 3; frame++) {
+				executionOrder = [];
+				app.update();
+
+				expect(executionOrder.size()).to.equal(4);
+				expect(executionOrder[0]).to.equal("input");
+				expect(executionOrder[1]).to.equal("integrate");
+				expect(executionOrder[2]).to.equal("collide");
+				expect(executionOrder[3]).to.equal("render");
+			}
+		});
+
+		it("启用去重时同一系统加入两个组只注册一次", () => {
+			app.dedupSystems(DedupPolicy.Skip);
+			const shared = createSystem("shared");
+
+			app.systemGroup(BuiltinSchedules.UPDATE, "GroupA").add(shared);
+			app.systemGroup(BuiltinSchedules.UPDATE, "GroupB").add(shared);
+
+			app.update();
+
+			expect(executionOrder.size()).to.equal(1);
+		});
+
+		it("被去重跳过的系统不应记录到组中，也不应成为链式组的前驱", () => {
+			app.dedupSystems(DedupPolicy.Skip);
+			const shared = createSystem("shared");
+			const next = createSystem("next");
+
+			app.systemGroup(BuiltinSchedules.UPDATE, "GroupA").add(shared);
+			const groupB = app.systemGroup(BuiltinSchedules.UPDATE, "GroupB").chain().add(shared, next);
+
+			expect(groupB.getSystems().size()).to.equal(1);
+			expect(groupB.getSystems()[0]).to.equal(next);
+
+			const [nextEntry] = app
+				.getSchedule(BuiltinSchedules.UPDATE)!
+				.getSystemsInRegistrationOrder()
+				.filter((system) => system.system === next);
+			expect(nextEntry.after).to.equal(undefined);
+		});
+
+		it("互斥组内的系统不应重叠执行", () => {
+			// 这里不使用锁检测重叠：Matter Loop 在同一线程上逐个调用系统且系统不能 yield，
+			// Luau 中两个系统不可能同时处于执行中，因此只需验证组内的串行顺序
+			app.addSystemsExclusiveGroup(BuiltinSchedules.UPDATE, "Audio", createSystem("play"), createSystem("mix"));
+
+			for (let frame = 0; frame < 10; frame++) {
+				app.update();
+			}
+
+			// 组内系统串行链接，每帧严格按 play、mix 交替执行
+			expect(executionOrder.size()).to.equal(20);
+			for (let index = 0; index < executionOrder.size(); index++) {
+				expect(executionOrder[index]).to.equal(index % 2 === 0 ? "play" : "mix");
+			}
+		});
+
+		it("forceSingleThread 应保留原有配置并设置 singleThread 标记", () => {
+			const system = createSystem("main");
+			const dependency = createSystem("dependency");
+
+			const forced = forceSingleThread(intoSystemConfigs(system).after(dependency)).toSystemConfigs();
+
+			expect(forced.size()).to.equal(1);
+			expect(forced[0].system).to.equal(system);
+			expect(forced[0].after!.size()).to.equal(1);
+			expect(forced[0].after![0]).to.equal(dependency);
+			expect(forced[0].singleThread).to.equal(true);
+		});
+
+		it("注册后应能从调度中读取 singleThread 标记", () => {
+			const pinned = createSystem("pinned");
+			const plain = createSystem("plain");
+
+			app.addSystems(BuiltinSchedules.UPDATE, forceSingleThread(pinned));
+			app.addSystems(BuiltinSchedules.UPDATE, plain);
+
+			const systems = app.getSchedule(BuiltinSchedules.UPDATE)!.getSystemsInRegistrationOrder();
+			const [pinnedEntry] = systems.filter((entry) => entry.system === pinned);
+			const [plainEntry] = systems.filter((entry) => entry.system === plain);
+
+			expect(pinnedEntry.singleThread).to.equal(true);
+			expect(plainEntry.singleThread).never.to.equal(true);
+		});
+	});
+};
@@ -0,0 +1,74 @@
+/**
+ * @fileoverview 系统注册表测试
+ */
+
+import { App } from "../app";
+import { BuiltinSchedules } from "../main-schedule";
+import { DedupPolicy } from "../system-dedup";
+import { SystemRegistry } from "../system-registry";
+import type { World } from "@rbxts/matter";
+import type { BevyWorld, Context } from "../../bevy_ecs";
+
+export = () => {
+	describe("System Registry", () => {
+		let app: App;
+
+		function movementSystem(world: World, context: Context) {}
+		function physicsSystem(world: World, context: Context) {}
+		function renderSystem(world: World, context: Context) {}
+
+		beforeEach(() => {
+			app = App.create();
+		});
+
+		it("应按调度列出已注册的系统", () => {
+			app.addSystems(BuiltinSchedules.UPDATE, movementSystem, physicsSystem);
+			app.addSystems(BuiltinSchedules.POST_UPDATE, renderSystem);
+
+			const registry = app.getResource<SystemRegistry>()!;
+			expect(registry).to.be.ok();
+
+			const updateSystems = registry.systemsIn(BuiltinSchedules.UPDATE);
+			expect(updateSystems.size()).to.equal(2);
+			expect(updateSystems[0].system).to.equal(movementSystem);
+			expect(updateSystems[0].schedule).to.equal(BuiltinSchedules.UPDATE);
+			expect(updateSystems[1].system).to.equal(physicsSystem);
+
+			const postUpdateSystems = registry.systemsIn(BuiltinSchedules.POST_UPDATE);
+			expect(postUpdateSystems.size()).to.equal(1);
+			expect(postUpdateSystems[0].name).to.equal("renderSystem");
+			expect(postUpdateSystems[0].schedule).to.equal(BuiltinSchedules.POST_UPDATE);
+
+			expect(registry.systemsIn(BuiltinSchedules.LAST).size()).to.equal(0);
+		});
+
+		it("应记录系统所属的系统集", () => {
+			app.systemGroup(BuiltinSchedules.UPDATE, "Physics").add(physicsSystem);
+
+			const info = app.getResource<SystemRegistry>()!.find(BuiltinSchedules.UPDATE, physicsSystem);
+			expect(info).to.be.ok();
+			expect(info!.sets.size()).to.equal(1);
+			expect(info!.sets[0]).to.equal("Physics");
+		});
+
+		it("重复注册的系统只出现一次", () => {
+			app.dedupSystems(DedupPolicy.Warn);
+			app.addSystems(BuiltinSchedules.UPDATE, movementSystem);
+			app.addSystems(BuiltinSchedules.UPDATE, movementSystem);
+
+			expect(app.getResource<SystemRegistry>()!.systemsIn(BuiltinSchedules.UPDATE).size()).to.equal(1);
+		});
+
+		it("应能在系统中通过资源访问", () => {
+			let systemCount = 0;
+			app.addSystems(BuiltinSchedules.UPDATE, movementSystem);
+			app.addSystems(BuiltinSchedules.UPDATE, (world: BevyWorld) => {
+				const registry = world.resources.getResource<SystemRegistry>();
+				systemCount = registry ? registry.systemsIn(BuiltinSchedules.UPDATE).size() : 0;
+			});
+
+			app.update();
+			expect(systemCount).to.equal(2);
+		});
+	});
+};
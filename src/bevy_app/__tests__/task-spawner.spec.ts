@@ -0,0 +1,65 @@
+/**
+ * @fileoverview 异步任务测试
+ */
+
+import { App } from "../app";
+import { TaskCompleted, TaskSpawner } from "../task-spawner";
+
+export = () => {
+	describe("TaskSpawner", () => {
+		let app: App;
+
+		beforeEach(() => {
+			app = App.create();
+		});
+
+		it("立即完成的任务应在一帧内发送完成消息", () => {
+			const reader = app.world().world.messages.createReader<TaskCompleted<number>>();
+			const handle = app.getResource<TaskSpawner>()!.spawn<number>(Promise.resolve(42));
+
+			app.update();
+
+			const completed = reader.read();
+			expect(completed.size()).to.equal(1);
+			expect(completed[0].handleId).to.equal(handle.id);
+			expect(completed[0].success).to.equal(true);
+			expect(completed[0].value).to.equal(42);
+			expect(handle.isFinished()).to.equal(true);
+			expect(app.getResource<TaskSpawner>()!.pendingCount()).to.equal(0);
+		});
+
+		it("已取消的任务不应发送完成消息", () => {
+			const reader = app.world().world.messages.createReader<TaskCompleted<number>>();
+			const handle = app.getResource<TaskSpawner>()!.spawn<number>(Promise.resolve(1));
+			handle.cancel();
+
+			app.update();
+
+			expect(reader.read().size()).to.equal(0);
+			expect(handle.isCancelled()).to.equal(true);
+			expect(app.getResource<TaskSpawner>()!.pendingCount()).to.equal(0);
+		});
+
+		it("失败的任务应发送带错误的完成消息", () => {
+			const reader = app.world().world.messages.createReader<TaskCompleted<number>>();
+			app.getResource<TaskSpawner>()!.spawn<number>(Promise.reject("timeout"));
+
+			app.update();
+
+			const completed = reader.read();
+			expect(completed.size()).to.equal(1);
+			expect(completed[0].success).to.equal(false);
+			expect(completed[0].err).to.equal("timeout");
+		});
+
+		it("未完成的任务应保持等待", () => {
+			const reader = app.world().world.messages.createReader<TaskCompleted<number>>();
+			app.getResource<TaskSpawner>()!.spawn<number>(new Promise<number>(() => {}));
+
+			app.update();
+
+			expect(reader.read().size()).to.equal(0);
+			expect(app.getResource<TaskSpawner>()!.pendingCount()).to.equal(1);
+		});
+	});
+};
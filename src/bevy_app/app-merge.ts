@@ -0,0 +1,165 @@
+/**
+ * App 合并
+ * 将独立构建的 App 模块合并到另一个 App 中，用于把可复用的功能拆分为各自的 App 再组合
+ *
+ * 合并转移的内容：
+ * - 插件：在目标 App 中重新 build；目标已有同名唯一插件时跳过，由其他插件添加的插件随外层插件一起重建
+ * - 系统：直接添加的系统按注册顺序加入相同的调度，系统集配置一并转移；目标调度中已注册的同一系统函数会被跳过
+ * - 资源：App 创建时自带的默认资源保留目标的实例；其余资源在目标中已存在时保留目标的实例并报告冲突
+ *
+ * 插件在 build 中注册的系统和插入的资源不会被复制：系统闭包可能捕获了源 App（例如 TimePlugin 的时间系统），
+ * 复制后仍会读写源 App，因此由重新 build 在目标 App 中重建。
+ * 源 App 中对这些资源的修改不会保留。
+ *
+ * 实体和消息缓冲区不会被转移。合并必须在两个 App 首次 update 之前进行，
+ * 因此源 App 的启动系统会在合并后的 App 首次 update 时运行一次。
+ */
+
+import type { ComponentId } from "../bevy_ecs/component/component-id";
+import type {
+	InternalSystemStruct,
+	ScheduleLabel,
+	SystemConfig,
+	SystemSet,
+	SystemSetConfig,
+} from "../bevy_ecs/schedule/types";
+import type { Plugin } from "./plugin";
+import type { SubApp } from "./sub-app";
+
+/**
+ * 合并冲突
+ * 合并本身已经完成，冲突的资源保留目标 App 中的实例
+ */
+export class MergeConflict {
+	public name = "MergeConflict";
+	public readonly message: string;
+
+	/**
+	 * 创建合并冲突
+	 * @param resources - 两个 App 中都存在的非默认资源类型名称
+	 */
+	constructor(public readonly resources: ReadonlyArray<string>) {
+		this.message = `Resources exist in both apps, kept the target's: ${resources.join(", ")}`;
+	}
+
+	/**
+	 * 将错误转换为字符串
+	 * @returns 错误信息字符串
+	 */
+	toString(): string {
+		return `${this.name}: ${this.message}`;
+	}
+}
+
+/**
+ * 合并系统集配置
+ * 目标中已存在同名系统集时追加源配置中的 before/after
+ * @param target - 目标 SubApp
+ * @param label - 调度标签
+ * @param config - 源系统集配置
+ */
+function mergeSetConfig(target: SubApp, label: ScheduleLabel, config: SystemSetConfig): void {
+	const schedules = target.getSchedules();
+	const existing = schedules.getSchedule(label).getGraph().systemSets.get(config.name);
+	if (existing === undefined) {
+		schedules.configureSetInSchedule(label, {
+			name: config.name,
+			before: [...(config.before ?? [])],
+			after: [...(config.after ?? [])],
+			runCondition: config.runCondition,
+		});
+		return;
+	}
+
+	// 调度保存的是配置对象本身，直接补全其中的 before/after
+	const writable = existing as { before?: Array<SystemSet>; after?: Array<SystemSet> };
+	const before = (writable.before ??= []);
+	for (const set of config.before ?? []) {
+		if (!before.includes(set)) {
+			before.push(set);
+		}
+	}
+	const after = (writable.after ??= []);
+	for (const set of config.after ?? []) {
+		if (!after.includes(set)) {
+			after.push(set);
+		}
+	}
+}
+
+/**
+ * 将系统结构还原为注册时的配置
+ * @param system - 调度中的系统结构
+ */
+function toSystemConfig(system: InternalSystemStruct): SystemConfig {
+	return {
+		system: system.system,
+		name: system.name,
+		runCondition: system.runCondition,
+		inSet: system.inSet,
+		before: system.before,
+		after: system.after,
+		priority: system.priority,
+		exclusive: system.exclusive,
+		singleThread: system.singleThread,
+	};
+}
+
+/**
+ * 将源 SubApp 的插件、系统和资源合并到目标 SubApp
+ * @param target - 目标 SubApp
+ * @param source - 源 SubApp
+ * @param sourceDefaults - 源 App 创建时自带的资源，这些资源不会被转移
+ * @param addPlugin - 在目标 App 中添加并构建插件
+ * @returns 存在冲突的资源时返回 MergeConflict
+ */
+export function mergeSubApps(
+	target: SubApp,
+	source: SubApp,
+	sourceDefaults: ReadonlySet<ComponentId>,
+	addPlugin: (plugin: Plugin) => void,
+): MergeConflict | undefined {
+	for (const plugin of source.getPlugins()) {
+		if (source.isNestedPlugin(plugin) || (plugin.isUnique() && target.hasPlugin(plugin.name()))) {
+			continue;
+		}
+		addPlugin(plugin);
+	}
+
+	const sourceSchedules = source.getSchedules();
+	for (const label of sourceSchedules.getScheduleLabels()) {
+		const schedule = sourceSchedules.getSchedule(label);
+		for (const [, setConfig] of schedule.getGraph().systemSets) {
+			mergeSetConfig(target, label, setConfig);
+		}
+
+		for (const system of schedule.getSystemsInRegistrationOrder()) {
+			if (source.isPluginSystem(system.system) || target.getSystemDedup().isRegistered(label, system.system)) {
+				continue;
+			}
+			target.addSystemConfig(label, toSystemConfig(system));
+		}
+	}
+
+	const conflicts: string[] = [];
+	const sourceResources = source.getResourceManager();
+	const targetResources = target.getResourceManager();
+	for (const [id, resource] of sourceResources.getAllResources()) {
+		if (sourceDefaults.has(id) || source.isPluginResource(id)) {
+			continue;
+		}
+
+		const descriptor = sourceResources.getResourceMetadataById(id)!.typeDescriptor;
+		if (targetResources.hasResourceByDescriptor(descriptor)) {
+			conflicts.push(descriptor.text);
+			continue;
+		}
+		targetResources.insertResourceByTypeDescriptor(resource, descriptor);
+	}
+
+	if (conflicts.size() > 0) {
+		conflicts.sort((a, b) => a < b);
+		return new MergeConflict(conflicts);
+	}
+	return undefined;
+}
@@ -6,17 +6,66 @@ import { Modding } from "@flamework/core";
 
 import { AppExit, AppExitCode, AppLabel, ErrorHandler } from "./types";
 import { BuiltinSchedules } from "./main-schedule";
-import { DuplicatePluginError, isPluginGroup, Plugin, PluginGroup, PluginState } from "./plugin";
+import { DuplicatePluginError, isPluginGroup, Plugin, PluginError, PluginGroup, PluginState } from "./plugin";
+import { sortPluginsByDependencies } from "./plugin-dependencies";
 import { SubApp, SubApps } from "./sub-app";
 import { Context, World, WorldContainer } from "../bevy_ecs";
 import { Schedule } from "../bevy_ecs/schedule/schedule";
-import type { ScheduleLabel, SystemFunction } from "../bevy_ecs/schedule/types";
+import type { ScheduleLabel, SystemFunction, SystemSet } from "../bevy_ecs/schedule/types";
 import type { IntoSystemConfigs } from "../bevy_ecs/schedule";
+import { intoSystemConfigs } from "../bevy_ecs/schedule/system-configs";
+import { anyComponentChanged } from "../bevy_ecs/schedule/common-conditions";
 import type { Diagnostic, DiagnosticsStore } from "../bevy_diagnostic/diagnostic";
 import { RunService } from "@rbxts/services";
+import type { AnyComponent, AnyEntity } from "@rbxts/matter";
 import { isMatchRobloxContext, RobloxContext } from "../utils/roblox-utils";
-import { Message, MessageReader, MessageWriter } from "../bevy_ecs/message";
-import { TypeDescriptor } from "../bevy_core/reflect";
+import { BoundedMessageStats, Message, MessageOverflowPolicy, MessageReader, MessageWriter } from "../bevy_ecs/message";
+import { getGenericTypeDescriptor, getTypeDescriptor, TypeDescriptor } from "../bevy_core/reflect";
+import { DedupPolicy } from "./system-dedup";
+import { BuildEnv } from "./build-env";
+import { CliArgs } from "./cli-args";
+import { getRunOnceKey, RunOnceFlags } from "./run-once";
+import { ResourceInitQueue } from "./resource-init";
+import { pollTasksSystem, TaskSpawner } from "./task-spawner";
+import type { ConfigError, ConfiguredPlugin, PluginConfig } from "./plugin-config";
+import { createFrameLimitedRunner, FrameStats } from "./frame-limiter";
+import { createPanicIsolationWrapper, SystemPanicLog } from "./panic-isolation";
+import { ComponentHook, createComponentAddedSystem, createComponentRemovedSystem } from "./component-hooks";
+import { createTimingWrapper, ProfilingStats, SystemTimingHook, SystemTimingHooks } from "./profiling";
+import { SystemBudget } from "./system-budget";
+import type { Duration } from "../bevy_time/duration";
+import { PauseControl, PauseGroup } from "./pause-control";
+import { ConsoleCommandHandler, ConsoleCommands } from "./console-commands";
+import type { ConsoleError } from "./console-commands";
+import { ResourcePersistence, ResourcePersistencePlugin } from "./resource-persistence";
+import type { PersistOptions } from "./resource-persistence";
+import { scheduleToDot } from "./schedule-dot";
+import { mergeSubApps } from "./app-merge";
+import type { MergeConflict } from "./app-merge";
+import type { ComponentId } from "../bevy_ecs/component/component-id";
+import { createEventRecorderSystem, createEventReplaySystem, EventLog } from "./event-replay";
+import { installLogger, LoggerConfig } from "../bevy_log/logger";
+import {
+	collectOrderingCycleErrors,
+	collectPluginDependencyErrors,
+	FrameworkBuildError,
+	FrameworkBuildErrorKind,
+} from "./build-error";
+import type { SystemGroup } from "./system-group";
+import type { StageError, StageLabel } from "./stages";
+import { SnapshotRegistry, WorldSnapshot } from "../bevy_ecs/snapshot";
+import { cleanupEntityNamesSystem, EntityNames } from "../bevy_ecs/entity-names";
+import { ChannelReceiver, ChannelSender, createChannel } from "../bevy_ecs/channel";
+import {
+	RequiredComponents,
+	RequirementBuilder,
+	RequirementPolicy,
+	validateRequiredComponentsSystem,
+} from "../bevy_ecs/required-components";
+import type { ComponentCtor } from "../bevy_ecs/query";
+import { ArchetypeBuilder, Archetypes } from "../bevy_ecs/archetypes";
+import { singleEntity, spawnSingleton } from "../bevy_ecs/singleton";
+import type { SingletonError } from "../bevy_ecs/singleton";
 
 /**
  * 扩展工厂函数类型
@@ -101,6 +150,13 @@ export class App<T extends Context = Context> {
 	private defaultErrorHandler?: ErrorHandler;
 	readonly context: T;
 	private appExitEventReader?: MessageReader<AppExit>;
+	private shutdownExitReader?: MessageReader<AppExit>;
+	private buildEnv = new BuildEnv();
+	private requiredResources: TypeDescriptor[] = [];
+	private buildFailures: FrameworkBuildError[] = [];
+	private resourceInits = new ResourceInitQueue();
+	/** App 创建时自带的资源，合并时不会被转移到其他 App */
+	private defaultResourceIds = new Set<ComponentId>();
 
 	/**
 	 * 创建App实例
@@ -117,6 +173,10 @@ export class App<T extends Context = Context> {
 
 		// 初始化主应用
 		this.initializeMainApp();
+
+		for (const id of this.subApps.main().getResourceManager().getResourceIds()) {
+			this.defaultResourceIds.add(id);
+		}
 	}
 
 	/**
@@ -149,12 +209,25 @@ export class App<T extends Context = Context> {
 
 		// 创建 AppExit 消息读取器
 		this.appExitEventReader = this.world().world.messages.createReader<AppExit>();
+		// 独立的读取器，用于关闭检测，不影响 shouldExit 的读取
+		this.shutdownExitReader = this.world().world.messages.createReader<AppExit>();
 
 		// 添加基础调度
 		this.initializeDefaultSchedules();
 
 		// 添加AppExit消息处理
 		this.addMessage<AppExit>();
+
+		// 启动参数只解析一次，随构建环境一起替换
+		this.insertResource<CliArgs>(this.buildEnv.cli);
+
+		// 异步任务在 PreUpdate 中轮询，完成消息在同一帧的 Update 中即可读取
+		this.insertResource(new TaskSpawner(this.world().world.messages));
+		this.addSystems(BuiltinSchedules.PRE_UPDATE, pollTasksSystem);
+
+		// 命令在帧末执行，First 中清理可以覆盖通过命令销毁的实体
+		this.insertResource(new EntityNames());
+		this.addSystems(BuiltinSchedules.FIRST, cleanupEntityNamesSystem);
 	}
 
 	/**
@@ -198,6 +271,7 @@ export class App<T extends Context = Context> {
 		}
 
 		this.subApps.update();
+		this.processExitRequests();
 	}
 
 	/**
@@ -230,6 +304,25 @@ export class App<T extends Context = Context> {
 		return this;
 	}
 
+	/**
+	 * 限制帧率
+	 * 将运行器替换为按目标帧率循环更新的运行器，用于没有垂直同步的无头/服务端 App，
+	 * 并插入 FrameStats 资源记录实际帧率。运行器在收到 AppExit 后返回
+	 * @param targetFps - 目标帧率，0 表示不限制
+	 * @returns 当前App实例，支持链式调用
+	 *
+	 * @example
+	 * app.limitFps(30).run();
+	 * print(app.getResource<FrameStats>()!.fps());
+	 */
+	limitFps(targetFps: number): this {
+		assert(targetFps >= 0, "limitFps: target frame rate must not be negative");
+
+		const stats = new FrameStats(targetFps);
+		this.insertResource(stats);
+		return this.setRunner(createFrameLimitedRunner(stats));
+	}
+
 	/**
 	 * 默认运行器实现
 	 * 对应 Rust 的 run_once 函数
@@ -245,13 +338,12 @@ export class App<T extends Context = Context> {
 			task.wait();
 		}
 
-		// 完成插件设置
-		// 对应 Rust: app.finish()
-		this.finish();
-
-		// 清理插件
-		// 对应 Rust: app.cleanup()
-		this.cleanup();
+		// 完成插件设置并校验构建结果
+		// 对应 Rust: app.finish() + app.cleanup()
+		const buildError = this.tryBuild();
+		if (buildError) {
+			error(buildError.toString());
+		}
 
 		// 执行一次更新（这会运行 Main 调度，包含启动和常规调度）
 		// 对应 Rust: app.update()
@@ -274,10 +366,15 @@ export class App<T extends Context = Context> {
 	/**
 	 * 完成所有插件的设置
 	 * 对应 Rust App::finish
-	 * 调用所有插件的 finish 方法
+	 * 调用所有插件的 finish 方法，然后按依赖顺序初始化 initResourceAfter 声明的资源
 	 */
 	finish(): void {
 		this.subApps.finish();
+
+		const errors = this.resourceInits.flush(this.subApps.main().getResourceManager());
+		for (const err of errors) {
+			this.buildFailures.push(err);
+		}
 	}
 
 	/**
@@ -289,6 +386,99 @@ export class App<T extends Context = Context> {
 		this.subApps.cleanup();
 	}
 
+	/**
+	 * 完成构建并收集构建错误
+	 * 调用插件的 finish/cleanup（如尚未调用），然后检查：
+	 * - initResourceAfter 声明的依赖资源是否都已插入
+	 * - requireResource 声明的资源是否存在
+	 * - 插件依赖是否都已添加、是否存在循环依赖
+	 * - 插件通过 requires() 声明的版本要求是否满足
+	 * - insertExclusiveResource 是否被重复调用
+	 * - 系统的 before/after 约束是否成环（在编译调度之前检查，错误中列出环上的系统）
+	 * - 调度能否成功编译
+	 *
+	 * 默认运行器在启动时调用此方法，发生错误时直接报错
+	 * @returns 构建成功返回 undefined；否则返回构建错误，多个错误时类型为 Multiple
+	 */
+	tryBuild(): FrameworkBuildError | undefined {
+		const state = this.getPluginState();
+		if (state !== PluginState.Finished && state !== PluginState.Cleaned) {
+			this.finish();
+		}
+		if (this.getPluginState() !== PluginState.Cleaned) {
+			this.cleanup();
+		}
+
+		const mainApp = this.subApps.main();
+		const errors: FrameworkBuildError[] = [...this.buildFailures];
+
+		for (const descriptor of this.requiredResources) {
+			if (!mainApp.getResourceManager().hasResourceByDescriptor(descriptor)) {
+				errors.push(FrameworkBuildError.missingResource(descriptor.text));
+			}
+		}
+
+		for (const dependencyError of collectPluginDependencyErrors(mainApp.getPlugins())) {
+			errors.push(dependencyError);
+		}
+
+		for (const cycleError of collectOrderingCycleErrors(mainApp.getSchedules())) {
+			errors.push(cycleError);
+		}
+
+		// 只有在配置正确时才编译调度，编译后无法再添加系统
+		if (errors.size() === 0) {
+			const [success, compileError] = pcall(() => mainApp.getSchedules().compile());
+			if (!success) {
+				errors.push(
+					new FrameworkBuildError(
+						FrameworkBuildErrorKind.ScheduleBuildFailed,
+						"Failed to compile schedules",
+						compileError,
+					),
+				);
+			}
+		}
+
+		return FrameworkBuildError.fromErrors(errors);
+	}
+
+	/**
+	 * 合并另一个 App
+	 * 将 other 的插件、系统和资源转移到当前 App，用于组合各自独立构建的功能模块：
+	 * - 插件在当前 App 中重新 build，当前 App 已有同名唯一插件时跳过
+	 * - 插件注册的系统和插入的资源由重新 build 重建，不会从 other 复制；它们可能捕获了 other
+	 * - 直接添加的系统按注册顺序加入相同调度；当前 App 中已注册的同一系统函数会被跳过
+	 * - 直接插入的资源在当前 App 中已存在时保留当前 App 的实例并作为冲突返回；App 自带的默认资源不参与合并
+	 *
+	 * 两个 App 都必须尚未 update，other 的启动系统会在合并后首次 update 时运行一次。
+	 * 实体和消息缓冲区不会被转移，合并后不应再使用 other
+	 * @param other - 要合并的 App
+	 * @returns 合并成功返回 undefined；存在冲突的资源时返回 MergeConflict
+	 *
+	 * @example
+	 * const combat = App.create().addPlugin(new CombatPlugin());
+	 * const conflict = app.merge(combat);
+	 */
+	merge(other: App): MergeConflict | undefined {
+		assert(other !== this, "App.merge: cannot merge an app into itself");
+		for (const app of [this, other]) {
+			const state = app.getPluginState();
+			assert(
+				state !== PluginState.Finished && state !== PluginState.Cleaned,
+				"App.merge must be called before either app is built",
+			);
+			assert(
+				!app.subApps.main().getSchedules().getOverallStats().compiled,
+				"App.merge must be called before either app is updated",
+			);
+		}
+
+		return mergeSubApps(this.subApps.main(), other.subApps.main(), other.defaultResourceIds, (plugin) =>
+			this.addBoxedPlugin(plugin),
+		);
+	}
+
 	/**
 	 * 检查是否正在构建插件
 	 * @returns 是否正在构建插件
@@ -342,6 +532,165 @@ export class App<T extends Context = Context> {
 		return this as unknown as App<T & ExtractAllPluginExtensions<P>>;
 	}
 
+	/**
+	 * 按条件添加插件
+	 * 条件为 false 时插件不会被构建，不会注册任何系统或资源
+	 * @param condition - 是否添加插件
+	 * @param plugin - 要添加的插件实例
+	 * @returns 当前App实例，支持链式调用
+	 *
+	 * @example
+	 * app.addPluginIf(RunService.IsStudio(), new DebuggerPlugin());
+	 */
+	addPluginIf(condition: boolean, plugin: Plugin<any>): this {
+		if (condition) {
+			this.addPlugin(plugin);
+		}
+		return this;
+	}
+
+	/**
+	 * 根据构建环境添加插件
+	 * 工厂函数返回 undefined 时不添加任何插件
+	 * @param factory - 插件工厂，接收当前构建环境
+	 * @returns 当前App实例，支持链式调用
+	 *
+	 * @example
+	 * app.addPluginWith((env) => (env.hasFlag("--profile") ? new ProfilerPlugin() : undefined));
+	 */
+	addPluginWith(factory: (env: BuildEnv) => Plugin<any> | undefined): this {
+		const plugin = factory(this.buildEnv);
+		if (plugin !== undefined) {
+			this.addPlugin(plugin);
+		}
+		return this;
+	}
+
+	/**
+	 * 设置构建环境
+	 * 影响之后的 addPluginWith 调用，并替换 CliArgs 资源
+	 * @param env - 构建环境
+	 * @returns 当前App实例，支持链式调用
+	 */
+	setBuildEnv(env: BuildEnv): this {
+		this.buildEnv = env;
+		this.insertResource<CliArgs>(env.cli);
+		return this;
+	}
+
+	/**
+	 * 使用指定的启动参数替换构建环境中的参数
+	 * 保留当前的环境变量，主要用于测试或在 Roblox 中模拟启动参数
+	 * @param args - 启动参数列表
+	 * @returns 当前App实例，支持链式调用
+	 *
+	 * @example
+	 * app.withArgs(["--port", "8080", "--verbose"]);
+	 * app.getResource<CliArgs>()!.getNumber("port"); // 8080
+	 */
+	withArgs(args: ReadonlyArray<string>): this {
+		return this.setBuildEnv(new BuildEnv({ args, vars: this.buildEnv.vars }));
+	}
+
+	/**
+	 * 选择日志后端并立即安装为全局日志订阅器
+	 * 应在添加任何插件之前调用，这样插件构建期间的日志也会使用选定的格式；
+	 * 之后添加的 LogPlugin 不会覆盖已安装的订阅器
+	 * @param config - 日志后端配置
+	 * @returns 当前App实例，支持链式调用
+	 *
+	 * @example
+	 * App.create().withLogger({ format: LoggerFormat.Json }).addPlugins(...DefaultPlugins.create().build());
+	 */
+	withLogger(config: LoggerConfig): this {
+		installLogger(config);
+		if (this.subApps.main().getPlugins().size() > 0) {
+			warn("[App] withLogger was called after plugins were added, their build logs used the previous logger");
+		}
+		return this;
+	}
+
+	/**
+	 * 获取构建环境
+	 * @returns 当前构建环境
+	 */
+	getBuildEnv(): BuildEnv {
+		return this.buildEnv;
+	}
+
+	/**
+	 * 按依赖顺序添加多个插件
+	 * 根据每个插件的 dependencies() 进行拓扑排序，依赖插件先于使用者构建，与传入顺序无关。
+	 * 依赖可以是本次传入的插件，也可以是已添加到 App 的插件。
+	 *
+	 * 排序失败时不会添加任何插件。
+	 *
+	 * @param plugins - 要添加的插件
+	 * @returns 成功时返回 undefined；存在循环依赖时返回 CyclicDependencyError，依赖缺失时返回 MissingDependencyError
+	 *
+	 * @example
+	 * ```typescript
+	 * const err = app.addPluginsWithDeps(new RenderPlugin(), new WindowPlugin());
+	 * if (err) {
+	 *     warn(err.toString());
+	 * }
+	 * ```
+	 */
+	addPluginsWithDeps(...plugins: Plugin<any>[]): PluginError | undefined {
+		const mainApp = this.subApps.main();
+		const sorted = sortPluginsByDependencies(plugins, (id) => mainApp.hasPlugin(id));
+
+		if (sorted instanceof PluginError) {
+			return sorted;
+		}
+
+		for (const plugin of sorted) {
+			this.addPlugin(plugin);
+		}
+		return undefined;
+	}
+
+	/**
+	 * 添加带配置的插件
+	 * 先校验配置，校验失败时直接返回错误，不插入配置也不构建插件；
+	 * 校验通过后配置作为资源插入，插件的 build 和系统可以通过 getResource<C>() 读取
+	 *
+	 * **注意**: @claudecode, @cursor - 这是一个宏方法，Modding.* 类型参数由编译器自动提供
+	 * @metadata macro
+	 * @template C - 配置类型
+	 * @param plugin - 插件实例
+	 * @param config - 插件配置
+	 * @param id - 配置类型标识符（由宏自动提供）
+	 * @param text - 配置类型文本描述（由宏自动提供）
+	 * @returns 成功时返回 undefined，配置无效时返回 ConfigError
+	 *
+	 * @example
+	 * ```typescript
+	 * const err = app.addConfiguredPlugin(new InventoryPlugin(), new InventoryConfig(32));
+	 * if (err) {
+	 *     error(err.toString());
+	 * }
+	 * ```
+	 */
+	addConfiguredPlugin<C extends PluginConfig>(
+		plugin: ConfiguredPlugin<C>,
+		config: C,
+		id?: Modding.Generic<C, "id">,
+		text?: Modding.Generic<C, "text">,
+	): ConfigError | undefined {
+		const validationError = config.validate();
+		if (validationError !== undefined) {
+			return validationError;
+		}
+
+		const descriptor = getTypeDescriptor(id, text);
+		assert(descriptor, "addConfiguredPlugin: can't get type descriptor, this is likely a macro issue");
+
+		this.insertResourceByTypeDescriptor(config, descriptor);
+		this.addPlugin(plugin);
+		return undefined;
+	}
+
 	/**
 	 * 内部插件添加逻辑
 	 * 处理插件注册和扩展工厂转换
@@ -364,6 +713,7 @@ export class App<T extends Context = Context> {
 				const extension = plugin.getExtension!(this);
 				assert(extension,"the return value of getExtension() is undefined:"+plugin.name());
 				this.insertResourceByTypeDescriptor(extension,plugin.extensionDescriptor!);
+				mainApp.markPluginResource(plugin.extensionDescriptor!);
 			}
 		}
 	}
@@ -415,6 +765,501 @@ export class App<T extends Context = Context> {
 		return this;
 	}
 
+	/**
+	 * 添加只运行一次的启动系统
+	 * 同一个键无论注册多少次（例如插件被重复添加），系统体在 App 生命周期内只执行一次，
+	 * 即使每次注册的是不同的函数实例
+	 * @param system - 系统函数
+	 * @param key - 识别系统的键，默认使用系统函数名；匿名函数必须提供
+	 * @returns 当前App实例，支持链式调用
+	 *
+	 * @example
+	 * app.addStartupOnce(spawnPlayer);
+	 * app.addStartupOnce(spawnPlayer); // 被忽略
+	 * app.addStartupOnce((world) => spawnLevel(world, config), "spawnLevel");
+	 */
+	addStartupOnce(system: SystemFunction, key?: string): this {
+		const runOnceKey = getRunOnceKey(system, key);
+		let flags = this.getResource<RunOnceFlags>();
+		if (flags === undefined) {
+			flags = new RunOnceFlags();
+			this.insertResource(flags);
+		}
+
+		if (!flags.register(runOnceKey)) {
+			return this;
+		}
+
+		const runOnceFlags = flags;
+		this.subApps
+			.main()
+			.addSystems(BuiltinSchedules.STARTUP, intoSystemConfigs(system).runIf(() => runOnceFlags.claim(runOnceKey)));
+		return this;
+	}
+
+	/**
+	 * 开启系统性能分析
+	 * 为每个系统记录执行耗时到 ProfilingStats 资源；未调用时系统不会被包装，没有额外开销。
+	 * 必须在调度编译（首次 update）之前调用，重复调用无效
+	 * @returns 当前App实例，支持链式调用
+	 *
+	 * @example
+	 * app.enableProfiling();
+	 * app.update();
+	 * for (const timing of app.getResource<ProfilingStats>()!.report()) {
+	 *     print(timing.name, timing.total);
+	 * }
+	 */
+	enableProfiling(): this {
+		if (this.getResource<ProfilingStats>() !== undefined) {
+			return this;
+		}
+
+		const stats = new ProfilingStats();
+		this.addSystemTimingHook((id, name, schedule, elapsed) => stats.record(id, name, schedule, elapsed));
+		this.insertResource(stats);
+		return this;
+	}
+
+	/**
+	 * 设置单个系统单帧的耗时预算
+	 * 系统某次执行超过预算时输出警告并记录到 SystemBudget 资源，同一系统每秒最多警告一次。
+	 * 未调用时系统不会被包装，没有额外开销；与 enableProfiling 共用同一个计时包装器。
+	 * 首次调用必须在调度编译（首次 update）之前；之后再次调用只修改预算
+	 * @param budget - 耗时预算
+	 * @returns 当前App实例，支持链式调用
+	 *
+	 * @example
+	 * app.setSystemBudget(Duration.fromMillis(2));
+	 */
+	setSystemBudget(budget: Duration): this {
+		const millis = budget.asMillis();
+		assert(millis >= 0, "setSystemBudget: budget must not be negative");
+
+		const existing = this.getResource<SystemBudget>();
+		if (existing !== undefined) {
+			existing.setBudget(millis);
+			return this;
+		}
+
+		const systemBudget = new SystemBudget(millis);
+		this.addSystemTimingHook((id, name, schedule, elapsed) => systemBudget.check(id, name, schedule, elapsed));
+		this.insertResource(systemBudget);
+		return this;
+	}
+
+	/**
+	 * 添加系统计时钩子
+	 * 首次添加时注册共享的计时包装器，之后的钩子复用同一次测量
+	 * @param hook - 计时钩子
+	 */
+	private addSystemTimingHook(hook: SystemTimingHook): void {
+		let hooks = this.getResource<SystemTimingHooks>();
+		if (hooks === undefined) {
+			hooks = new SystemTimingHooks();
+			this.subApps.main().getSchedules().addSystemWrapper(createTimingWrapper(hooks));
+			this.insertResource(hooks);
+		}
+		hooks.add(hook);
+	}
+
+	/**
+	 * 开启系统错误隔离
+	 * 每个系统在 pcall 中执行，出错时记录系统名称与错误到 SystemPanicLog 资源，
+	 * 并在之后的帧中禁用该系统，而不是中断整个 App。
+	 * 出错前已经产生的修改不会回滚，见 panic-isolation 模块说明。
+	 * 必须在调度编译（首次 update）之前调用，重复调用无效
+	 * @returns 当前App实例，支持链式调用
+	 */
+	isolatePanics(): this {
+		if (this.getResource<SystemPanicLog>() !== undefined) {
+			return this;
+		}
+
+		const log = new SystemPanicLog();
+		this.subApps.main().getSchedules().addSystemWrapper(createPanicIsolationWrapper(log));
+		this.insertResource(log);
+		return this;
+	}
+
+	/**
+	 * 注册组件添加回调
+	 * 在 Update 中添加一个检测系统，组件被添加到实体后的下一次运行时调用回调
+	 * @param component - 组件构造函数
+	 * @param callback - 回调，参数为实体和命令缓冲区
+	 * @returns 当前App实例，支持链式调用
+	 *
+	 * @example
+	 * app.onComponentAdded(Health, (entity, commands) => {
+	 *     commands.addComponent(entity, HealthBar({ visible: true }));
+	 * });
+	 */
+	onComponentAdded(component: ComponentCtor, callback: ComponentHook): this {
+		this.subApps.main().addSystems(BuiltinSchedules.UPDATE, createComponentAddedSystem(component, callback));
+		return this;
+	}
+
+	/**
+	 * 注册组件移除回调
+	 * 在 Update 中添加一个检测系统；回调时组件已不存在，但仍能收到对应的实体
+	 * @param component - 组件构造函数
+	 * @param callback - 回调，参数为实体和命令缓冲区
+	 * @returns 当前App实例，支持链式调用
+	 */
+	onComponentRemoved(component: ComponentCtor, callback: ComponentHook): this {
+		this.subApps.main().addSystems(BuiltinSchedules.UPDATE, createComponentRemovedSystem(component, callback));
+		return this;
+	}
+
+	/**
+	 * 声明必需组件
+	 * 在 Studio 中首次声明时自动开启检查（Warn 策略），其他环境需要调用 validateRequirements
+	 * @param component - 声明依赖的组件
+	 * @returns 声明构建器，通过 with() 添加必需组件
+	 *
+	 * @example
+	 * app.requireComponent(Velocity).with(Position);
+	 */
+	requireComponent(component: ComponentCtor): RequirementBuilder {
+		const registry = this.getOrInsertRequiredComponents();
+		if (RunService.IsStudio() && !registry.validationEnabled) {
+			this.validateRequirements(registry.policy);
+		}
+		return registry.require(component);
+	}
+
+	/**
+	 * 开启必需组件检查
+	 * 在每帧的 Last 中检查违反声明的实体，按策略输出警告或报错，违反记录可通过 RequiredComponents.getLastViolations 获取。
+	 * 重复调用只更新策略
+	 * @param policy - 处理策略，默认为 Warn
+	 * @returns 当前App实例，支持链式调用
+	 */
+	validateRequirements(policy: RequirementPolicy = RequirementPolicy.Warn): this {
+		const registry = this.getOrInsertRequiredComponents();
+		registry.policy = policy;
+
+		if (!registry.validationEnabled) {
+			registry.validationEnabled = true;
+			this.subApps.main().addSystems(BuiltinSchedules.LAST, validateRequiredComponentsSystem);
+		}
+		return this;
+	}
+
+	/**
+	 * 获取必需组件注册表，不存在时插入
+	 * @returns 必需组件注册表
+	 */
+	private getOrInsertRequiredComponents(): RequiredComponents {
+		let registry = this.getResource<RequiredComponents>();
+		if (registry === undefined) {
+			registry = new RequiredComponents();
+			this.insertResource(registry);
+		}
+		return registry;
+	}
+
+	/**
+	 * 注册命名原型
+	 * 原型是一组带默认值的组件，之后可以通过 spawnArchetype 按名称生成实体。名称已存在时覆盖旧的声明
+	 * @param name - 原型名称
+	 * @param build - 声明回调，通过 builder.add 添加组件及其默认值
+	 * @returns 当前App实例，支持链式调用
+	 *
+	 * @example
+	 * app.registerArchetype("enemy", (archetype) =>
+	 *     archetype.add(Health, { value: 100 }).add(Position, { x: 0, y: 0 }).add(Velocity, { x: 0, y: 0 }),
+	 * );
+	 */
+	registerArchetype(name: string, build: (builder: ArchetypeBuilder) => void): this {
+		let archetypes = this.getResource<Archetypes>();
+		if (archetypes === undefined) {
+			archetypes = new Archetypes();
+			this.insertResource(archetypes);
+		}
+		archetypes.register(name, build);
+		return this;
+	}
+
+	/**
+	 * 按命名原型生成实体
+	 * overrides 中与原型组件类型相同的实例替换默认值；原型未注册时输出警告且不生成实体。
+	 * 系统中可以通过 world.resources.getResource<Archetypes>() 获取注册表后调用 spawn
+	 * @param name - 原型名称
+	 * @param overrides - 覆盖默认值的组件实例
+	 * @returns 新实体，原型未注册时返回 undefined
+	 *
+	 * @example
+	 * const boss = app.spawnArchetype("enemy", [Health({ value: 1000 })]);
+	 */
+	spawnArchetype(name: string, overrides: ReadonlyArray<AnyComponent> = []): AnyEntity | undefined {
+		const archetypes = this.getResource<Archetypes>();
+		if (archetypes === undefined) {
+			warn(`[Archetypes] Archetype "${name}" is not registered, nothing was spawned`);
+			return undefined;
+		}
+		return archetypes.spawn(this.getWorld(), name, overrides);
+	}
+
+	/**
+	 * 生成单例实体
+	 * 同一时间最多只有一个实体拥有 marker 标记组件；components 中没有标记组件实例时自动添加
+	 * @param marker - 标记组件
+	 * @param components - 实体的其他组件
+	 * @returns 新实体；单例已存在时返回 SingletonError
+	 *
+	 * @example
+	 * const result = app.spawnSingleton(LocalPlayer, Health({ value: 100 }));
+	 * if (result instanceof SingletonError) { ... }
+	 */
+	spawnSingleton(marker: ComponentCtor, ...components: AnyComponent[]): AnyEntity | SingletonError {
+		return spawnSingleton(this.getWorld(), marker, ...components);
+	}
+
+	/**
+	 * 查找单例实体
+	 * @param marker - 标记组件
+	 * @returns 拥有标记组件的实体，不存在时返回 undefined
+	 */
+	singleEntity(marker: ComponentCtor): AnyEntity | undefined {
+		return singleEntity(this.getWorld(), marker);
+	}
+
+	/**
+	 * 注册控制台命令
+	 * 首次调用时插入 ConsoleCommands 资源；同名命令已存在时覆盖
+	 * @param name - 命令名，不能包含空白
+	 * @param handler - 处理函数，接收世界和解析后的参数
+	 * @param description - 命令说明
+	 * @returns 当前App实例，支持链式调用
+	 *
+	 * @example
+	 * app.registerCommand("spawn_enemy", (world, args) => {
+	 *     const count = tonumber(args[0]) ?? 1;
+	 *     for (let index = 0; index < count; index++) {
+	 *         world.spawn(Enemy({}));
+	 *     }
+	 *     return `Spawned ${count} enemies`;
+	 * });
+	 */
+	registerCommand(name: string, handler: ConsoleCommandHandler, description?: string): this {
+		let commands = this.getResource<ConsoleCommands>();
+		if (commands === undefined) {
+			commands = new ConsoleCommands();
+			this.insertResource(commands);
+		}
+		commands.register(name, handler, description);
+		return this;
+	}
+
+	/**
+	 * 执行一行控制台输入
+	 * @param line - 输入行，例如 `spawn_enemy 3`
+	 * @returns 命令输出；命令未注册、输入无法解析或处理函数报错时返回 ConsoleError
+	 */
+	executeCommand(line: string): string | ConsoleError {
+		const commands = this.getResource<ConsoleCommands>() ?? new ConsoleCommands();
+		return commands.execute(this.getWorld(), line);
+	}
+
+	/**
+	 * 添加响应式系统
+	 * 系统只在有实体添加或修改了指定组件的帧运行；首帧只要存在拥有该组件的实体也会运行
+	 * @param component - 触发系统运行的组件构造函数
+	 * @param system - 系统函数
+	 * @param schedule - 调度标签，默认为 Update
+	 * @returns 当前App实例，支持链式调用
+	 *
+	 * @example
+	 * app.addReactiveSystem(Obstacle, rebuildNavMesh);
+	 */
+	addReactiveSystem(
+		component: ComponentCtor,
+		system: SystemFunction,
+		schedule: ScheduleLabel = BuiltinSchedules.UPDATE,
+	): this {
+		this.subApps.main().addSystems(schedule, intoSystemConfigs(system).runIf(anyComponentChanged(component)));
+		return this;
+	}
+
+	/**
+	 * 开启确定性执行模式，用于可复现的集成测试
+	 * 每个调度内的系统按注册顺序串行执行，相当于对所有系统调用 chain()；
+	 * 显式的 before/after 约束与注册顺序矛盾时，调度编译报错。
+	 * Matter Loop 本身是单线程执行的，因此无需额外设置执行器。
+	 * 必须在调度编译（首次 update）之前调用
+	 * @returns 当前App实例，支持链式调用
+	 */
+	deterministicMode(): this {
+		this.subApps.main().getSchedules().setDeterministic(true);
+		return this;
+	}
+
+	/**
+	 * 注册可以被快照序列化的组件类型
+	 * 首次调用时插入 SnapshotRegistry 资源
+	 * @param component - 组件构造函数
+	 * @param name - 序列化使用的名称，默认使用组件名
+	 * @returns 当前App实例，支持链式调用
+	 */
+	registerSnapshot(component: ComponentCtor, name?: string): this {
+		let registry = this.getResource<SnapshotRegistry>();
+		if (registry === undefined) {
+			registry = new SnapshotRegistry();
+			this.insertResource(registry);
+		}
+
+		registry.register(component, name);
+		return this;
+	}
+
+	/**
+	 * 捕获主 World 的快照
+	 * 只包含通过 registerSnapshot 注册的组件
+	 * @returns World 快照
+	 */
+	captureSnapshot(): WorldSnapshot {
+		const registry = this.getResource<SnapshotRegistry>() ?? new SnapshotRegistry();
+		return WorldSnapshot.capture(this.world().world, registry);
+	}
+
+	/**
+	 * 将快照恢复到主 World
+	 * @param snapshot - World 快照
+	 * @returns 当前App实例，支持链式调用
+	 */
+	restoreSnapshot(snapshot: WorldSnapshot): this {
+		WorldSnapshot.restore(this.world().world, snapshot);
+		return this;
+	}
+
+	/**
+	 * 添加自定义阶段
+	 * 同一调度内的阶段按顺序串联执行，通过 inStage(label) 将系统加入阶段
+	 * @param label - 阶段标签
+	 * @param after - 前一个阶段；省略时追加到最后一个阶段之后
+	 * @param schedule - 阶段所在的调度，默认为 Update
+	 * @returns 阶段已存在或 after 指向不存在的阶段时返回错误
+	 *
+	 * @example
+	 * app.addStage("Input");
+	 * app.addStage("Simulate", "Input");
+	 * app.addSystems(Update, inStage(readInput, "Input"), inStage(integrate, "Simulate"));
+	 */
+	addStage(label: StageLabel, after?: StageLabel, schedule: ScheduleLabel = BuiltinSchedules.UPDATE): StageError | undefined {
+		return this.subApps.main().addStage(schedule, label, after);
+	}
+
+	/**
+	 * 获取系统组构建器
+	 * 组内系统会加入与组同名的系统集，组之间可以通过 before/after 整体排序
+	 * @param schedule - 调度标签
+	 * @param label - 组标签，同一调度内同名的组共享同一个构建器
+	 * @returns 系统组构建器
+	 *
+	 * @example
+	 * app.systemGroup(Update, "Physics").chain().add(integrate, resolveCollisions);
+	 * app.systemGroup(Update, "Render").after("Physics").add(syncTransforms);
+	 */
+	systemGroup(schedule: ScheduleLabel, label: SystemSet): SystemGroup {
+		return this.subApps.main().systemGroup(schedule, label);
+	}
+
+	/**
+	 * 添加互斥系统组
+	 * 组内系统加入与组同名的系统集，并按添加顺序链式执行，任意两个系统都不会同时运行。
+	 * 组内系统之间不能再被重新排序，也无法被并行执行器同时调度，
+	 * 因此只应用于确实需要独占访问的系统（例如共享同一个非线程安全的外部对象）
+	 * @param schedule - 调度标签
+	 * @param label - 组标签，同一调度内同名的组共享同一个系统集
+	 * @param systems - 组内的系统
+	 * @returns 当前App实例，支持链式调用
+	 *
+	 * @example
+	 * app.addSystemsExclusiveGroup(Update, "Audio", playSounds, mixChannels);
+	 */
+	addSystemsExclusiveGroup(schedule: ScheduleLabel, label: SystemSet, ...systems: SystemFunction[]): this {
+		this.subApps.main().systemGroup(schedule, label).chain().add(...systems);
+		return this;
+	}
+
+	/**
+	 * 添加可暂停的系统
+	 * 系统只在 PauseControl.isRunning(group) 为 true 时运行，通过 PauseControl 资源的 pause/resume 控制
+	 * @param group - 暂停组标签
+	 * @param system - 系统或系统配置
+	 * @param schedule - 调度标签，默认为 Update
+	 * @returns 当前App实例，支持链式调用
+	 *
+	 * @example
+	 * app.addPausableSystem("gameplay", movementSystem);
+	 * // 打开暂停菜单时
+	 * world.resources.getResource<PauseControl>()!.pause("gameplay");
+	 */
+	addPausableSystem(
+		group: PauseGroup,
+		system: IntoSystemConfigs,
+		schedule: ScheduleLabel = BuiltinSchedules.UPDATE,
+	): this {
+		let control = this.getResource<PauseControl>();
+		if (control === undefined) {
+			control = new PauseControl();
+			this.insertResource(control);
+		}
+
+		this.subApps.main().addSystems(
+			schedule,
+			intoSystemConfigs(system).runIf((world) => {
+				const pauseControl = world.resources.getResource<PauseControl>();
+				return pauseControl === undefined || pauseControl.isRunning(group);
+			}),
+		);
+		return this;
+	}
+
+	/**
+	 * 设置重复系统注册的处理策略
+	 * 未设置时，同一系统重复添加到同一调度会由 Schedule 直接报错
+	 * @param policy - 去重策略：Warn 警告后仍注册，Skip 静默跳过，Panic 报错
+	 * @returns 当前App实例，支持链式调用
+	 *
+	 * @example
+	 * app.dedupSystems(DedupPolicy.Skip);
+	 * app.addSystems(Update, mySystem);
+	 * app.addSystems(Update, mySystem); // 被跳过，mySystem 每帧只运行一次
+	 */
+	dedupSystems(policy: DedupPolicy): this {
+		this.subApps.main().setDedupPolicy(policy);
+		return this;
+	}
+
+	/**
+	 * 开启跨调度重复检查
+	 * 同一系统函数被注册到多个调度时输出警告，并同时给出两个调度标签；
+	 * 检测结果可以通过 main().getSystemDedup().getCrossScheduleDuplicates() 获取
+	 * @returns 当前App实例，支持链式调用
+	 *
+	 * @example
+	 * app.warnCrossScheduleDuplicates();
+	 * app.addSystems(Update, movePlayer);
+	 * app.addSystems(FixedUpdate, movePlayer); // 警告：movePlayer 同时注册在 Update 和 FixedUpdate 中
+	 */
+	warnCrossScheduleDuplicates(): this {
+		this.subApps.main().getSystemDedup().enableCrossScheduleCheck();
+		return this;
+	}
+
+	/**
+	 * 允许系统注册到多个调度
+	 * 跨调度重复检查不再对该系统警告，应在添加系统之前调用
+	 * @param system - 系统函数
+	 * @returns 当前App实例，支持链式调用
+	 */
+	allowMultiSchedule(system: SystemFunction): this {
+		this.subApps.main().getSystemDedup().allowMultiSchedule(system);
+		return this;
+	}
+
 	/**
 	 * 仅在服务端添加系统
 	 * @param schedule - 调度标签
@@ -452,6 +1297,132 @@ export class App<T extends Context = Context> {
 		return this;
 	}
 
+	/**
+	 * 添加有界消息类型
+	 * 每帧最多缓存 capacity 条消息，超出部分按 policy 处理；
+	 * 丢弃数量记录在 BoundedMessageStats<T> 资源中
+	 *
+	 * **注意**: @claudecode, @cursor - 这是一个宏方法，Modding.* 类型参数由编译器自动提供
+	 * @metadata macro
+	 * @template T - 消息类型
+	 * @param capacity - 每帧消息缓冲区的容量
+	 * @param policy - 溢出策略，默认丢弃新消息
+	 * @param id - 消息类型标识符（由宏自动提供）
+	 * @param text - 消息类型文本描述（由宏自动提供）
+	 * @returns 当前App实例，支持链式调用
+	 */
+	addBoundedMessage<T extends Message>(
+		capacity: number,
+		policy: MessageOverflowPolicy = MessageOverflowPolicy.DropNewest,
+		id?: Modding.Generic<T, "id">,
+		text?: Modding.Generic<T, "text">,
+	): this {
+		const descriptor = getTypeDescriptor(id, text);
+		assert(descriptor, "addBoundedMessage: can't get type descriptor, this is likely a macro issue");
+
+		const world = this.subApps.main().world().world;
+		const stats = new BoundedMessageStats<T>(capacity, policy);
+		world.messages.getOrCreateMessages<T>(id, text).setBound(stats);
+		world.resources.insertResourceByTypeDescriptor(stats, getGenericTypeDescriptor<BoundedMessageStats<T>>(descriptor));
+		return this;
+	}
+
+	/**
+	 * 录制消息
+	 * 插入 EventLog<T> 资源，之后每帧写入的 T 消息都会带着帧序号记录到日志中。重复调用不做任何事
+	 *
+	 * **注意**: @claudecode, @cursor - 这是一个宏方法，Modding.* 类型参数由编译器自动提供
+	 * @metadata macro
+	 * @template T - 消息类型
+	 * @param id - 消息类型标识符（由宏自动提供）
+	 * @param text - 消息类型文本描述（由宏自动提供）
+	 * @returns 当前App实例，支持链式调用
+	 *
+	 * @example
+	 * app.recordEvents<DamageEvent>();
+	 * // ...运行若干帧后
+	 * const log = app.getResource<EventLog<DamageEvent>>()!;
+	 */
+	recordEvents<T extends Message>(id?: Modding.Generic<T, "id">, text?: Modding.Generic<T, "text">): this {
+		const descriptor = getTypeDescriptor(id, text);
+		assert(descriptor, "recordEvents: can't get type descriptor, this is likely a macro issue");
+
+		const world = this.subApps.main().world().world;
+		const logDescriptor = getGenericTypeDescriptor<EventLog<T>>(descriptor);
+		if (world.resources.getResourceByTypeDescriptor<EventLog<T>>(logDescriptor) !== undefined) {
+			return this;
+		}
+
+		const log = new EventLog<T>();
+		world.resources.insertResourceByTypeDescriptor(log, logDescriptor);
+		const reader = world.messages.createReader<T>(id, text);
+		this.subApps.main().addSystems(BuiltinSchedules.LAST, createEventRecorderSystem(reader, log));
+		return this;
+	}
+
+	/**
+	 * 回放录制的消息
+	 * 每帧在 First 中重新写入日志里帧序号与当前帧相同的消息，帧序号从回放开始后的第一次 update 计为 0。
+	 * 应在全新的 App 上开启 deterministicMode，并用 runFixed 以录制时的步长推进，保证帧对齐
+	 *
+	 * **注意**: @claudecode, @cursor - 这是一个宏方法，Modding.* 类型参数由编译器自动提供
+	 * @metadata macro
+	 * @template T - 消息类型
+	 * @param log - 录制得到的事件日志
+	 * @param id - 消息类型标识符（由宏自动提供）
+	 * @param text - 消息类型文本描述（由宏自动提供）
+	 * @returns 当前App实例，支持链式调用
+	 *
+	 * @example
+	 * const replay = App.create().deterministicMode().addPlugin(new TimePlugin());
+	 * replay.replayEvents<DamageEvent>(log);
+	 * runFixed(replay, 60, Duration.fromSecs(1 / 60));
+	 */
+	replayEvents<T extends Message>(
+		log: EventLog<T>,
+		id?: Modding.Generic<T, "id">,
+		text?: Modding.Generic<T, "text">,
+	): this {
+		const messages = this.subApps.main().world().world.messages.getOrCreateMessages<T>(id, text);
+		this.subApps.main().addSystems(BuiltinSchedules.FIRST, createEventReplaySystem(messages, log));
+		return this;
+	}
+
+
+	/**
+	 * 添加类型化通道
+	 * 插入 ChannelSender<T> 与 ChannelReceiver<T> 资源，两者共享同一个队列；
+	 * 值跨帧保留直到被接收端取出。通道已存在时不做任何事
+	 *
+	 * **注意**: @claudecode, @cursor - 这是一个宏方法，Modding.* 类型参数由编译器自动提供
+	 * @metadata macro
+	 * @template T - 值类型
+	 * @param id - 值类型标识符（由宏自动提供）
+	 * @param text - 值类型文本描述（由宏自动提供）
+	 * @returns 当前App实例，支持链式调用
+	 *
+	 * @example
+	 * app.addChannel<PathRequest>();
+	 * // 生产者
+	 * world.resources.getResource<ChannelSender<PathRequest>>()!.send(request);
+	 * // 消费者
+	 * for (const request of world.resources.getResource<ChannelReceiver<PathRequest>>()!.tryIter()) { ... }
+	 */
+	addChannel<T>(id?: Modding.Generic<T, "id">, text?: Modding.Generic<T, "text">): this {
+		const descriptor = getTypeDescriptor(id, text);
+		assert(descriptor, "addChannel: can't get type descriptor, this is likely a macro issue");
+
+		const resources = this.subApps.main().getResourceManager();
+		const senderDescriptor = getGenericTypeDescriptor<ChannelSender<T>>(descriptor);
+		if (resources.hasResourceByDescriptor(senderDescriptor)) {
+			return this;
+		}
+
+		const [sender, receiver] = createChannel<T>();
+		resources.insertResourceByTypeDescriptor(sender, senderDescriptor);
+		resources.insertResourceByTypeDescriptor(receiver, getGenericTypeDescriptor<ChannelReceiver<T>>(descriptor));
+		return this;
+	}
 
 	/**
 	 * 插入资源到应用程序
@@ -469,6 +1440,103 @@ export class App<T extends Context = Context> {
 		return this
 	}
 
+	/**
+	 * 替换已存在的资源并返回旧值
+	 * 新值会被标记为已变更，使用 resourceChanged 条件的系统会在下一次调度时运行
+	 *
+	 * **注意**: @claudecode, @cursor - 这是一个宏方法，Modding.* 类型参数由编译器自动提供
+	 * @metadata macro
+	 * @template T - 资源对象类型
+	 * @param resource - 新的资源对象
+	 * @param id - 资源类型标识符（由宏自动提供）
+	 * @param text - 资源类型文本描述（由宏自动提供）
+	 * @returns 被替换的旧资源；资源尚未插入时报错
+	 *
+	 * @example
+	 * const previous = app.swapResource(new DifficultyConfig(Difficulty.Hard));
+	 */
+	public swapResource<T extends object>(resource: T, id?: Modding.Generic<T, "id">, text?: Modding.Generic<T, "text">): T {
+		return this.subApps.main().getResourceManager().swapResource(resource, id, text);
+	}
+
+	/**
+	 * 声明构建完成时必须存在的资源
+	 * 由 tryBuild 检查，缺失时返回 MissingResource 错误
+	 *
+	 * **注意**: @claudecode, @cursor - 这是一个宏方法，Modding.* 类型参数由编译器自动提供
+	 * @metadata macro
+	 * @template T - 资源对象类型
+	 * @param id - 资源类型标识符（由宏自动提供）
+	 * @param text - 资源类型文本描述（由宏自动提供）
+	 * @returns 当前App实例，支持链式调用
+	 */
+	public requireResource<T extends object>(id?: Modding.Generic<T, "id">, text?: Modding.Generic<T,"text">): this {
+		const descriptor = getTypeDescriptor(id, text);
+		assert(descriptor, "requireResource: can't get type descriptor, this is likely a macro issue");
+		this.requiredResources.push(descriptor);
+		return this;
+	}
+
+	/**
+	 * 声明在依赖资源存在之后再初始化的资源
+	 * 资源在 finish 阶段按依赖关系依次创建，因此工厂函数可以安全地读取依赖资源，与插件的添加顺序无关。
+	 * 资源已经存在时不会被覆盖；依赖始终没有被插入时，tryBuild 返回 MissingResourceDependency 错误
+	 *
+	 * **注意**: @claudecode, @cursor - 这是一个宏方法，Modding.* 类型参数由编译器自动提供
+	 * @metadata macro
+	 * @template T - 资源对象类型
+	 * @template D - 依赖的资源类型
+	 * @param create - 创建资源的函数
+	 * @param id - 资源类型标识符（由宏自动提供）
+	 * @param text - 资源类型文本描述（由宏自动提供）
+	 * @param dependencyId - 依赖资源类型标识符（由宏自动提供）
+	 * @param dependencyText - 依赖资源类型文本描述（由宏自动提供）
+	 * @returns 当前App实例，支持链式调用
+	 *
+	 * @example
+	 * app.initResourceAfter<SpawnTable, GameConfig>((app) => new SpawnTable(app.getResource<GameConfig>()!));
+	 */
+	public initResourceAfter<T extends object, D extends object>(
+		create: (app: App) => T,
+		id?: Modding.Generic<T, "id">,
+		text?: Modding.Generic<T, "text">,
+		dependencyId?: Modding.Generic<D, "id">,
+		dependencyText?: Modding.Generic<D, "text">,
+	): this {
+		const descriptor = getTypeDescriptor(id, text);
+		const dependency = getTypeDescriptor(dependencyId, dependencyText);
+		assert(descriptor && dependency, "initResourceAfter: can't get type descriptor, this is likely a macro issue");
+
+		this.resourceInits.push(descriptor, dependency, () => create(this as unknown as App));
+		return this;
+	}
+
+	/**
+	 * 插入独占资源
+	 * 该类型的资源已存在时不会覆盖，而是记录 DuplicateResource 错误，由 tryBuild 返回
+	 *
+	 * **注意**: @claudecode, @cursor - 这是一个宏方法，Modding.* 类型参数由编译器自动提供
+	 * @metadata macro
+	 * @template T - 资源对象类型
+	 * @param resource - 要插入的资源对象
+	 * @param id - 资源类型标识符（由宏自动提供）
+	 * @param text - 资源类型文本描述（由宏自动提供）
+	 * @returns 当前App实例，支持链式调用
+	 */
+	public insertExclusiveResource<T extends object>(resource:T, id?: Modding.Generic<T, "id">, text?: Modding.Generic<T,"text">): this {
+		const descriptor = getTypeDescriptor(id, text);
+		assert(descriptor, "insertExclusiveResource: can't get type descriptor, this is likely a macro issue");
+
+		const resourceManager = this.subApps.main().getResourceManager();
+		if (resourceManager.hasResourceByDescriptor(descriptor)) {
+			this.buildFailures.push(FrameworkBuildError.duplicateResource(descriptor.text));
+			return this;
+		}
+
+		resourceManager.insertResourceByTypeDescriptor(resource, descriptor);
+		return this;
+	}
+
 	/**
 	 * 通过类型描述符插入资源
 	 * @template T - 资源对象类型
@@ -508,6 +1576,45 @@ export class App<T extends Context = Context> {
 		return this.subApps.main().getResourceManager().getResourceByTypeDescriptor<T>(typeDescriptor);
 	}
 
+	/**
+	 * 插入持久化资源
+	 * 立即从存储读取上次保存的值，不存在时使用默认值；内容无法解析时输出警告并使用默认值。
+	 * 应用关闭时写回存储；开启 saveOnChange 后，值变化并在 debounce 秒内保持不变时也会写回
+	 *
+	 * **注意**: @claudecode, @cursor - 这是一个宏方法，Modding.* 类型参数由编译器自动提供
+	 * @metadata macro
+	 * @template R - 资源类型
+	 * @param path - 存储路径
+	 * @param createDefault - 创建默认值，也用于承载读取到的字段
+	 * @param options - 持久化选项，必须提供存储
+	 * @param id - 资源类型标识符（由宏自动提供）
+	 * @param text - 资源类型文本描述（由宏自动提供）
+	 * @returns 当前App实例，支持链式调用
+	 *
+	 * @example
+	 * app.persistResource<Settings>("settings", () => new Settings(), { store: settingsStore, saveOnChange: true });
+	 */
+	persistResource<R extends object>(
+		path: string,
+		createDefault: () => R,
+		options: PersistOptions,
+		id?: Modding.Generic<R, "id">,
+		text?: Modding.Generic<R, "text">,
+	): this {
+		const descriptor = getTypeDescriptor(id, text);
+		assert(descriptor, "persistResource: can't get type descriptor, this is likely a macro issue");
+
+		let persistence = this.getResource<ResourcePersistence>();
+		if (persistence === undefined) {
+			persistence = new ResourcePersistence(this.getWorld().resources);
+			this.insertResource(persistence);
+			this.addPlugin(new ResourcePersistencePlugin(persistence));
+		}
+
+		const resource = persistence.load(path, descriptor, createDefault, options);
+		return this.insertResourceByTypeDescriptor(resource, descriptor);
+	}
+
 
 	/**
 	 * 获取World容器
@@ -628,6 +1735,23 @@ export class App<T extends Context = Context> {
 		return this.subApps.main().getSchedule(label);
 	}
 
+	/**
+	 * 将调度的系统排序约束导出为 Graphviz DOT 文本
+	 * 系统为节点，before/after 约束为边，同一系统集的系统聚为一个 cluster
+	 * @param label - 调度标签
+	 * @returns DOT 文本，调度不存在时报错
+	 *
+	 * @example
+	 * ```typescript
+	 * print(app.dumpScheduleDot(BuiltinSchedules.UPDATE));
+	 * ```
+	 */
+	dumpScheduleDot(label: ScheduleLabel): string {
+		const schedule = this.getSchedule(label);
+		assert(schedule, `dumpScheduleDot: schedule "${label}" does not exist`);
+		return scheduleToDot(schedule);
+	}
+
 	/**
 	 * 编辑调度
 	 * 对应 Rust App::edit_schedule
@@ -678,6 +1802,35 @@ export class App<T extends Context = Context> {
 		return events[0];
 	}
 
+	/**
+	 * 处理退出请求
+	 * 检测到 AppExit 事件时关闭应用，多次请求退出也只会关闭一次。
+	 * 手动 update() 模式下每次更新后自动调用，自定义运行器应在每帧调用
+	 * @returns 应用是否已关闭
+	 */
+	processExitRequests(): boolean {
+		if (this.shutdownExitReader && this.shutdownExitReader.read().size() > 0) {
+			this.shutdown();
+		}
+		return this.isShutdown();
+	}
+
+	/**
+	 * 关闭应用
+	 * 按插件注册的逆序调用所有插件的 onShutdown，重复调用无效
+	 */
+	shutdown(): void {
+		this.subApps.shutdown();
+	}
+
+	/**
+	 * 检查应用是否已关闭
+	 * @returns 是否已关闭
+	 */
+	isShutdown(): boolean {
+		return this.subApps.main().isShutdown();
+	}
+
 	/**
 	 * 发送成功退出事件
 	 * 对应 Rust App::exit
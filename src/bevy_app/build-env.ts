@@ -0,0 +1,89 @@
+/**
+ * 构建环境
+ * 插件构建阶段可读取的运行时信息，用于按条件加载插件
+ */
+
+import { RunService } from "@rbxts/services";
+import { RobloxContext } from "../utils/roblox-utils";
+import { CliArgs } from "./cli-args";
+
+/**
+ * 构建环境配置
+ */
+export interface BuildEnvConfig {
+	/** 启动参数 */
+	readonly args?: ReadonlyArray<string>;
+	/** 环境变量 */
+	readonly vars?: ReadonlyMap<string, string>;
+}
+
+/**
+ * 构建环境
+ * 保存启动参数、环境变量以及当前的 Roblox 运行上下文
+ *
+ * @example
+ * ```typescript
+ * app.setBuildEnv(new BuildEnv({ args: ["--debug"] }));
+ * app.addPluginWith((env) => (env.hasFlag("--debug") ? new DebuggerPlugin() : undefined));
+ * ```
+ */
+export class BuildEnv {
+	/** 启动参数 */
+	readonly args: ReadonlyArray<string>;
+	/** 环境变量 */
+	readonly vars: ReadonlyMap<string, string>;
+	/** 当前运行上下文 */
+	readonly robloxContext: RobloxContext;
+	/** 解析后的启动参数 */
+	readonly cli: CliArgs;
+
+	/**
+	 * 创建构建环境
+	 * @param config - 构建环境配置
+	 */
+	constructor(config: BuildEnvConfig = {}) {
+		this.args = config.args ?? [];
+		this.vars = config.vars ?? new Map();
+		this.robloxContext = RunService.IsServer() ? RobloxContext.Server : RobloxContext.Client;
+		this.cli = new CliArgs(this.args);
+	}
+
+	/**
+	 * 检查是否包含指定的启动参数
+	 * @param flag - 参数名，例如 "--debug"
+	 * @returns 是否包含该参数
+	 */
+	hasFlag(flag: string): boolean {
+		return this.args.includes(flag);
+	}
+
+	/**
+	 * 获取环境变量
+	 * @param name - 变量名
+	 * @returns 变量值，不存在时返回 undefined
+	 */
+	getVar(name: string): string | undefined {
+		return this.vars.get(name);
+	}
+
+	/**
+	 * 是否运行在服务端
+	 */
+	isServer(): boolean {
+		return this.robloxContext === RobloxContext.Server;
+	}
+
+	/**
+	 * 是否运行在客户端
+	 */
+	isClient(): boolean {
+		return this.robloxContext === RobloxContext.Client;
+	}
+
+	/**
+	 * 是否运行在 Studio 中
+	 */
+	isStudio(): boolean {
+		return RunService.IsStudio();
+	}
+}
@@ -0,0 +1,206 @@
+/**
+ * App 构建错误
+ * App.tryBuild 收集构建阶段的失败并以错误值返回，而不是直接报错
+ */
+
+import type { Plugin } from "./plugin";
+import { CyclicDependencyError, MissingDependencyError, VersionMismatchError } from "./plugin";
+import { sortPluginsByDependencies } from "./plugin-dependencies";
+import type { Schedules } from "../bevy_ecs/schedule/schedules";
+import { findOrderingCycle } from "./ordering-cycle";
+
+/**
+ * 构建错误类型
+ */
+export enum FrameworkBuildErrorKind {
+	/** 必需的资源在构建完成后仍不存在 */
+	MissingResource = "MissingResource",
+	/** 插件依赖未添加 */
+	MissingDependency = "MissingDependency",
+	/** 插件报告的版本不满足其他插件的版本要求 */
+	VersionMismatch = "VersionMismatch",
+	/** 插件之间存在循环依赖 */
+	CyclicDependency = "CyclicDependency",
+	/** 独占资源被重复插入 */
+	DuplicateResource = "DuplicateResource",
+	/** initResourceAfter 声明的依赖资源始终没有被插入 */
+	MissingResourceDependency = "MissingResourceDependency",
+	/** 系统的 before/after 约束成环，见 source() 返回的 OrderingCycleError */
+	OrderingCycle = "OrderingCycle",
+	/** 调度编译失败（例如系统之间的循环依赖） */
+	ScheduleBuildFailed = "ScheduleBuildFailed",
+	/** 多个构建错误，见 errors */
+	Multiple = "Multiple",
+}
+
+/**
+ * App 构建错误
+ * 通过 source() 访问底层错误，形成错误链
+ */
+export class FrameworkBuildError {
+	public name = "FrameworkBuildError";
+
+	/**
+	 * 创建构建错误
+	 * @param kind - 错误类型
+	 * @param message - 错误信息
+	 * @param sourceError - 导致该错误的底层错误
+	 * @param errors - Multiple 类型时包含的所有错误
+	 */
+	constructor(
+		public readonly kind: FrameworkBuildErrorKind,
+		public readonly message: string,
+		private readonly sourceError?: unknown,
+		public readonly errors: ReadonlyArray<FrameworkBuildError> = [],
+	) {}
+
+	/**
+	 * 获取底层错误
+	 * 对应 Rust std::error::Error::source
+	 * @returns 底层错误，没有时返回 undefined
+	 */
+	source(): unknown {
+		return this.sourceError;
+	}
+
+	/**
+	 * 将错误转换为字符串，包含完整的错误链
+	 * @returns 错误信息字符串
+	 */
+	toString(): string {
+		let result = `${this.name}(${this.kind}): ${this.message}`;
+
+		for (const nested of this.errors) {
+			result += `\n  - ${nested.toString()}`;
+		}
+
+		if (this.sourceError !== undefined) {
+			result += `\n  caused by: ${tostring(this.sourceError)}`;
+		}
+
+		return result;
+	}
+
+	/**
+	 * 创建缺失资源错误
+	 * @param resourceName - 资源类型名称
+	 */
+	static missingResource(resourceName: string): FrameworkBuildError {
+		return new FrameworkBuildError(
+			FrameworkBuildErrorKind.MissingResource,
+			`Required resource "${resourceName}" was not inserted during build`,
+		);
+	}
+
+	/**
+	 * 创建重复资源错误
+	 * @param resourceName - 资源类型名称
+	 */
+	static duplicateResource(resourceName: string): FrameworkBuildError {
+		return new FrameworkBuildError(
+			FrameworkBuildErrorKind.DuplicateResource,
+			`Exclusive resource "${resourceName}" was inserted more than once`,
+		);
+	}
+
+	/**
+	 * 创建缺失资源依赖错误
+	 * @param resourceName - 等待初始化的资源类型名称
+	 * @param dependencyName - 缺失的依赖资源类型名称
+	 */
+	static missingResourceDependency(resourceName: string, dependencyName: string): FrameworkBuildError {
+		return new FrameworkBuildError(
+			FrameworkBuildErrorKind.MissingResourceDependency,
+			`Resource "${resourceName}" could not be initialized: dependency "${dependencyName}" was never inserted`,
+		);
+	}
+
+	/**
+	 * 合并多个错误
+	 * @param errors - 错误列表
+	 * @returns 没有错误时返回 undefined，只有一个错误时直接返回该错误
+	 */
+	static fromErrors(errors: ReadonlyArray<FrameworkBuildError>): FrameworkBuildError | undefined {
+		if (errors.size() === 0) {
+			return undefined;
+		}
+
+		if (errors.size() === 1) {
+			return errors[0];
+		}
+
+		return new FrameworkBuildError(
+			FrameworkBuildErrorKind.Multiple,
+			`${errors.size()} build errors occurred`,
+			undefined,
+			errors,
+		);
+	}
+}
+
+/**
+ * 检查已添加插件的依赖关系
+ * @param plugins - 已添加的插件
+ * @returns 缺失依赖、版本不匹配和循环依赖错误
+ */
+export function collectPluginDependencyErrors(plugins: ReadonlyArray<Plugin<any>>): FrameworkBuildError[] {
+	const errors: FrameworkBuildError[] = [];
+	const pluginsByName = new Map<string, Plugin<any>>();
+	for (const plugin of plugins) {
+		pluginsByName.set(plugin.name(), plugin);
+	}
+
+	for (const plugin of plugins) {
+		const dependencies = plugin.dependencies?.() ?? [];
+		for (const dependency of dependencies) {
+			if (!pluginsByName.has(dependency)) {
+				const source = new MissingDependencyError(plugin.name(), dependency);
+				errors.push(new FrameworkBuildError(FrameworkBuildErrorKind.MissingDependency, source.message, source));
+			}
+		}
+
+		for (const [dependency, required] of plugin.requires?.() ?? []) {
+			const dependencyPlugin = pluginsByName.get(dependency);
+			if (dependencyPlugin === undefined) {
+				// 同时出现在 dependencies() 中时已在上面报告
+				if (!dependencies.includes(dependency)) {
+					const source = new MissingDependencyError(plugin.name(), dependency);
+					errors.push(
+						new FrameworkBuildError(FrameworkBuildErrorKind.MissingDependency, source.message, source),
+					);
+				}
+				continue;
+			}
+
+			const found = dependencyPlugin.version?.();
+			if (found === undefined || !required.matches(found)) {
+				const source = new VersionMismatchError(plugin.name(), dependency, required, found);
+				errors.push(new FrameworkBuildError(FrameworkBuildErrorKind.VersionMismatch, source.message, source));
+			}
+		}
+	}
+
+	// 缺失的依赖已在上面报告，排序时视为已满足，只检测循环
+	const sorted = sortPluginsByDependencies(plugins, () => true);
+	if (sorted instanceof CyclicDependencyError) {
+		errors.push(new FrameworkBuildError(FrameworkBuildErrorKind.CyclicDependency, sorted.message, sorted));
+	}
+
+	return errors;
+}
+
+/**
+ * 检查所有调度中的系统排序环
+ * @param schedules - 调度集合
+ * @returns 每个成环调度的 OrderingCycle 错误
+ */
+export function collectOrderingCycleErrors(schedules: Schedules): FrameworkBuildError[] {
+	const errors: FrameworkBuildError[] = [];
+	for (const label of schedules.getScheduleLabels()) {
+		const cycle = findOrderingCycle(schedules.getSchedule(label));
+		if (cycle !== undefined) {
+			errors.push(new FrameworkBuildError(FrameworkBuildErrorKind.OrderingCycle, cycle.message, cycle));
+		}
+	}
+	return errors;
+}
@@ -0,0 +1,232 @@
+/**
+ * 启动参数解析
+ * 将 BuildEnv 的启动参数解析为 CliArgs 资源，插件可在 finish 或启动系统中读取
+ */
+
+import type { Resource } from "../bevy_ecs/resource";
+
+/**
+ * 启动参数值解析函数
+ * 无法解析时返回 undefined
+ */
+export type CliValueParser<T> = (raw: string) => T | undefined;
+
+/**
+ * 规范化参数名，去掉前缀的 "-" 或 "--"
+ * @param name - 参数名
+ * @returns 规范化后的参数名
+ */
+function normalizeFlagName(name: string): string {
+	if (name.sub(1, 2) === "--") {
+		return name.sub(3);
+	}
+	if (name.sub(1, 1) === "-") {
+		return name.sub(2);
+	}
+	return name;
+}
+
+/**
+ * 检查参数是否可以作为前一个参数的值
+ * @param token - 参数
+ */
+function isValueToken(token: string): boolean {
+	return token.sub(1, 1) !== "-" || tonumber(token) !== undefined;
+}
+
+/**
+ * 已解析的启动参数资源
+ *
+ * 支持的格式：
+ * - `--name value` 与 `--name=value`
+ * - `--flag`（无值标志）
+ * - `-v`、`-abc`（短标志，`-abc` 等价于 `-a -b -c`）
+ * - `--` 之后的参数全部视为位置参数
+ *
+ * 格式错误的参数不会导致失败，而是记录在 warnings() 中
+ *
+ * @example
+ * ```typescript
+ * const args = app.getResource<CliArgs>()!;
+ * const port = args.getNumber("port") ?? 8080;
+ * if (args.flagPresent("verbose")) { ... }
+ * ```
+ */
+export class CliArgs implements Resource {
+	readonly __brand = "Resource" as const;
+	/** 出现过的标志，包括没有值的标志 */
+	private readonly presentFlags = new Set<string>();
+	/** 带值标志的值 */
+	private readonly flagValues = new Map<string, string>();
+	private readonly positionalArgs: string[] = [];
+	private readonly warningMessages: string[] = [];
+
+	/**
+	 * 解析启动参数
+	 * @param args - 启动参数列表
+	 */
+	constructor(args: ReadonlyArray<string> = []) {
+		this.parse(args);
+	}
+
+	/**
+	 * 检查标志是否存在
+	 * @param name - 标志名，可带或不带 "--" 前缀
+	 * @returns 是否存在
+	 */
+	flagPresent(name: string): boolean {
+		return this.presentFlags.has(normalizeFlagName(name));
+	}
+
+	/**
+	 * 获取参数的原始字符串值
+	 * @param flag - 参数名，可带或不带 "--" 前缀
+	 * @returns 参数值，参数不存在或没有值时返回 undefined
+	 */
+	getString(flag: string): string | undefined {
+		return this.flagValues.get(normalizeFlagName(flag));
+	}
+
+	/**
+	 * 使用解析函数获取类型化的参数值
+	 * 解析失败时记录警告并返回 undefined
+	 * @param flag - 参数名，可带或不带 "--" 前缀
+	 * @param parse - 值解析函数
+	 * @returns 解析后的值
+	 */
+	get<T>(flag: string, parse: CliValueParser<T>): T | undefined {
+		const raw = this.getString(flag);
+		if (raw === undefined) {
+			return undefined;
+		}
+
+		const value = parse(raw);
+		if (value === undefined) {
+			this.warningMessages.push(`Invalid value "${raw}" for argument "--${normalizeFlagName(flag)}"`);
+		}
+		return value;
+	}
+
+	/**
+	 * 获取数字参数
+	 * @param flag - 参数名，可带或不带 "--" 前缀
+	 * @returns 数字值，不存在或无法解析时返回 undefined
+	 */
+	getNumber(flag: string): number | undefined {
+		return this.get(flag, (raw) => tonumber(raw));
+	}
+
+	/**
+	 * 获取布尔参数
+	 * 接受 true/false/1/0/yes/no，仅有标志而没有值时视为 true
+	 * @param flag - 参数名，可带或不带 "--" 前缀
+	 * @returns 布尔值，不存在或无法解析时返回 undefined
+	 */
+	getBoolean(flag: string): boolean | undefined {
+		if (this.flagPresent(flag) && this.getString(flag) === undefined) {
+			return true;
+		}
+
+		return this.get(flag, (raw) => {
+			const lowered = raw.lower();
+			if (lowered === "true" || lowered === "1" || lowered === "yes") {
+				return true;
+			}
+			if (lowered === "false" || lowered === "0" || lowered === "no") {
+				return false;
+			}
+			return undefined;
+		});
+	}
+
+	/**
+	 * 获取位置参数
+	 * @returns 不属于任何标志的参数列表
+	 */
+	positional(): ReadonlyArray<string> {
+		return this.positionalArgs;
+	}
+
+	/**
+	 * 获取解析过程中收集的警告
+	 * @returns 警告信息列表
+	 */
+	warnings(): ReadonlyArray<string> {
+		return this.warningMessages;
+	}
+
+	/**
+	 * 解析参数列表
+	 * @param args - 启动参数列表
+	 */
+	private parse(args: ReadonlyArray<string>): void {
+		let index = 0;
+		while (index < args.size()) {
+			const token = args[index];
+			index++;
+
+			if (token === "--") {
+				for (let rest = index; rest < args.size(); rest++) {
+					this.positionalArgs.push(args[rest]);
+				}
+				return;
+			}
+
+			if (token.sub(1, 3) === "---" || token === "-") {
+				this.warningMessages.push(`Malformed argument "${token}"`);
+				continue;
+			}
+
+			if (token.sub(1, 2) === "--") {
+				const body = token.sub(3);
+				const [equalsIndex] = body.find("=", 1, true);
+
+				if (equalsIndex !== undefined) {
+					const name = body.sub(1, equalsIndex - 1);
+					if (name === "") {
+						this.warningMessages.push(`Malformed argument "${token}"`);
+						continue;
+					}
+					this.setFlag(name, body.sub(equalsIndex + 1));
+					continue;
+				}
+
+				const nextToken = args[index];
+				if (nextToken !== undefined && isValueToken(nextToken)) {
+					this.setFlag(body, nextToken);
+					index++;
+				} else {
+					this.setFlag(body, undefined);
+				}
+				continue;
+			}
+
+			if (token.sub(1, 1) === "-" && tonumber(token) === undefined) {
+				for (let charIndex = 2; charIndex <= token.size(); charIndex++) {
+					this.setFlag(token.sub(charIndex, charIndex), undefined);
+				}
+				continue;
+			}
+
+			this.positionalArgs.push(token);
+		}
+	}
+
+	/**
+	 * 记录标志，重复出现时后者覆盖前者
+	 * @param name - 标志名
+	 * @param value - 标志值
+	 */
+	private setFlag(name: string, value: string | undefined): void {
+		if (this.presentFlags.has(name)) {
+			this.warningMessages.push(`Argument "--${name}" was given more than once, using the last value`);
+		}
+
+		this.presentFlags.add(name);
+		if (value !== undefined) {
+			this.flagValues.set(name, value);
+		} else {
+			this.flagValues.delete(name);
+		}
+	}
+}
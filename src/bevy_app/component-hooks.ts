@@ -0,0 +1,50 @@
+/**
+ * 组件生命周期回调
+ * 在组件被添加到实体或从实体上移除时调用回调，无需手写变更检测系统
+ */
+
+import type { AnyEntity } from "@rbxts/matter";
+import type { World } from "../bevy_ecs/bevy-world";
+import type { CommandBuffer } from "../bevy_ecs/command-buffer";
+import type { ComponentCtor } from "../bevy_ecs/query";
+
+/**
+ * 组件生命周期回调
+ * @param entity - 组件所在的实体；移除回调中组件已不存在，但实体 ID 仍然有效
+ * @param commands - 命令缓冲区，命令在本帧结束时执行
+ */
+export type ComponentHook = (entity: AnyEntity, commands: CommandBuffer) => void;
+
+/**
+ * 创建检测组件添加的系统
+ * 基于 Matter 的 queryChanged：旧值为空、新值存在即视为添加
+ * @param component - 组件构造函数
+ * @param callback - 添加回调
+ * @returns 系统函数
+ */
+export function createComponentAddedSystem(component: ComponentCtor, callback: ComponentHook) {
+	return (world: World): void => {
+		for (const [entity, record] of world.queryChanged(component)) {
+			if (record.new !== undefined && record.old === undefined) {
+				callback(entity, world.commands);
+			}
+		}
+	};
+}
+
+/**
+ * 创建检测组件移除的系统
+ * 基于 Matter 的 queryChanged：旧值存在、新值为空即视为移除（包括实体被销毁）
+ * @param component - 组件构造函数
+ * @param callback - 移除回调
+ * @returns 系统函数
+ */
+export function createComponentRemovedSystem(component: ComponentCtor, callback: ComponentHook) {
+	return (world: World): void => {
+		for (const [entity, record] of world.queryChanged(component)) {
+			if (record.new === undefined && record.old !== undefined) {
+				callback(entity, world.commands);
+			}
+		}
+	};
+}
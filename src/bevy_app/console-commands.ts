@@ -0,0 +1,180 @@
+/**
+ * 控制台命令
+ * 为开发控制台注册按名称调用的命令，输入一行文本即可触发 ECS 操作，例如生成敌人、修改资源
+ *
+ * 一行输入按空白拆分为命令名和参数，双引号包裹的参数可以包含空白。
+ * 处理函数报错时返回 HandlerFailed 错误，不会影响调用方。
+ */
+
+import type { World } from "../bevy_ecs/bevy-world";
+import type { Resource } from "../bevy_ecs/resource";
+
+/**
+ * 命令处理函数
+ * @param world - 游戏世界
+ * @param args - 命令参数
+ * @returns 输出到控制台的文本，没有返回值时输出为空字符串
+ */
+export type ConsoleCommandHandler = (world: World, args: ReadonlyArray<string>) => string | void;
+
+/**
+ * 已注册的命令
+ */
+export interface ConsoleCommand {
+	/** 命令名 */
+	readonly name: string;
+	/** 命令说明 */
+	readonly description?: string;
+	/** 处理函数 */
+	readonly handler: ConsoleCommandHandler;
+}
+
+/**
+ * 控制台错误类型
+ */
+export enum ConsoleErrorKind {
+	/** 输入为空 */
+	EmptyInput = "EmptyInput",
+	/** 命令未注册 */
+	UnknownCommand = "UnknownCommand",
+	/** 引号没有闭合 */
+	UnterminatedQuote = "UnterminatedQuote",
+	/** 处理函数报错 */
+	HandlerFailed = "HandlerFailed",
+}
+
+/**
+ * 控制台错误
+ */
+export class ConsoleError {
+	public name = "ConsoleError";
+
+	/**
+	 * 创建控制台错误
+	 * @param kind - 错误类型
+	 * @param message - 错误信息
+	 */
+	constructor(
+		public readonly kind: ConsoleErrorKind,
+		public readonly message: string,
+	) {}
+
+	/**
+	 * 将错误转换为字符串
+	 * @returns 错误信息字符串
+	 */
+	toString(): string {
+		return `${this.name}(${this.kind}): ${this.message}`;
+	}
+}
+
+/**
+ * 将一行输入拆分为单词
+ * @param line - 输入行
+ * @returns 单词列表；引号没有闭合时返回 undefined
+ */
+export function parseCommandLine(line: string): string[] | undefined {
+	const words: string[] = [];
+	let current = "";
+	let inWord = false;
+	let quoted = false;
+
+	for (let index = 1; index <= line.size(); index++) {
+		const char = line.sub(index, index);
+		if (char === '"') {
+			quoted = !quoted;
+			inWord = true;
+		} else if (!quoted && char.match("^%s$")[0] !== undefined) {
+			if (inWord) {
+				words.push(current);
+				current = "";
+				inWord = false;
+			}
+		} else {
+			current += char;
+			inWord = true;
+		}
+	}
+
+	if (quoted) {
+		return undefined;
+	}
+	if (inWord) {
+		words.push(current);
+	}
+	return words;
+}
+
+/**
+ * 控制台命令注册表资源
+ * 由 App.registerCommand 在首次调用时插入
+ */
+export class ConsoleCommands implements Resource {
+	readonly __brand = "Resource" as const;
+	private readonly commands = new Map<string, ConsoleCommand>();
+
+	/**
+	 * 注册命令
+	 * 同名命令已存在时覆盖
+	 * @param name - 命令名，不能包含空白
+	 * @param handler - 处理函数
+	 * @param description - 命令说明
+	 */
+	register(name: string, handler: ConsoleCommandHandler, description?: string): void {
+		assert(name !== "" && name.match("%s")[0] === undefined, `Invalid console command name "${name}"`);
+		this.commands.set(name, { name, handler, description });
+	}
+
+	/**
+	 * 检查命令是否已注册
+	 * @param name - 命令名
+	 */
+	has(name: string): boolean {
+		return this.commands.has(name);
+	}
+
+	/**
+	 * 获取所有命令，按名称排序
+	 * @returns 命令列表
+	 */
+	getCommands(): ConsoleCommand[] {
+		const commands: ConsoleCommand[] = [];
+		for (const [, command] of this.commands) {
+			commands.push(command);
+		}
+		commands.sort((a, b) => a.name < b.name);
+		return commands;
+	}
+
+	/**
+	 * 解析并执行一行输入
+	 * @param world - 游戏世界
+	 * @param line - 输入行，例如 `spawn_enemy 3`
+	 * @returns 命令输出；命令未注册、输入无法解析或处理函数报错时返回 ConsoleError
+	 */
+	execute(world: World, line: string): string | ConsoleError {
+		const words = parseCommandLine(line);
+		if (words === undefined) {
+			return new ConsoleError(ConsoleErrorKind.UnterminatedQuote, `Unterminated quote in "${line}"`);
+		}
+		if (words.size() === 0) {
+			return new ConsoleError(ConsoleErrorKind.EmptyInput, "No command given");
+		}
+
+		const name = words.shift()!;
+		const command = this.commands.get(name);
+		if (command === undefined) {
+			const available = this.getCommands().map((registered) => registered.name);
+			return new ConsoleError(
+				ConsoleErrorKind.UnknownCommand,
+				`Unknown command "${name}". Available commands: ${available.size() > 0 ? available.join(", ") : "(none)"}`,
+			);
+		}
+
+		const [success, result] = pcall(command.handler, world, words);
+		if (!success) {
+			return new ConsoleError(ConsoleErrorKind.HandlerFailed, `Command "${name}" failed: ${tostring(result)}`);
+		}
+		return result !== undefined ? tostring(result) : "";
+	}
+}
@@ -0,0 +1,122 @@
+/**
+ * 事件录制与回放
+ * 通过 App.recordEvents 记录选定消息类型的每条消息及其所在帧，
+ * 之后在一次全新的运行中通过 App.replayEvents 在相同的帧重新写入，用于复现问题
+ *
+ * 帧序号从录制（回放）开始后的第一次 update 计为 0，每次 update 加 1。
+ * 录制在 Last 中读取本帧写入的消息，回放在 First 中写入，因此回放的消息在同一帧内对所有系统可见。
+ * 为了结果可复现，回放的运行应开启 deterministicMode，并用 runFixed 以固定步长推进，
+ * 使每一帧的时间增量与录制时一致。
+ */
+
+import type { Message, MessageReader, Messages } from "../bevy_ecs/message";
+import type { Resource } from "../bevy_ecs/resource";
+import type { SystemFunction } from "../bevy_ecs/schedule/types";
+
+/**
+ * 录制的单条事件
+ * @template E - 消息类型
+ */
+export interface RecordedEvent<E extends Message> {
+	/** 事件所在帧 */
+	readonly frame: number;
+	/** 事件内容 */
+	readonly event: E;
+}
+
+/**
+ * 事件日志资源
+ * 由 App.recordEvents<E> 插入，按写入顺序保存录制的事件
+ * @template E - 消息类型
+ */
+export class EventLog<E extends Message> implements Resource {
+	readonly __brand = "Resource" as const;
+	private readonly entries: RecordedEvent<E>[] = [];
+	/** 类型标记 */
+	private readonly _marker?: E;
+
+	/**
+	 * 追加一条事件
+	 * @param frame - 事件所在帧，不能早于已记录的最后一帧
+	 * @param event - 事件内容
+	 */
+	record(frame: number, event: E): void {
+		const last = this.entries[this.entries.size() - 1];
+		assert(last === undefined || last.frame <= frame, `EventLog: frame ${frame} is earlier than recorded frame`);
+		this.entries.push({ frame, event });
+	}
+
+	/**
+	 * 获取所有录制的事件
+	 * @returns 事件列表，按帧和写入顺序排列
+	 */
+	getEntries(): ReadonlyArray<RecordedEvent<E>> {
+		return this.entries;
+	}
+
+	/**
+	 * 获取某一帧录制的事件
+	 * @param frame - 帧序号
+	 * @returns 该帧的事件，按写入顺序排列
+	 */
+	eventsAt(frame: number): E[] {
+		const events: E[] = [];
+		for (const entry of this.entries) {
+			if (entry.frame === frame) {
+				events.push(entry.event);
+			}
+		}
+		return events;
+	}
+
+	/**
+	 * 获取录制的事件数量
+	 */
+	size(): number {
+		return this.entries.size();
+	}
+
+	/**
+	 * 清空录制的事件
+	 */
+	clear(): void {
+		this.entries.clear();
+	}
+}
+
+/**
+ * 创建录制系统
+ * 每帧读取 reader 中的新消息并以当前帧序号写入日志
+ * @param reader - 消息读取器
+ * @param log - 事件日志
+ * @returns 系统函数
+ */
+export function createEventRecorderSystem<E extends Message>(reader: MessageReader<E>, log: EventLog<E>): SystemFunction {
+	let frame = 0;
+	return () => {
+		for (const event of reader.read()) {
+			log.record(frame, event);
+		}
+		frame++;
+	};
+}
+
+/**
+ * 创建回放系统
+ * 每帧将日志中帧序号等于当前帧的事件按原顺序写入消息缓冲区
+ * @param messages - 消息缓冲区
+ * @param log - 要回放的事件日志
+ * @returns 系统函数
+ */
+export function createEventReplaySystem<E extends Message>(messages: Messages<E>, log: EventLog<E>): SystemFunction {
+	const entries = log.getEntries();
+	let frame = 0;
+	let cursor = 0;
+	return () => {
+		while (cursor < entries.size() && entries[cursor].frame <= frame) {
+			messages.write(entries[cursor].event);
+			cursor++;
+		}
+		frame++;
+	};
+}
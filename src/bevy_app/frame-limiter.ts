@@ -0,0 +1,113 @@
+/**
+ * 帧率限制
+ * 为没有垂直同步的无头/服务端 App 提供按目标帧率运行的运行器
+ */
+
+import type { Resource } from "../bevy_ecs/resource";
+import type { App } from "./app";
+import { PluginState } from "./plugin";
+import { AppExit } from "./types";
+
+/** 计算 FPS 时使用的最近帧数 */
+const FPS_SAMPLE_SIZE = 60;
+
+/**
+ * 帧统计资源
+ * 由 App.limitFps 插入，每帧结束时更新
+ */
+export class FrameStats implements Resource {
+	readonly __brand = "Resource" as const;
+	/** 已完成的帧数 */
+	frameCount = 0;
+	/** 最近一帧的工作耗时（秒），不含等待时间 */
+	lastWorkTime = 0;
+	/** 最近一帧的总耗时（秒），包含等待时间 */
+	lastFrameTime = 0;
+	private readonly frameTimes: number[] = [];
+	private frameTimeSum = 0;
+
+	/**
+	 * 创建帧统计
+	 * @param targetFps - 目标帧率，0 表示不限制
+	 */
+	constructor(public readonly targetFps: number) {}
+
+	/**
+	 * 获取实际测得的帧率
+	 * 基于最近 60 帧的平均帧时间
+	 * @returns 每秒帧数，尚无数据时返回 0
+	 */
+	fps(): number {
+		if (this.frameTimeSum <= 0) {
+			return 0;
+		}
+		return this.frameTimes.size() / this.frameTimeSum;
+	}
+
+	/**
+	 * 记录一帧
+	 * @param workTime - 帧工作耗时（秒）
+	 * @param frameTime - 帧总耗时（秒）
+	 */
+	recordFrame(workTime: number, frameTime: number): void {
+		this.frameCount++;
+		this.lastWorkTime = workTime;
+		this.lastFrameTime = frameTime;
+
+		this.frameTimes.push(frameTime);
+		this.frameTimeSum += frameTime;
+		if (this.frameTimes.size() > FPS_SAMPLE_SIZE) {
+			this.frameTimeSum -= this.frameTimes.shift()!;
+		}
+	}
+}
+
+/**
+ * 创建限制帧率的运行器
+ * 每帧结束时根据本帧的工作耗时等待剩余时间；上一帧多等待的时间会从下一帧的等待中扣除，避免累计过量休眠。
+ * 目标帧率为 0 时不等待，每帧只让出一次线程（Roblox 中不让出会阻塞整个脚本）
+ * @param stats - 帧统计资源
+ * @returns 运行器函数，收到 AppExit 后返回
+ */
+export function createFrameLimitedRunner(stats: FrameStats): (app: App) => AppExit {
+	return (app: App) => {
+		while (app.getPluginState() === PluginState.Adding) {
+			task.wait();
+		}
+
+		const buildError = app.tryBuild();
+		if (buildError) {
+			error(buildError.toString());
+		}
+
+		const frameBudget = stats.targetFps > 0 ? 1 / stats.targetFps : 0;
+		let oversleep = 0;
+
+		for (;;) {
+			const frameStart = os.clock();
+			app.update();
+			const workTime = os.clock() - frameStart;
+
+			const exit = app.shouldExit();
+			if (exit) {
+				stats.recordFrame(workTime, os.clock() - frameStart);
+				return exit;
+			}
+
+			if (frameBudget > 0) {
+				const sleepTime = frameBudget - workTime - oversleep;
+				if (sleepTime > 0) {
+					const waited = task.wait(sleepTime);
+					oversleep = math.min(math.max(waited - sleepTime, 0), frameBudget);
+				} else {
+					oversleep = 0;
+					task.wait();
+				}
+			} else {
+				task.wait();
+			}
+
+			stats.recordFrame(workTime, os.clock() - frameStart);
+		}
+	};
+}
@@ -0,0 +1,39 @@
+/**
+ * 系统热替换
+ * 在运行时替换系统实现而无需重新构建 App，用于迭代开发
+ */
+
+import type { Resource } from "../bevy_ecs/resource";
+import type { SystemFunction } from "../bevy_ecs/schedule/types";
+import type { SubApp } from "./sub-app";
+
+/**
+ * 系统热替换资源
+ * 由 SubApp 自动插入，可在系统中通过 world.resources.getResource<HotSystems>() 访问
+ *
+ * 替换后的系统沿用旧系统在调度中的位置：系统集、运行条件以及 before/after 关系保持不变。
+ *
+ * @example
+ * ```typescript
+ * const info = app.getResource<SystemRegistry>()!.find(BuiltinSchedules.UPDATE, moveSystem)!;
+ * app.getResource<HotSystems>()!.replaceSystem(info.id, newMoveSystem);
+ * ```
+ */
+export class HotSystems implements Resource {
+	readonly __brand = "Resource" as const;
+
+	/**
+	 * 创建系统热替换资源
+	 * @param subApp - 所属 SubApp
+	 */
+	constructor(private readonly subApp: SubApp) {}
+
+	/**
+	 * 替换系统实现
+	 * @param oldId - 被替换系统的标识符，可从 SystemRegistry 获取
+	 * @param newSystem - 新的系统函数
+	 */
+	replaceSystem(oldId: string, newSystem: SystemFunction): void {
+		this.subApp.replaceSystem(oldId, newSystem);
+	}
+}
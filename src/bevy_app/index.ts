@@ -31,11 +31,36 @@
 export * from "./types";
 export * from "./app";
 export * from "./plugin";
+export * from "./plugin-dependencies";
+export * from "./plugin-version";
+export * from "./plugin-config";
+export * from "./build-env";
+export * from "./cli-args";
+export * from "./build-error";
+export * from "./ordering-cycle";
 export * from "./sub-app";
 export * from "./roblox-adapters";
 export * from "./main-schedule";
 export * from "./extensions";
 export * from "./context";
+export * from "./system-dedup";
+export * from "./system-group";
+export * from "./system-registry";
+export * from "./hot-systems";
+export * from "./run-once";
+export * from "./component-hooks";
+export * from "./task-spawner";
+export * from "./stages";
+export * from "./profiling";
+export * from "./system-budget";
+export * from "./event-replay";
+export * from "./pause-control";
+export * from "./app-merge";
+export * from "./console-commands";
+export * from "./resource-persistence";
+export * from "./schedule-dot";
+export * from "./panic-isolation";
+export * from "./frame-limiter";
 
 // 导出预设模块
 export * as prelude from "./prelude";
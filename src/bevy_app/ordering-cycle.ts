@@ -0,0 +1,110 @@
+/**
+ * 系统排序环检查
+ * App.tryBuild 在编译调度之前，根据系统注册时保留的 before/after/inSet 配置检查排序约束是否成环，
+ * 成环时返回 OrderingCycle 错误并列出环上的系统，而不是等到调度编译时才报出难以定位的错误
+ *
+ * 边的方向与执行顺序一致：A -> B 表示 A 必须在 B 之前执行。
+ * 系统集约束展开为集合成员之间的边，因此经由系统集形成的环同样会被发现。
+ */
+
+import type { Schedule } from "../bevy_ecs/schedule/schedule";
+import type { ScheduleLabel } from "../bevy_ecs/schedule/types";
+import { getSystemDisplayName } from "./system-dedup";
+
+/**
+ * 系统排序环错误
+ */
+export class OrderingCycleError {
+	public name = "OrderingCycleError";
+	public readonly message: string;
+
+	/**
+	 * 创建排序环错误
+	 * @param schedule - 出现环的调度
+	 * @param cycle - 构成环的系统名称，首尾相同
+	 */
+	constructor(
+		public readonly schedule: ScheduleLabel,
+		public readonly cycle: ReadonlyArray<string>,
+	) {
+		this.message = `System ordering cycle in schedule "${schedule}": ${cycle.join(" -> ")}`;
+	}
+
+	/**
+	 * 将错误转换为字符串
+	 * @returns 错误信息字符串
+	 */
+	toString(): string {
+		return `${this.name}: ${this.message}`;
+	}
+}
+
+/**
+ * 查找调度中的排序环
+ * @param schedule - 要检查的调度
+ * @returns 第一个找到的环，没有环时返回 undefined
+ */
+export function findOrderingCycle(schedule: Schedule): OrderingCycleError | undefined {
+	const systems = schedule.getSystemsInRegistrationOrder();
+
+	const namesById = new Map<string, string>();
+	for (const system of systems) {
+		namesById.set(system.id, getSystemDisplayName(system));
+	}
+
+	const successors = new Map<string, string[]>();
+	for (const ordering of schedule.getOrderingEdges()) {
+		const list = successors.get(ordering.from) ?? [];
+		if (!list.includes(ordering.to)) {
+			list.push(ordering.to);
+		}
+		successors.set(ordering.from, list);
+	}
+
+	// 深度优先搜索，遇到仍在路径上的节点时即找到环
+	const finished = new Set<string>();
+	const path: string[] = [];
+	const onPath = new Set<string>();
+
+	const visit = (id: string): string[] | undefined => {
+		path.push(id);
+		onPath.add(id);
+
+		for (const next of successors.get(id) ?? []) {
+			if (onPath.has(next)) {
+				const start = path.indexOf(next);
+				const cycle: string[] = [];
+				for (let index = start; index < path.size(); index++) {
+					cycle.push(path[index]);
+				}
+				cycle.push(next);
+				return cycle;
+			}
+			if (!finished.has(next)) {
+				const found = visit(next);
+				if (found !== undefined) {
+					return found;
+				}
+			}
+		}
+
+		path.pop();
+		onPath.delete(id);
+		finished.add(id);
+		return undefined;
+	};
+
+	for (const system of systems) {
+		if (finished.has(system.id)) {
+			continue;
+		}
+		const cycle = visit(system.id);
+		if (cycle !== undefined) {
+			return new OrderingCycleError(
+				schedule.getLabel(),
+				cycle.map((id) => namesById.get(id) ?? id),
+			);
+		}
+	}
+	return undefined;
+}
@@ -0,0 +1,89 @@
+/**
+ * 系统错误隔离
+ * 通过 App.isolatePanics 开启后，单个系统抛出的错误不会中断整个 App：
+ * 错误会被记录到 SystemPanicLog，出错的系统在之后的帧中被禁用
+ *
+ * 注意：Luau 没有回滚机制，系统在出错前对 World 或资源的修改会保留下来。
+ * 需要隔离的系统应当在出错时不留下不一致的状态（相当于 Rust 的 UnwindSafe 要求）。
+ */
+
+import type { Resource } from "../bevy_ecs/resource";
+import type { ScheduleLabel, SystemWrapper } from "../bevy_ecs/schedule/types";
+
+/**
+ * 系统错误记录
+ */
+export interface SystemPanic {
+	/** 系统唯一标识 */
+	readonly id: string;
+	/** 系统名称 */
+	readonly name: string;
+	/** 所属调度 */
+	readonly schedule: ScheduleLabel;
+	/** 错误信息 */
+	readonly message: string;
+}
+
+/**
+ * 系统错误日志资源
+ */
+export class SystemPanicLog implements Resource {
+	readonly __brand = "Resource" as const;
+	private readonly panics: SystemPanic[] = [];
+	private readonly disabledSystems = new Set<string>();
+
+	/**
+	 * 记录系统错误并禁用该系统
+	 * @param panic - 错误记录
+	 */
+	record(panic: SystemPanic): void {
+		this.panics.push(panic);
+		this.disabledSystems.add(panic.id);
+	}
+
+	/**
+	 * 检查系统是否已因出错被禁用
+	 * @param id - 系统唯一标识
+	 */
+	isDisabled(id: string): boolean {
+		return this.disabledSystems.has(id);
+	}
+
+	/**
+	 * 重新启用被禁用的系统
+	 * @param id - 系统唯一标识
+	 */
+	enable(id: string): void {
+		this.disabledSystems.delete(id);
+	}
+
+	/**
+	 * 获取所有错误记录
+	 * @returns 按发生顺序排列的错误记录
+	 */
+	getPanics(): ReadonlyArray<SystemPanic> {
+		return this.panics;
+	}
+}
+
+/**
+ * 创建错误隔离包装器
+ * @param log - 错误日志资源
+ * @returns 系统包装器
+ */
+export function createPanicIsolationWrapper(log: SystemPanicLog): SystemWrapper {
+	return (system, info) => {
+		return (world, context) => {
+			if (log.isDisabled(info.id)) {
+				return;
+			}
+
+			const [success, err] = pcall(system, world, context);
+			if (!success) {
+				const message = tostring(err);
+				warn(`[isolatePanics] System "${info.name}" in schedule "${info.schedule}" failed and has been disabled: ${message}`);
+				log.record({ id: info.id, name: info.name, schedule: info.schedule, message });
+			}
+		};
+	};
+}
@@ -0,0 +1,75 @@
+/**
+ * 分组暂停
+ * 通过 App.addPausableSystem 注册的系统属于一个暂停组，组被暂停时系统不运行，例如暂停菜单打开时冻结游戏逻辑而 UI 继续运行
+ *
+ * 暂停状态保存在 PauseControl 资源中，由运行条件在每个系统执行前读取。
+ * 因此在帧中途（例如某个 UI 系统中）调用 pause/resume 是安全的：
+ * 本帧尚未执行的系统立即按新状态运行或跳过，已经执行过的系统不受影响。
+ */
+
+import type { Resource } from "../bevy_ecs/resource";
+
+/**
+ * 暂停组标签
+ */
+export type PauseGroup = string;
+
+/**
+ * 暂停控制资源
+ * 由 App.addPausableSystem 在首次调用时插入
+ */
+export class PauseControl implements Resource {
+	readonly __brand = "Resource" as const;
+	private readonly paused = new Set<PauseGroup>();
+
+	/**
+	 * 暂停组
+	 * @param group - 组标签
+	 */
+	pause(group: PauseGroup): void {
+		this.paused.add(group);
+	}
+
+	/**
+	 * 恢复组
+	 * @param group - 组标签
+	 */
+	resume(group: PauseGroup): void {
+		this.paused.delete(group);
+	}
+
+	/**
+	 * 切换组的暂停状态
+	 * @param group - 组标签
+	 * @returns 切换后组是否在运行
+	 */
+	toggle(group: PauseGroup): boolean {
+		if (this.paused.has(group)) {
+			this.paused.delete(group);
+			return true;
+		}
+		this.paused.add(group);
+		return false;
+	}
+
+	/**
+	 * 检查组是否在运行
+	 * 从未暂停过的组视为在运行
+	 * @param group - 组标签
+	 */
+	isRunning(group: PauseGroup): boolean {
+		return !this.paused.has(group);
+	}
+
+	/**
+	 * 获取被暂停的组
+	 * @returns 组标签列表
+	 */
+	getPausedGroups(): ReadonlyArray<PauseGroup> {
+		const groups: PauseGroup[] = [];
+		for (const group of this.paused) {
+			groups.push(group);
+		}
+		return groups;
+	}
+}
@@ -0,0 +1,55 @@
+/**
+ * 插件配置
+ * 插件通过带校验的配置对象进行配置，配置在插件构建前校验，并作为资源供插件的系统读取
+ */
+
+import type { Plugin } from "./plugin";
+
+/**
+ * 配置校验错误
+ */
+export class ConfigError {
+	public name = "ConfigError";
+
+	/**
+	 * 创建配置校验错误
+	 * @param message - 错误信息
+	 * @param field - 出错的配置字段
+	 */
+	constructor(
+		public readonly message: string,
+		public readonly field?: string,
+	) {}
+
+	/**
+	 * 将错误转换为字符串
+	 * @returns 错误信息字符串
+	 */
+	toString(): string {
+		if (this.field !== undefined) {
+			return `${this.name}(${this.field}): ${this.message}`;
+		}
+		return `${this.name}: ${this.message}`;
+	}
+}
+
+/**
+ * 可校验的插件配置
+ */
+export interface PluginConfig {
+	/**
+	 * 校验配置
+	 * @returns 配置有效时返回 undefined，否则返回校验错误
+	 */
+	validate(): ConfigError | undefined;
+}
+
+/**
+ * 声明配置类型的插件
+ * 配置类型仅用于类型检查，插件在 build 及系统中通过 getResource<C>() 读取配置
+ * @template C - 配置类型
+ */
+export interface ConfiguredPlugin<C extends PluginConfig> extends Plugin<any> {
+	/** 仅用于类型推导，不需要赋值 */
+	readonly __config?: C;
+}
@@ -0,0 +1,89 @@
+/**
+ * 插件依赖排序
+ * 根据 Plugin.dependencies() 声明对插件进行拓扑排序，保证依赖先于使用者构建
+ */
+
+import { CyclicDependencyError, MissingDependencyError, Plugin, PluginError, PluginId } from "./plugin";
+
+/**
+ * 访问状态
+ */
+const enum VisitState {
+	/** 正在访问（位于当前 DFS 路径上） */
+	Visiting,
+	/** 已完成排序 */
+	Done,
+}
+
+/**
+ * 按依赖关系对插件进行拓扑排序
+ * 无依赖关系的插件保持传入顺序
+ * @param plugins - 待排序的插件
+ * @param isAlreadyAdded - 判断依赖是否已添加到 App（已添加的依赖视为已满足）
+ * @returns 排序后的插件列表，或描述失败原因的错误
+ */
+export function sortPluginsByDependencies(
+	plugins: ReadonlyArray<Plugin<any>>,
+	isAlreadyAdded: (id: PluginId) => boolean,
+): Plugin<any>[] | PluginError {
+	const pluginsById = new Map<PluginId, Plugin<any>>();
+	for (const plugin of plugins) {
+		pluginsById.set(plugin.name(), plugin);
+	}
+
+	const states = new Map<PluginId, VisitState>();
+	const path: PluginId[] = [];
+	const sorted: Plugin<any>[] = [];
+
+	const visit = (plugin: Plugin<any>): PluginError | undefined => {
+		const id = plugin.name();
+		const state = states.get(id);
+
+		if (state === VisitState.Done) {
+			return undefined;
+		}
+
+		if (state === VisitState.Visiting) {
+			const cycleStart = path.indexOf(id);
+			const cycle: PluginId[] = [];
+			for (let index = cycleStart; index < path.size(); index++) {
+				cycle.push(path[index]);
+			}
+			cycle.push(id);
+			return new CyclicDependencyError(cycle);
+		}
+
+		states.set(id, VisitState.Visiting);
+		path.push(id);
+
+		for (const dependency of plugin.dependencies?.() ?? []) {
+			const dependencyPlugin = pluginsById.get(dependency);
+
+			if (dependencyPlugin === undefined) {
+				if (isAlreadyAdded(dependency)) {
+					continue;
+				}
+				return new MissingDependencyError(id, dependency);
+			}
+
+			const err = visit(dependencyPlugin);
+			if (err !== undefined) {
+				return err;
+			}
+		}
+
+		path.pop();
+		states.set(id, VisitState.Done);
+		sorted.push(plugin);
+		return undefined;
+	};
+
+	for (const plugin of plugins) {
+		const err = visit(plugin);
+		if (err !== undefined) {
+			return err;
+		}
+	}
+
+	return sorted;
+}
@@ -0,0 +1,231 @@
+/**
+ * 插件版本
+ * 插件通过 Plugin.version() 报告自身版本，通过 Plugin.requires() 声明对其他插件的版本要求，
+ * App.tryBuild 在构建时检查每个要求，不满足时返回 VersionMismatch 错误
+ *
+ * 版本号遵循语义化版本的 MAJOR.MINOR.PATCH 格式，不支持预发布和构建元数据。
+ */
+
+/**
+ * 语义化版本号
+ */
+export class Version {
+	/**
+	 * 创建版本号
+	 * @param major - 主版本号
+	 * @param minor - 次版本号
+	 * @param patch - 修订号
+	 */
+	constructor(
+		public readonly major: number,
+		public readonly minor: number = 0,
+		public readonly patch: number = 0,
+	) {}
+
+	/**
+	 * 解析版本号
+	 * 省略的次版本号和修订号视为 0，例如 "1.5" 等价于 "1.5.0"
+	 * @param text - 版本号文本
+	 * @returns 版本号；格式错误时报错
+	 */
+	static parse(text: string): Version {
+		const parts = parseNumericParts(text);
+		if (parts === undefined) {
+			error(`Invalid version "${text}"`);
+		}
+		return new Version(parts[0], parts[1] ?? 0, parts[2] ?? 0);
+	}
+
+	/**
+	 * 与另一个版本比较
+	 * @param other - 另一个版本
+	 * @returns 小于时返回负数，相等时返回 0，大于时返回正数
+	 */
+	compare(other: Version): number {
+		if (this.major !== other.major) {
+			return this.major - other.major;
+		}
+		if (this.minor !== other.minor) {
+			return this.minor - other.minor;
+		}
+		return this.patch - other.patch;
+	}
+
+	/**
+	 * 将版本号转换为字符串
+	 * @returns MAJOR.MINOR.PATCH 格式的字符串
+	 */
+	toString(): string {
+		return `${this.major}.${this.minor}.${this.patch}`;
+	}
+}
+
+/**
+ * 比较运算符
+ */
+type ComparatorOp = "=" | ">" | ">=" | "<" | "<=";
+
+/**
+ * 单个比较条件
+ */
+interface Comparator {
+	readonly op: ComparatorOp;
+	readonly version: Version;
+}
+
+/**
+ * 解析由 "." 分隔的 1 到 3 个非负整数
+ * @param text - 版本号文本
+ * @returns 解析出的数字列表，格式错误时返回 undefined
+ */
+function parseNumericParts(text: string): number[] | undefined {
+	const parts = text.split(".");
+	if (parts.size() > 3) {
+		return undefined;
+	}
+
+	const numbers: number[] = [];
+	for (const part of parts) {
+		if (part.match("^%d+$")[0] === undefined) {
+			return undefined;
+		}
+		numbers.push(tonumber(part)!);
+	}
+	return numbers;
+}
+
+/**
+ * 计算部分版本号的上界（不包含）
+ * 用于把 ^ 和 ~ 展开为 >= 与 < 两个条件
+ * @param parts - 版本号各段
+ * @param keep - 保持不变的段数，最后一段加 1
+ */
+function upperBound(parts: ReadonlyArray<number>, keep: number): Version {
+	if (keep <= 1) {
+		return new Version(parts[0] + 1);
+	}
+	if (keep === 2) {
+		return new Version(parts[0], parts[1] + 1);
+	}
+	return new Version(parts[0], parts[1], parts[2] + 1);
+}
+
+/**
+ * 版本要求
+ *
+ * 支持的格式（与 Cargo 的版本要求一致）：
+ * - `^1.2.3`、`1.2.3`：兼容更新，不改变最左侧的非零段，即 >=1.2.3 <2.0.0
+ * - `~1.2`：只允许修订号更新，即 >=1.2.0 <1.3.0
+ * - `>=1.0`、`>1.0`、`<2.0`、`<=2.0`、`=1.2.3`：比较
+ * - `*`：任意版本
+ * - 多个条件以逗号分隔，需要同时满足，例如 `>=1.2, <1.5`
+ *
+ * @example
+ * ```typescript
+ * requires(): Array<[PluginId, VersionReq]> {
+ *     return [["PhysicsPlugin", VersionReq.parse("^2.0")]];
+ * }
+ * ```
+ */
+export class VersionReq {
+	/**
+	 * 创建版本要求
+	 * @param text - 版本要求原文，用于错误信息
+	 * @param comparators - 需要同时满足的比较条件
+	 */
+	private constructor(
+		private readonly text: string,
+		private readonly comparators: ReadonlyArray<Comparator>,
+	) {}
+
+	/**
+	 * 解析版本要求
+	 * @param text - 版本要求文本
+	 * @returns 版本要求；格式错误时报错
+	 */
+	static parse(text: string): VersionReq {
+		const comparators: Comparator[] = [];
+
+		for (const rawPart of text.split(",")) {
+			const [part] = rawPart.gsub("^%s*(.-)%s*$", "%1");
+			if (part === "*") {
+				continue;
+			}
+
+			const [op, rest] = part.match("^([%^~=<>]*)%s*(.*)$") as LuaTuple<[string, string]>;
+			const parts = parseNumericParts(rest);
+			if (parts === undefined) {
+				error(`Invalid version requirement "${text}"`);
+			}
+			const version = new Version(parts[0], parts[1] ?? 0, parts[2] ?? 0);
+
+			if (op === "" || op === "^") {
+				// 保持最左侧的非零段不变；全为零时保持所有给出的段
+				let keep = 1;
+				while (keep < parts.size() && parts[keep - 1] === 0) {
+					keep++;
+				}
+				comparators.push({ op: ">=", version }, { op: "<", version: upperBound(parts, keep) });
+			} else if (op === "~") {
+				comparators.push(
+					{ op: ">=", version },
+					{ op: "<", version: upperBound(parts, parts.size() === 1 ? 1 : 2) },
+				);
+			} else if (op === "=" && parts.size() < 3) {
+				comparators.push({ op: ">=", version }, { op: "<", version: upperBound(parts, parts.size()) });
+			} else if (op === ">" && parts.size() < 3) {
+				// 省略的段表示任意值，>1.2 要求高于所有 1.2.x
+				comparators.push({ op: ">=", version: upperBound(parts, parts.size()) });
+			} else if (op === "<=" && parts.size() < 3) {
+				// <=1.2 包含所有 1.2.x
+				comparators.push({ op: "<", version: upperBound(parts, parts.size()) });
+			} else if (op === "=" || op === ">" || op === ">=" || op === "<" || op === "<=") {
+				comparators.push({ op, version });
+			} else {
+				error(`Invalid version requirement "${text}"`);
+			}
+		}
+
+		return new VersionReq(text, comparators);
+	}
+
+	/**
+	 * 检查版本是否满足要求
+	 * @param version - 要检查的版本
+	 */
+	matches(version: Version): boolean {
+		for (const comparator of this.comparators) {
+			const ordering = version.compare(comparator.version);
+			let satisfied: boolean;
+			switch (comparator.op) {
+				case "=":
+					satisfied = ordering === 0;
+					break;
+				case ">":
+					satisfied = ordering > 0;
+					break;
+				case ">=":
+					satisfied = ordering >= 0;
+					break;
+				case "<":
+					satisfied = ordering < 0;
+					break;
+				case "<=":
+					satisfied = ordering <= 0;
+					break;
+			}
+			if (!satisfied) {
+				return false;
+			}
+		}
+		return true;
+	}
+
+	/**
+	 * 将版本要求转换为字符串
+	 * @returns 版本要求原文
+	 */
+	toString(): string {
+		return this.text;
+	}
+}
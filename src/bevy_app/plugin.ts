@@ -10,6 +10,13 @@ import type { Schedule } from "../bevy_ecs/schedule/schedule";
 import { Modding } from "@flamework/core";
 import { getTypeDescriptor, TypeDescriptor } from "bevy_core/reflect";
 import { BevyWorld, Context } from "bevy_ecs";
+import type { Version, VersionReq } from "./plugin-version";
+
+/**
+ * 插件标识符
+ * 使用插件名称（Plugin.name() 的返回值）标识插件
+ */
+export type PluginId = string;
 
 /**
  * 插件接口定义
@@ -52,6 +59,14 @@ export interface Plugin<T=undefined> {
 	 */
 	cleanup?(app: App): void;
 
+	/**
+	 * 应用关闭时调用
+	 * 在收到 AppExit 后按插件注册的逆序调用，每个插件只调用一次。
+	 * 可用于刷新日志、关闭连接或持久化状态
+	 * @param app - App实例
+	 */
+	onShutdown?(app: App): void;
+
 	/**
 	 * 插件名称
 	 * 对应 Rust Plugin::name
@@ -66,6 +81,27 @@ export interface Plugin<T=undefined> {
 	 */
 	isUnique(): boolean;
 
+	/**
+	 * 插件依赖
+	 * 通过 App.addPluginsWithDeps 添加时，依赖插件会先于本插件构建
+	 * @returns 依赖插件的标识符列表
+	 */
+	dependencies?(): PluginId[];
+
+	/**
+	 * 插件版本
+	 * 其他插件通过 requires() 声明的版本要求会与此版本比较
+	 * @returns 插件版本，未实现时视为未知版本
+	 */
+	version?(): Version;
+
+	/**
+	 * 插件对其他插件的版本要求
+	 * App.tryBuild 会检查每个要求，被依赖的插件未添加或版本不满足时返回构建错误
+	 * @returns [插件标识符, 版本要求] 列表
+	 */
+	requires?(): Array<[PluginId, VersionReq]>;
+
 	/**
 	 * 该插件适应的roblox域
 	 * - undefined: 服务端和客户端都运行
@@ -125,6 +161,13 @@ export abstract class BasePlugin<T=undefined> implements Plugin<T> {
 	 */
 	cleanup?(_app: App): void;
 
+	/**
+	 * 应用关闭时调用
+	 * 可选实现
+	 * @param _app - App实例（未使用）
+	 */
+	onShutdown?(_app: App): void;
+
 	/**
 	 * 插件名称
 	 * 默认返回"BasePlugin"
@@ -331,6 +374,68 @@ export class DuplicatePluginError extends PluginError {
 	}
 }
 
+/**
+ * 循环依赖错误
+ * 当插件依赖关系中存在环时返回
+ */
+export class CyclicDependencyError extends PluginError {
+	/**
+	 * 创建循环依赖错误
+	 * @param cycle - 构成环的插件标识符，首尾相同
+	 */
+	constructor(public readonly cycle: PluginId[]) {
+		super(`Cyclic plugin dependency detected: ${cycle.join(" -> ")}`, cycle[0]);
+		this.name = "CyclicDependencyError";
+	}
+}
+
+/**
+ * 缺失依赖错误
+ * 当插件声明的依赖既未添加到 App 也不在本次添加的插件中时返回
+ */
+export class MissingDependencyError extends PluginError {
+	/**
+	 * 创建缺失依赖错误
+	 * @param pluginName - 声明依赖的插件名称
+	 * @param dependency - 缺失的依赖标识符
+	 */
+	constructor(
+		pluginName: string,
+		public readonly dependency: PluginId,
+	) {
+		super(`Plugin "${pluginName}" depends on "${dependency}", which was not added`, pluginName);
+		this.name = "MissingDependencyError";
+	}
+}
+
+/**
+ * 版本不匹配错误
+ * 当插件通过 requires() 声明的版本要求与被依赖插件报告的版本不符时返回
+ */
+export class VersionMismatchError extends PluginError {
+	/**
+	 * 创建版本不匹配错误
+	 * @param pluginName - 声明版本要求的插件名称
+	 * @param dependency - 被依赖的插件标识符
+	 * @param required - 版本要求
+	 * @param found - 被依赖插件报告的版本，未实现 version() 时为 undefined
+	 */
+	constructor(
+		pluginName: string,
+		public readonly dependency: PluginId,
+		public readonly required: VersionReq,
+		public readonly found: Version | undefined,
+	) {
+		super(
+			`Plugin "${pluginName}" requires "${dependency}" ${required.toString()}, but found ${
+				found !== undefined ? found.toString() : "unknown version"
+			}`,
+			pluginName,
+		);
+		this.name = "VersionMismatchError";
+	}
+}
+
 // ============================================================================
 // 函数式 Plugin API
 // ============================================================================
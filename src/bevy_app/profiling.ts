@@ -0,0 +1,183 @@
+/**
+ * 系统性能分析
+ * 通过 App.enableProfiling 开启后，记录每个系统每次执行的耗时
+ *
+ * 未开启时不会包装任何系统，没有额外开销
+ *
+ * 耗时由共享的计时包装器测量一次，再分发给所有计时钩子（SystemTimingHooks）。
+ * 性能分析与系统耗时预算（setSystemBudget）都注册为钩子，同时开启时不会重复计时。
+ */
+
+import type { Resource } from "../bevy_ecs/resource";
+import type { ScheduleLabel, SystemWrapper } from "../bevy_ecs/schedule/types";
+
+/**
+ * 单个系统的耗时统计（单位：毫秒）
+ */
+export interface SystemTiming {
+	/** 系统唯一标识 */
+	readonly id: string;
+	/** 系统名称 */
+	readonly name: string;
+	/** 所属调度 */
+	readonly schedule: ScheduleLabel;
+	/** 执行次数 */
+	readonly calls: number;
+	/** 总耗时 */
+	readonly total: number;
+	/** 最短耗时 */
+	readonly min: number;
+	/** 最长耗时 */
+	readonly max: number;
+	/** 平均耗时 */
+	readonly mean: number;
+}
+
+/**
+ * 可变的耗时累积记录
+ */
+interface TimingAccumulator {
+	id: string;
+	name: string;
+	schedule: ScheduleLabel;
+	calls: number;
+	total: number;
+	min: number;
+	max: number;
+}
+
+/**
+ * 性能统计资源
+ * 按系统累积跨帧的耗时统计
+ */
+export class ProfilingStats implements Resource {
+	readonly __brand = "Resource" as const;
+	private readonly timings = new Map<string, TimingAccumulator>();
+
+	/**
+	 * 记录一次系统执行
+	 * @param id - 系统唯一标识
+	 * @param name - 系统名称
+	 * @param schedule - 所属调度
+	 * @param elapsed - 耗时（毫秒）
+	 */
+	record(id: string, name: string, schedule: ScheduleLabel, elapsed: number): void {
+		const timing = this.timings.get(id);
+		if (!timing) {
+			this.timings.set(id, { id, name, schedule, calls: 1, total: elapsed, min: elapsed, max: elapsed });
+			return;
+		}
+
+		timing.calls += 1;
+		timing.total += elapsed;
+		timing.min = math.min(timing.min, elapsed);
+		timing.max = math.max(timing.max, elapsed);
+	}
+
+	/**
+	 * 按名称查找系统耗时
+	 * @param name - 系统名称
+	 * @returns 第一个匹配的统计，不存在时返回 undefined
+	 */
+	getTiming(name: string): SystemTiming | undefined {
+		for (const [, timing] of this.timings) {
+			if (timing.name === name) {
+				return this.toSystemTiming(timing);
+			}
+		}
+		return undefined;
+	}
+
+	/**
+	 * 生成性能报告
+	 * @returns 所有系统的耗时统计，按总耗时降序排列
+	 */
+	report(): SystemTiming[] {
+		const result: SystemTiming[] = [];
+		for (const [, timing] of this.timings) {
+			result.push(this.toSystemTiming(timing));
+		}
+		result.sort((a, b) => a.total > b.total);
+		return result;
+	}
+
+	/**
+	 * 清空所有统计
+	 */
+	reset(): void {
+		this.timings.clear();
+	}
+
+	/**
+	 * 转换为只读统计
+	 * @param timing - 累积记录
+	 * @returns 系统耗时统计
+	 */
+	private toSystemTiming(timing: TimingAccumulator): SystemTiming {
+		return {
+			id: timing.id,
+			name: timing.name,
+			schedule: timing.schedule,
+			calls: timing.calls,
+			total: timing.total,
+			min: timing.min,
+			max: timing.max,
+			mean: timing.total / timing.calls,
+		};
+	}
+}
+
+/**
+ * 系统计时钩子
+ * @param id - 系统唯一标识
+ * @param name - 系统名称
+ * @param schedule - 所属调度
+ * @param elapsed - 本次执行耗时（毫秒）
+ */
+export type SystemTimingHook = (id: string, name: string, schedule: ScheduleLabel, elapsed: number) => void;
+
+/**
+ * 系统计时钩子资源
+ * 保存所有需要系统耗时的钩子，由 createTimingWrapper 创建的包装器在每次系统执行后调用
+ */
+export class SystemTimingHooks implements Resource {
+	readonly __brand = "Resource" as const;
+	private readonly hooks: SystemTimingHook[] = [];
+
+	/**
+	 * 添加计时钩子
+	 * @param hook - 计时钩子
+	 */
+	add(hook: SystemTimingHook): void {
+		this.hooks.push(hook);
+	}
+
+	/**
+	 * 将一次系统执行的耗时分发给所有钩子
+	 * @param id - 系统唯一标识
+	 * @param name - 系统名称
+	 * @param schedule - 所属调度
+	 * @param elapsed - 耗时（毫秒）
+	 */
+	dispatch(id: string, name: string, schedule: ScheduleLabel, elapsed: number): void {
+		for (const hook of this.hooks) {
+			hook(id, name, schedule, elapsed);
+		}
+	}
+}
+
+/**
+ * 创建共享的计时包装器
+ * 每次系统执行只测量一次耗时
+ * @param hooks - 计时钩子资源
+ * @returns 系统包装器
+ */
+export function createTimingWrapper(hooks: SystemTimingHooks): SystemWrapper {
+	return (system, info) => {
+		return (world, context) => {
+			const startTime = os.clock();
+			system(world, context);
+			hooks.dispatch(info.id, info.name, info.schedule, (os.clock() - startTime) * 1000);
+		};
+	};
+}
@@ -0,0 +1,79 @@
+/**
+ * 有序的资源初始化
+ * 通过 initResourceAfter 声明的资源在 finish 阶段按依赖关系依次创建，与插件的添加顺序无关
+ */
+
+import type { TypeDescriptor } from "../bevy_core/reflect";
+import type { ResourceManager } from "../bevy_ecs/resource";
+import { FrameworkBuildError } from "./build-error";
+
+/**
+ * 等待初始化的资源
+ */
+interface PendingResourceInit {
+	readonly descriptor: TypeDescriptor;
+	readonly dependency: TypeDescriptor;
+	readonly create: () => object;
+}
+
+/**
+ * 资源初始化队列
+ */
+export class ResourceInitQueue {
+	private pending: PendingResourceInit[] = [];
+
+	/**
+	 * 添加等待初始化的资源
+	 * @param descriptor - 资源类型描述符
+	 * @param dependency - 依赖的资源类型描述符
+	 * @param create - 创建资源的函数，在依赖存在后调用
+	 */
+	push(descriptor: TypeDescriptor, dependency: TypeDescriptor, create: () => object): void {
+		this.pending.push({ descriptor, dependency, create });
+	}
+
+	/**
+	 * 检查是否有等待初始化的资源
+	 */
+	isEmpty(): boolean {
+		return this.pending.size() === 0;
+	}
+
+	/**
+	 * 按依赖顺序初始化所有等待中的资源
+	 * 资源已经存在时不会被覆盖；依赖始终不存在（包括相互依赖）的资源不会被创建
+	 * @param resourceManager - 资源管理器
+	 * @returns 无法初始化的资源对应的错误
+	 */
+	flush(resourceManager: ResourceManager): FrameworkBuildError[] {
+		let remaining = this.pending;
+		this.pending = [];
+
+		let progressed = true;
+		while (progressed && remaining.size() > 0) {
+			progressed = false;
+			const blocked: PendingResourceInit[] = [];
+
+			for (const init of remaining) {
+				if (resourceManager.hasResourceByDescriptor(init.descriptor)) {
+					progressed = true;
+					continue;
+				}
+
+				if (!resourceManager.hasResourceByDescriptor(init.dependency)) {
+					blocked.push(init);
+					continue;
+				}
+
+				resourceManager.insertResourceByTypeDescriptor(init.create(), init.descriptor);
+				progressed = true;
+			}
+
+			remaining = blocked;
+		}
+
+		return remaining.map((init) =>
+			FrameworkBuildError.missingResourceDependency(init.descriptor.text, init.dependency.text),
+		);
+	}
+}
@@ -0,0 +1,299 @@
+/**
+ * 资源持久化
+ * 通过 App.persistResource 让设置、进度等资源在重启后保留：
+ * 注册时从存储读取上次保存的值，读取失败时回退到默认值；应用关闭时写回存储，也可以在值变化后延迟写回
+ *
+ * Roblox 没有文件系统，"路径" 只是存储中的键，实际读写由 PersistenceStore 实现决定，因此必须显式提供存储：
+ * 需要跨服务器保留时提供基于 DataStore 的实现；MemoryPersistenceStore 只在存储实例存活期间保留，适用于测试。
+ *
+ * 资源按字段编码为 JSON，只保存数字、字符串、布尔值及其组成的表，方法和 __brand 不会被保存。
+ * 读取时将保存的字段覆盖到默认实例上，因此新增的字段会保留默认值；
+ * 类型与默认值不一致的字段会输出警告并保留默认值，默认实例中不存在的字段被忽略。
+ */
+
+import { HttpService } from "@rbxts/services";
+import type { Resource, ResourceManager } from "../bevy_ecs/resource";
+import type { SystemFunction } from "../bevy_ecs/schedule/types";
+import type { TypeDescriptor } from "../bevy_core/reflect";
+import { encodeStableJson } from "../bevy_ecs/snapshot";
+import { BasePlugin } from "./plugin";
+import { BuiltinSchedules } from "./main-schedule";
+import type { App } from "./app";
+
+/**
+ * 持久化存储
+ */
+export interface PersistenceStore {
+	/**
+	 * 读取保存的内容
+	 * @param path - 存储路径
+	 * @returns 保存的文本，不存在时返回 undefined
+	 */
+	read(path: string): string | undefined;
+
+	/**
+	 * 写入内容
+	 * @param path - 存储路径
+	 * @param contents - 要保存的文本
+	 */
+	write(path: string, contents: string): void;
+}
+
+/**
+ * 内存持久化存储
+ * 内容只在存储实例存活期间保留，适用于测试和不需要跨会话保存的场景
+ */
+export class MemoryPersistenceStore implements PersistenceStore {
+	private readonly files = new Map<string, string>();
+
+	read(path: string): string | undefined {
+		return this.files.get(path);
+	}
+
+	write(path: string, contents: string): void {
+		this.files.set(path, contents);
+	}
+}
+
+/**
+ * persistResource 选项
+ */
+export interface PersistOptions {
+	/** 存储 */
+	readonly store: PersistenceStore;
+	/** 值变化后是否自动写回，默认 false，只在关闭时写回 */
+	readonly saveOnChange?: boolean;
+	/** 自动写回的延迟（秒），值在这段时间内没有再变化时才写回，默认 1 */
+	readonly debounce?: number;
+}
+
+/**
+ * 单个持久化资源的记录
+ */
+interface PersistedEntry {
+	readonly path: string;
+	readonly descriptor: TypeDescriptor;
+	readonly store: PersistenceStore;
+	readonly saveOnChange: boolean;
+	readonly debounce: number;
+	/** 最后写入（或读取）的内容 */
+	lastWritten?: string;
+	/** 最近一次观察到的未写入内容 */
+	pending?: string;
+	/** pending 被观察到的时间 */
+	pendingSince: number;
+}
+
+/**
+ * 将资源编码为 JSON
+ * 使用稳定的键顺序，值没有变化时编码结果相同，便于判断是否需要写回
+ * @param resource - 资源实例
+ */
+function encodeResource(resource: object): string {
+	const fields: Record<string, unknown> = {};
+	for (const [key, value] of pairs(resource as Record<string, unknown>)) {
+		if (key === "__brand" || typeIs(value, "function")) {
+			continue;
+		}
+		fields[key] = value;
+	}
+	return encodeStableJson(fields);
+}
+
+/**
+ * 将保存的内容解码到默认实例上
+ * 只覆盖默认实例中已有且类型相同的字段
+ * @param contents - 保存的文本
+ * @param target - 默认实例
+ * @returns 类型与默认值不一致而被跳过的字段；内容无法解析或不是 JSON 对象时返回 undefined，此时 target 不会被修改
+ */
+function decodeInto(contents: string, target: object): string[] | undefined {
+	const [success, decoded] = pcall(() => HttpService.JSONDecode(contents));
+	if (!success || !typeIs(decoded, "table")) {
+		return undefined;
+	}
+
+	const fields = decoded as Record<string, unknown>;
+	for (const [key] of pairs(fields)) {
+		if (!typeIs(key, "string")) {
+			return undefined;
+		}
+	}
+
+	const defaults = target as Record<string, unknown>;
+	const mismatched: string[] = [];
+	for (const [key, value] of pairs(fields)) {
+		const defaultValue = defaults[key];
+		if (key === "__brand" || defaultValue === undefined || typeIs(defaultValue, "function")) {
+			continue;
+		}
+		if (typeOf(value) !== typeOf(defaultValue)) {
+			mismatched.push(key);
+			continue;
+		}
+		defaults[key] = value;
+	}
+	mismatched.sort((a, b) => a < b);
+	return mismatched;
+}
+
+/**
+ * 资源持久化注册表
+ * 由 App.persistResource 在首次调用时插入
+ */
+export class ResourcePersistence implements Resource {
+	readonly __brand = "Resource" as const;
+	private readonly entries: PersistedEntry[] = [];
+
+	/**
+	 * 创建资源持久化注册表
+	 * @param resources - 资源管理器
+	 * @param clock - 计时函数（秒），用于延迟写回
+	 */
+	constructor(
+		private readonly resources: ResourceManager,
+		private readonly clock: () => number = os.clock,
+	) {}
+
+	/**
+	 * 加载资源并登记持久化
+	 * 存储中没有内容或内容无法解析时使用默认值，后者会输出警告；类型不一致的字段同样保留默认值并输出警告
+	 * @param path - 存储路径
+	 * @param descriptor - 资源的类型描述符
+	 * @param createDefault - 创建默认值
+	 * @param options - 持久化选项
+	 * @returns 加载得到的资源实例
+	 */
+	load<R extends object>(
+		path: string,
+		descriptor: TypeDescriptor,
+		createDefault: () => R,
+		options: PersistOptions,
+	): R {
+		const store = options.store;
+		const resource = createDefault();
+
+		const contents = store.read(path);
+		if (contents !== undefined) {
+			const mismatched = decodeInto(contents, resource);
+			if (mismatched === undefined) {
+				warn(`[ResourcePersistence] Failed to parse "${path}" for ${descriptor.text}, using default value`);
+			} else if (mismatched.size() > 0) {
+				warn(
+					`[ResourcePersistence] Fields with unexpected types in "${path}" for ${descriptor.text}, using default values: ${mismatched.join(", ")}`,
+				);
+			}
+		}
+
+		this.entries.push({
+			path,
+			descriptor,
+			store,
+			saveOnChange: options.saveOnChange ?? false,
+			debounce: math.max(options.debounce ?? 1, 0),
+			lastWritten: encodeResource(resource),
+			pendingSince: 0,
+		});
+		return resource;
+	}
+
+	/**
+	 * 检查自动写回的资源是否有变化，变化稳定超过延迟时间后写回
+	 * 由持久化系统在每帧 Last 中调用
+	 */
+	saveChanged(): void {
+		const now = this.clock();
+		for (const entry of this.entries) {
+			if (!entry.saveOnChange) {
+				continue;
+			}
+
+			const resource = this.resources.getResourceByTypeDescriptor<object>(entry.descriptor);
+			if (resource === undefined) {
+				continue;
+			}
+
+			const contents = encodeResource(resource);
+			if (contents === entry.lastWritten) {
+				entry.pending = undefined;
+				continue;
+			}
+			if (contents !== entry.pending) {
+				entry.pending = contents;
+				entry.pendingSince = now;
+			}
+			if (now - entry.pendingSince >= entry.debounce) {
+				this.write(entry, contents);
+			}
+		}
+	}
+
+	/**
+	 * 将所有持久化资源写回存储
+	 * 应用关闭时自动调用；资源已被移除时跳过
+	 */
+	flush(): void {
+		for (const entry of this.entries) {
+			const resource = this.resources.getResourceByTypeDescriptor<object>(entry.descriptor);
+			if (resource !== undefined) {
+				this.write(entry, encodeResource(resource));
+			}
+		}
+	}
+
+	/**
+	 * 获取已登记的存储路径
+	 * @returns 路径列表，按登记顺序排列
+	 */
+	getPaths(): string[] {
+		return this.entries.map((entry) => entry.path);
+	}
+
+	/**
+	 * 写入存储并记录已写入的内容
+	 * @param entry - 持久化记录
+	 * @param contents - 编码后的资源
+	 */
+	private write(entry: PersistedEntry, contents: string): void {
+		entry.store.write(entry.path, contents);
+		entry.lastWritten = contents;
+		entry.pending = undefined;
+	}
+}
+
+/**
+ * 创建持久化系统
+ * @param persistence - 资源持久化注册表
+ * @returns 系统函数
+ */
+export function createPersistenceSystem(persistence: ResourcePersistence): SystemFunction {
+	return () => {
+		persistence.saveChanged();
+	};
+}
+
+/**
+ * 资源持久化插件
+ * 由 App.persistResource 在首次调用时添加，负责自动写回和关闭时写回
+ */
+export class ResourcePersistencePlugin extends BasePlugin {
+	/**
+	 * 创建资源持久化插件
+	 * @param persistence - 资源持久化注册表
+	 */
+	constructor(private readonly persistence: ResourcePersistence) {
+		super();
+	}
+
+	build(app: App): void {
+		app.addSystems(BuiltinSchedules.LAST, createPersistenceSystem(this.persistence));
+	}
+
+	onShutdown(_app: App): void {
+		this.persistence.flush();
+	}
+
+	name(): string {
+		return "ResourcePersistencePlugin";
+	}
+}
@@ -68,9 +68,11 @@ export class RobloxRunnerPlugin extends BasePlugin {
 			task.wait();
 		}
 
-		// 完成插件设置
-		app.finish();
-		app.cleanup();
+		// 完成插件设置并校验构建结果
+		const buildError = app.tryBuild();
+		if (buildError) {
+			error(buildError.toString());
+		}
 
 		// 启动调度将通过 Loop 执行（在 startMainLoop 中处理）
 	}
@@ -112,5 +114,13 @@ export class RobloxRunnerPlugin extends BasePlugin {
 
 		// 启动 Loop（包含所有调度）
 		mainApp.startLoop(events);
+
+		// 收到 AppExit 后关闭插件并停止 Loop
+		const exitConnection = mainEvent.Connect(() => {
+			if (app.processExitRequests()) {
+				exitConnection.Disconnect();
+				mainApp.stopLoop();
+			}
+		});
 	}
 }
\ No newline at end of file
@@ -0,0 +1,75 @@
+/**
+ * 只运行一次的系统
+ * 保证系统在 App 的整个生命周期内只执行一次，即使被重复注册
+ *
+ * 以稳定的键（显式传入的键或系统函数名）识别系统，而不是函数引用：
+ * 插件每次 build 时新建的闭包、热重载后重新加载的模块函数都是不同的函数实例，但属于同一个系统。
+ */
+
+import type { Resource } from "../bevy_ecs/resource";
+import type { SystemFunction } from "../bevy_ecs/schedule/types";
+
+/**
+ * 获取只运行一次的系统的键
+ * @param system - 系统函数
+ * @param key - 显式指定的键
+ * @returns 显式指定的键，否则为系统函数名
+ */
+export function getRunOnceKey(system: SystemFunction, key?: string): string {
+	if (key !== undefined) {
+		return key;
+	}
+
+	const [name] = debug.info(system, "n");
+	assert(
+		name !== undefined && name !== "",
+		"addStartupOnce: anonymous systems need an explicit key to be identified across registrations",
+	);
+	return name;
+}
+
+/**
+ * 只运行一次的系统标记资源
+ * 以系统键为键记录注册与执行状态
+ */
+export class RunOnceFlags implements Resource {
+	readonly __brand = "Resource" as const;
+	private readonly registeredKeys = new Set<string>();
+	private readonly completedKeys = new Set<string>();
+
+	/**
+	 * 记录系统注册
+	 * @param key - 系统键
+	 * @returns 首次注册时返回 true，已注册过时返回 false
+	 */
+	register(key: string): boolean {
+		if (this.registeredKeys.has(key)) {
+			return false;
+		}
+
+		this.registeredKeys.add(key);
+		return true;
+	}
+
+	/**
+	 * 尝试占用系统的执行机会
+	 * @param key - 系统键
+	 * @returns 系统尚未执行过时返回 true 并将其标记为已执行
+	 */
+	claim(key: string): boolean {
+		if (this.completedKeys.has(key)) {
+			return false;
+		}
+
+		this.completedKeys.add(key);
+		return true;
+	}
+
+	/**
+	 * 检查系统是否已经执行过
+	 * @param key - 系统键
+	 */
+	hasRun(key: string): boolean {
+		return this.completedKeys.has(key);
+	}
+}
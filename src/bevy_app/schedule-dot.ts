@@ -0,0 +1,80 @@
+/**
+ * 调度图导出
+ * 将调度中系统的排序约束导出为 Graphviz DOT 文本，用于排查系统执行顺序问题
+ *
+ * 导出使用系统注册时保留的 before/after/inSet 配置，调度编译前后都可以调用。
+ * 边的方向与执行顺序一致：A -> B 表示 A 在 B 之前执行。
+ */
+
+import type { Schedule } from "../bevy_ecs/schedule/schedule";
+import type { InternalSystemStruct, SystemSet } from "../bevy_ecs/schedule/types";
+import { getSystemDisplayName } from "./system-dedup";
+
+/**
+ * 转义 DOT 字符串中的特殊字符
+ * @param text - 原始文本
+ * @returns 带引号的 DOT 字符串
+ */
+function quote(text: string): string {
+	const [escaped] = text.gsub("\\", "\\\\");
+	const [result] = escaped.gsub('"', '\\"');
+	return `"${result}"`;
+}
+
+/**
+ * 将调度导出为 Graphviz DOT 文本
+ * - 每个系统是一个节点，节点标签为系统名称
+ * - 同一系统集中的系统放在同一个 cluster 中
+ * - 系统的 before/after 约束是实线边，标签为约束类型
+ * - 系统集之间的 before/after 约束是连接两个集合成员的虚线边
+ * @param schedule - 要导出的调度
+ * @returns DOT 文本
+ */
+export function scheduleToDot(schedule: Schedule): string {
+	const systems = schedule.getSystemsInRegistrationOrder();
+
+	const setOrder: SystemSet[] = [];
+	for (const system of systems) {
+		if (system.inSet !== undefined && !setOrder.includes(system.inSet)) {
+			setOrder.push(system.inSet);
+		}
+	}
+
+	const lines: string[] = [`digraph ${quote(schedule.getLabel())} {`, "\trankdir=LR;", "\tnode [shape=box];"];
+	const node = (system: InternalSystemStruct) =>
+		`${quote(system.id)} [label=${quote(getSystemDisplayName(system))}];`;
+
+	for (let index = 0; index < setOrder.size(); index++) {
+		const set = setOrder[index];
+		lines.push(`\tsubgraph ${quote(`cluster_${index}`)} {`);
+		lines.push(`\t\tlabel=${quote(set)};`);
+		for (const system of systems) {
+			if (system.inSet === set) {
+				lines.push(`\t\t${node(system)}`);
+			}
+		}
+		lines.push("\t}");
+	}
+	for (const system of systems) {
+		if (system.inSet === undefined) {
+			lines.push(`\t${node(system)}`);
+		}
+	}
+
+	const emitted = new Set<string>();
+	const edge = (from: string, to: string, attributes: string) => {
+		const line = `\t${quote(from)} -> ${quote(to)} [${attributes}];`;
+		if (from !== to && !emitted.has(line)) {
+			emitted.add(line);
+			lines.push(line);
+		}
+	};
+
+	for (const ordering of schedule.getOrderingEdges()) {
+		const style = ordering.viaSet ? ", style=dashed" : "";
+		edge(ordering.from, ordering.to, `label="${ordering.kind}"${style}`);
+	}
+
+	lines.push("}");
+	return lines.join("\n");
+}
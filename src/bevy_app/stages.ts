@@ -0,0 +1,132 @@
+/**
+ * 自定义阶段
+ * 在一个调度内部按声明顺序依次执行的命名阶段
+ *
+ * 每个阶段对应一个系统集，阶段之间通过系统集的 after 关系串联，
+ * 系统通过 inStage(label) 加入阶段，在前一个阶段的所有系统完成后执行。
+ */
+
+import type { Schedules } from "../bevy_ecs/schedule/schedules";
+import type { ScheduleLabel, SystemSet } from "../bevy_ecs/schedule/types";
+
+/**
+ * 阶段标签
+ */
+export type StageLabel = SystemSet;
+
+/**
+ * 阶段对应的系统集配置
+ */
+interface StageSetConfig {
+	name: SystemSet;
+	before: SystemSet[];
+	after: SystemSet[];
+}
+
+/**
+ * 阶段错误
+ */
+export class StageError {
+	public name = "StageError";
+
+	/**
+	 * 创建阶段错误
+	 * @param message - 错误信息
+	 * @param stage - 出错的阶段标签
+	 */
+	constructor(
+		public readonly message: string,
+		public readonly stage: StageLabel,
+	) {}
+
+	/**
+	 * 将错误转换为字符串
+	 * @returns 错误信息字符串
+	 */
+	toString(): string {
+		return `${this.name}: ${this.message}`;
+	}
+}
+
+/**
+ * 单个调度内的阶段顺序
+ */
+export class StageOrder {
+	private readonly order: StageLabel[] = [];
+	/**
+	 * 阶段对应的系统集配置
+	 * Schedule 持有这些对象的引用并在编译时读取，插入新阶段时原地更新 after
+	 */
+	private readonly setConfigs = new Map<StageLabel, StageSetConfig>();
+
+	/**
+	 * 创建阶段顺序
+	 * @param schedules - 调度管理器
+	 * @param schedule - 阶段所在的调度
+	 */
+	constructor(
+		private readonly schedules: Schedules,
+		private readonly schedule: ScheduleLabel,
+	) {}
+
+	/**
+	 * 添加阶段
+	 * @param label - 阶段标签
+	 * @param after - 前一个阶段；省略时追加到最后一个阶段之后
+	 * @returns 阶段已存在或 after 指向不存在的阶段时返回错误
+	 */
+	add(label: StageLabel, after?: StageLabel): StageError | undefined {
+		if (this.setConfigs.has(label)) {
+			return new StageError(`Stage "${label}" already exists in schedule "${this.schedule}"`, label);
+		}
+
+		let insertIndex = this.order.size();
+		if (after !== undefined) {
+			const afterIndex = this.order.indexOf(after);
+			if (afterIndex === -1) {
+				return new StageError(
+					`Cannot add stage "${label}" after "${after}": stage does not exist in schedule "${this.schedule}"`,
+					label,
+				);
+			}
+			insertIndex = afterIndex + 1;
+		}
+
+		const setConfig: StageSetConfig = { name: label, before: [], after: [] };
+		this.schedules.configureSetInSchedule(this.schedule, setConfig);
+		this.setConfigs.set(label, setConfig);
+		this.order.insert(insertIndex, label);
+		this.relink();
+		return undefined;
+	}
+
+	/**
+	 * 检查阶段是否存在
+	 * @param label - 阶段标签
+	 */
+	has(label: StageLabel): boolean {
+		return this.setConfigs.has(label);
+	}
+
+	/**
+	 * 获取阶段的执行顺序
+	 * @returns 阶段标签列表
+	 */
+	getOrder(): ReadonlyArray<StageLabel> {
+		return this.order;
+	}
+
+	/**
+	 * 重新建立阶段之间的 after 关系
+	 * 每个阶段依赖之前的所有阶段，这样中间的阶段没有系统时顺序仍然成立
+	 */
+	private relink(): void {
+		for (let index = 0; index < this.order.size(); index++) {
+			const setConfig = this.setConfigs.get(this.order[index])!;
+			setConfig.after.clear();
+			for (let previous = 0; previous < index; previous++) {
+				setConfig.after.push(this.order[previous]);
+			}
+		}
+	}
+}
@@ -7,16 +7,24 @@ import { AppLabel, ErrorHandler } from "./types";
 import { Plugin, PluginState } from "./plugin";
 import { WorldContainer, World, Context, createWorldAndContext } from "../bevy_ecs";
 import { ResourceManager, Resource } from "../bevy_ecs/resource";
+import { ComponentId, getComponentIdByDescriptor } from "../bevy_ecs/component/component-id";
+import type { TypeDescriptor } from "../bevy_core/reflect";
 import { CommandBuffer } from "../bevy_ecs/command-buffer";
 import { Message, MessageRegistry } from "../bevy_ecs/message";
 import { MainScheduleOrder, FixedMainScheduleOrder, BuiltinSchedules } from "./main-schedule";
 import { App } from "./app";
 import { Schedule } from "../bevy_ecs/schedule/schedule";
 import { Schedules } from "../bevy_ecs/schedule/schedules";
-import type { SystemFunction, SystemConfig, ScheduleLabel } from "../bevy_ecs/schedule/types";
+import type { SystemFunction, SystemConfig, ScheduleLabel, SystemSet } from "../bevy_ecs/schedule/types";
 import { intoSystemConfigs } from "../bevy_ecs/schedule/system-configs";
 import type { IntoSystemConfigs } from "../bevy_ecs/schedule";
 import { Modding } from "@flamework/core";
+import { DedupPolicy, SystemDedupTracker } from "./system-dedup";
+import { SystemGroup } from "./system-group";
+import { SystemRegistry } from "./system-registry";
+import { HotSystems } from "./hot-systems";
+import { StageError, StageLabel, StageOrder } from "./stages";
+import { DeferredDespawn } from "../bevy_ecs/deferred-despawn";
 
 // 前向声明 App 类型
 interface AppInterface {
@@ -37,6 +45,9 @@ export class SubApp {
 	private pluginRegistry: Plugin[] = [];
 	private pluginNames = new Set<string>();
 	private pluginBuildDepth = 0;
+	private nestedPlugins = new Set<Plugin>();
+	private pluginSystems = new Set<SystemFunction>();
+	private pluginResourceIds = new Set<ComponentId>();
 	private _pluginState: PluginState = PluginState.Adding;
 	private updateSchedule?: ScheduleLabel;
 	private extractFunction?: (mainWorld: WorldContainer, subWorld: WorldContainer) => void;
@@ -47,6 +58,13 @@ export class SubApp {
 	private context: Context;
 	private loopConnections?: { [scheduleLabel: string]: RBXScriptConnection };
 	private isLoopRunning = false;
+	private systemDedup = new SystemDedupTracker();
+	private systemGroups = new Map<ScheduleLabel, Map<SystemSet, SystemGroup>>();
+	private hasShutdown = false;
+	private systemRegistry = new SystemRegistry();
+	private deferredDespawn = new DeferredDespawn();
+	private deferredDespawnRegistered = false;
+	private stageOrders = new Map<ScheduleLabel, StageOrder>();
 
 	constructor() {
 
@@ -63,6 +81,9 @@ export class SubApp {
 		this.resourceManager = this.world().world.resources;
 		this.commandBuffer = this.world().world.commands;
 		this.messageRegistry = this.world().world.messages;
+		this.resourceManager.insertResource(this.systemRegistry);
+		this.resourceManager.insertResource(new HotSystems(this));
+		this.resourceManager.insertResource(this.deferredDespawn);
 
 
 		this.schedules = new Schedules(this._world.world, this.context);
@@ -210,6 +231,7 @@ export class SubApp {
 		// 这确保 Matter hooks 正常工作，并且 once 属性被正确处理
 
 		// 确保所有调度都已编译并注册到 Loop
+		this.registerDeferredDespawnSystem();
 		this.schedules.compile();
 		const loop = this.schedules.getLoop();
 
@@ -254,11 +276,111 @@ export class SubApp {
 			const configs = systemConfigs.toSystemConfigs();
 			// 添加到调度
 			for (const config of configs) {
-				this.schedules.addSystemToSchedule(schedule, config);
+				this.addSystemConfig(schedule, config);
 			}
 		}
 	}
 
+	/**
+	 * 添加单个已展开的系统配置
+	 * 经过去重处理后注册到调度，并记录到系统注册表
+	 * @param schedule - 调度标签
+	 * @param config - 系统配置
+	 * @returns 注册得到的系统 ID；被去重跳过时返回 undefined
+	 */
+	addSystemConfig(schedule: ScheduleLabel, config: SystemConfig): string | undefined {
+		const dedupedConfig = this.systemDedup.process(schedule, config);
+		if (dedupedConfig === undefined) {
+			return undefined;
+		}
+		const systemId = this.schedules.addSystemToSchedule(schedule, dedupedConfig);
+		this.systemRegistry.record(schedule, config, systemId);
+		if (this.pluginBuildDepth > 0) {
+			this.pluginSystems.add(config.system);
+		}
+		return systemId;
+	}
+
+	/**
+	 * 设置重复系统处理策略
+	 * @param policy - 去重策略
+	 */
+	setDedupPolicy(policy: DedupPolicy): void {
+		this.systemDedup.setPolicy(policy);
+	}
+
+	/**
+	 * 获取系统去重跟踪器
+	 */
+	getSystemDedup(): SystemDedupTracker {
+		return this.systemDedup;
+	}
+
+	/**
+	 * 获取系统注册表
+	 */
+	getSystemRegistry(): SystemRegistry {
+		return this.systemRegistry;
+	}
+
+	/**
+	 * 替换系统实现
+	 * 新系统沿用旧系统的调度位置和排序关系，编译后调用在下一帧生效
+	 * @param systemId - 被替换系统的标识符
+	 * @param newSystem - 新的系统函数
+	 */
+	replaceSystem(systemId: string, newSystem: SystemFunction): void {
+		const { schedule, oldSystem } = this.schedules.replaceSystem(systemId, newSystem);
+		this.systemDedup.replace(schedule, oldSystem, newSystem);
+		this.systemRegistry.replace(systemId, newSystem);
+	}
+
+	/**
+	 * 在调度中添加自定义阶段
+	 * @param schedule - 调度标签
+	 * @param label - 阶段标签
+	 * @param after - 前一个阶段；省略时追加到最后一个阶段之后
+	 * @returns 添加失败时返回错误
+	 */
+	addStage(schedule: ScheduleLabel, label: StageLabel, after?: StageLabel): StageError | undefined {
+		let stageOrder = this.stageOrders.get(schedule);
+		if (!stageOrder) {
+			stageOrder = new StageOrder(this.schedules, schedule);
+			this.stageOrders.set(schedule, stageOrder);
+		}
+		return stageOrder.add(label, after);
+	}
+
+	/**
+	 * 获取调度中的阶段顺序
+	 * @param schedule - 调度标签
+	 * @returns 阶段标签列表，按执行顺序排列
+	 */
+	getStages(schedule: ScheduleLabel): ReadonlyArray<StageLabel> {
+		return this.stageOrders.get(schedule)?.getOrder() ?? [];
+	}
+
+	/**
+	 * 获取或创建系统组
+	 * @param schedule - 调度标签
+	 * @param label - 组标签
+	 * @returns 系统组，同一调度内同名的组返回同一个实例
+	 */
+	systemGroup(schedule: ScheduleLabel, label: SystemSet): SystemGroup {
+		let groups = this.systemGroups.get(schedule);
+		if (!groups) {
+			groups = new Map();
+			this.systemGroups.set(schedule, groups);
+		}
+
+		let group = groups.get(label);
+		if (!group) {
+			group = new SystemGroup(this, schedule, label);
+			groups.set(label, group);
+		}
+		return group;
+	}
+
 	/**
 	 * 运行指定的调度
 	 * 对应 Rust App::try_run_schedule
@@ -390,11 +512,16 @@ export class SubApp {
 
 	/**
 	 * 添加插件
+	 * 构建期间注册的系统和插入的资源会被记录为插件所有，App.merge 不会转移它们
 	 */
 	addPlugin(plugin: Plugin): void {
+		if (this.pluginBuildDepth > 0) {
+			this.nestedPlugins.add(plugin);
+		}
 		this.pluginRegistry.push(plugin);
 		this.pluginNames.add(plugin.name());
 
+		const existingResources = new Set(this.resourceManager.getResourceIds());
 		this.pluginBuildDepth += 1;
 		try {
 			if (this.appReference) {
@@ -405,6 +532,52 @@ export class SubApp {
 		} finally {
 			this.pluginBuildDepth -= 1;
 		}
+
+		for (const id of this.resourceManager.getResourceIds()) {
+			if (!existingResources.has(id)) {
+				this.pluginResourceIds.add(id);
+			}
+		}
+	}
+
+	/**
+	 * 将资源记录为插件所有
+	 * 用于插件构建之外由框架代插件插入的资源，例如插件扩展
+	 * @param descriptor - 资源的类型描述符
+	 */
+	markPluginResource(descriptor: TypeDescriptor): void {
+		this.pluginResourceIds.add(getComponentIdByDescriptor(descriptor));
+	}
+
+	/**
+	 * 检查插件是否由其他插件在构建期间添加
+	 * @param plugin - 插件实例
+	 */
+	isNestedPlugin(plugin: Plugin): boolean {
+		return this.nestedPlugins.has(plugin);
+	}
+
+	/**
+	 * 检查系统是否由插件在构建期间注册
+	 * @param system - 系统函数
+	 */
+	isPluginSystem(system: SystemFunction): boolean {
+		return this.pluginSystems.has(system);
+	}
+
+	/**
+	 * 检查资源是否由插件插入
+	 * @param id - 资源 ID
+	 */
+	isPluginResource(id: ComponentId): boolean {
+		return this.pluginResourceIds.has(id);
+	}
+
+	/**
+	 * 获取所有已添加的插件
+	 */
+	getPlugins(): ReadonlyArray<Plugin> {
+		return this.pluginRegistry;
 	}
 
 	/**
@@ -465,9 +638,31 @@ export class SubApp {
 				plugin.finish(this.appReference as unknown as App);
 			}
 		}
+		this.registerDeferredDespawnSystem();
 		this._pluginState = PluginState.Finished;
 	}
 
+	/**
+	 * 注册执行延迟销毁的框架系统
+	 * 在调度编译前注册，使其在 Last 中排在所有系统之后：拓扑排序模式下依靠最大优先级，
+	 * 确定性模式下依靠最晚的注册顺序。手动 update 和 Loop 运行器都会执行该系统
+	 */
+	private registerDeferredDespawnSystem(): void {
+		if (this.deferredDespawnRegistered || this.schedules.getSchedule(BuiltinSchedules.LAST).getState().compiled) {
+			return;
+		}
+		this.deferredDespawnRegistered = true;
+
+		const deferredDespawn = this.deferredDespawn;
+		this.schedules.addSystemToSchedule(BuiltinSchedules.LAST, {
+			system: (world: World) => {
+				deferredDespawn.flush(world);
+			},
+			name: "DeferredDespawn::flush",
+			priority: math.huge,
+		});
+	}
+
 	/**
 	 * 清理插件
 	 */
@@ -480,6 +675,31 @@ export class SubApp {
 		this._pluginState = PluginState.Cleaned;
 	}
 
+	/**
+	 * 关闭插件
+	 * 按注册的逆序调用插件的 onShutdown，重复调用时不会再次执行
+	 */
+	shutdown(): void {
+		if (this.hasShutdown) {
+			return;
+		}
+		this.hasShutdown = true;
+
+		for (let index = this.pluginRegistry.size() - 1; index >= 0; index--) {
+			const plugin = this.pluginRegistry[index];
+			if (plugin.onShutdown !== undefined && this.appReference) {
+				plugin.onShutdown(this.appReference as unknown as App);
+			}
+		}
+	}
+
+	/**
+	 * 检查是否已关闭
+	 */
+	isShutdown(): boolean {
+		return this.hasShutdown;
+	}
+
 	/**
 	 * 设置错误处理器
 	 */
@@ -522,6 +742,7 @@ export class SubApp {
 		}
 
 		// 编译并启动 Loop
+		this.registerDeferredDespawnSystem();
 		this.schedules.compile();
 		this.loopConnections = this.schedules.begin(events);
 		this.isLoopRunning = true;
@@ -671,6 +892,17 @@ export class SubApps {
 		}
 	}
 
+	/**
+	 * 关闭所有SubApp的插件
+	 * 先关闭子应用，最后关闭主应用
+	 */
+	shutdown(): void {
+		for (const [_, subApp] of this.subApps) {
+			subApp.shutdown();
+		}
+		this._main.shutdown();
+	}
+
 	/**
 	 * 获取指定标签的SubApp
 	 * @param label - SubApp标签
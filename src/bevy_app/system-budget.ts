@@ -0,0 +1,100 @@
+/**
+ * 系统耗时预算
+ * 通过 App.setSystemBudget 开启后，单个系统在一帧内的耗时超过预算时输出警告，用于发现失控的系统
+ *
+ * 未开启时不会包装任何系统，没有额外开销。
+ * 耗时复用性能分析的计时包装器（见 profiling 模块的 SystemTimingHooks），预算检查只注册为计时钩子。
+ * 同一个系统每秒最多警告一次，避免持续超时的系统刷屏。
+ */
+
+import type { Resource } from "../bevy_ecs/resource";
+import type { ScheduleLabel } from "../bevy_ecs/schedule/types";
+
+/**
+ * 同一系统两次警告之间的最短间隔（秒）
+ */
+const WARN_INTERVAL = 1;
+
+/**
+ * 超出预算的记录
+ */
+export interface BudgetViolation {
+	/** 系统唯一标识 */
+	readonly id: string;
+	/** 系统名称 */
+	readonly name: string;
+	/** 所属调度 */
+	readonly schedule: ScheduleLabel;
+	/** 本次执行耗时（毫秒） */
+	readonly elapsed: number;
+	/** 当时的预算（毫秒） */
+	readonly budget: number;
+}
+
+/**
+ * 系统耗时预算资源
+ * 保存预算并记录已经输出的超时警告
+ */
+export class SystemBudget implements Resource {
+	readonly __brand = "Resource" as const;
+	private readonly lastWarned = new Map<string, number>();
+	private readonly violations: BudgetViolation[] = [];
+
+	/**
+	 * 创建系统耗时预算
+	 * @param budget - 单个系统单帧的耗时预算（毫秒）
+	 */
+	constructor(private budget: number) {}
+
+	/**
+	 * 获取预算
+	 * @returns 预算（毫秒）
+	 */
+	getBudget(): number {
+		return this.budget;
+	}
+
+	/**
+	 * 修改预算
+	 * @param budget - 新的预算（毫秒）
+	 */
+	setBudget(budget: number): void {
+		this.budget = budget;
+	}
+
+	/**
+	 * 检查一次系统执行是否超出预算
+	 * 超出时输出警告并记录，同一系统在 WARN_INTERVAL 秒内只警告一次
+	 * @param id - 系统唯一标识
+	 * @param name - 系统名称
+	 * @param schedule - 所属调度
+	 * @param elapsed - 耗时（毫秒）
+	 * @param now - 当前时间（秒），默认使用 os.clock()
+	 * @returns 是否输出了警告
+	 */
+	check(id: string, name: string, schedule: ScheduleLabel, elapsed: number, now: number = os.clock()): boolean {
+		if (elapsed <= this.budget) {
+			return false;
+		}
+
+		const last = this.lastWarned.get(id);
+		if (last !== undefined && now - last < WARN_INTERVAL) {
+			return false;
+		}
+
+		this.lastWarned.set(id, now);
+		this.violations.push({ id, name, schedule, elapsed, budget: this.budget });
+		warn(
+			`[SystemBudget] System "${name}" in schedule "${schedule}" took ${string.format("%.2f", elapsed)}ms, budget is ${this.budget}ms`,
+		);
+		return true;
+	}
+
+	/**
+	 * 获取已输出警告的超时记录
+	 * @returns 超时记录列表，按时间顺序排列
+	 */
+	getViolations(): ReadonlyArray<BudgetViolation> {
+		return this.violations;
+	}
+}
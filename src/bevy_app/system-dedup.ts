@@ -0,0 +1,214 @@
+/**
+ * 系统去重跟踪
+ * 检测同一系统被重复注册到同一调度的情况
+ *
+ * Rust Bevy 对重复注册静默接受，同一系统每帧会运行多次，这几乎总是配置错误。
+ * 本模块按调度记录已注册的系统函数，并根据 DedupPolicy 决定如何处理重复注册。
+ *
+ * 开启跨调度检查后，同一系统函数被注册到多个调度（例如同时在 Update 和 FixedUpdate 中）时也会警告，
+ * 确实需要在多个调度中运行的系统可以通过 allowMultiSchedule 豁免。
+ */
+
+import type { ScheduleLabel, SystemConfig, SystemFunction } from "../bevy_ecs/schedule/types";
+
+/**
+ * 重复系统处理策略
+ */
+export enum DedupPolicy {
+	/** 首次检测到重复时输出警告，仍然注册（与 Rust Bevy 行为一致，系统会多次运行） */
+	Warn = "Warn",
+	/** 静默跳过重复注册，系统每帧只运行一次 */
+	Skip = "Skip",
+	/** 检测到重复时直接报错，适用于测试 */
+	Panic = "Panic",
+}
+
+/**
+ * 跨调度重复注册记录
+ */
+export interface CrossScheduleDuplicate {
+	/** 系统名称 */
+	readonly name: string;
+	/** 系统此前已注册的调度 */
+	readonly existing: ScheduleLabel;
+	/** 本次注册的调度 */
+	readonly schedule: ScheduleLabel;
+}
+
+/**
+ * 获取系统的显示名称
+ * 优先使用配置中的名称，否则使用函数名
+ * @param config - 系统配置
+ * @returns 系统名称
+ */
+export function getSystemDisplayName(config: SystemConfig): string {
+	if (config.name !== undefined) {
+		return config.name;
+	}
+
+	const [name] = debug.info(config.system, "n");
+	return name !== undefined && name !== "" ? name : "anonymous";
+}
+
+/**
+ * 系统去重跟踪器
+ * 按调度记录每个已注册的系统函数
+ */
+export class SystemDedupTracker {
+	private policy?: DedupPolicy;
+	private readonly registered = new Map<ScheduleLabel, Set<SystemFunction>>();
+	private readonly warned = new Map<ScheduleLabel, Set<SystemFunction>>();
+	private crossScheduleCheck = false;
+	private readonly multiScheduleAllowed = new Set<SystemFunction>();
+	private readonly crossScheduleDuplicates: CrossScheduleDuplicate[] = [];
+
+	/**
+	 * 设置去重策略
+	 * @param policy - 去重策略
+	 */
+	setPolicy(policy: DedupPolicy): void {
+		this.policy = policy;
+	}
+
+	/**
+	 * 获取当前去重策略
+	 * @returns 去重策略，未设置时返回 undefined
+	 */
+	getPolicy(): DedupPolicy | undefined {
+		return this.policy;
+	}
+
+	/**
+	 * 开启跨调度重复检查
+	 * 之后每当一个系统函数被注册到它尚未注册过的调度、且已注册在其他调度中时输出警告
+	 */
+	enableCrossScheduleCheck(): void {
+		this.crossScheduleCheck = true;
+	}
+
+	/**
+	 * 允许系统注册到多个调度，跨调度检查不再对它警告
+	 * @param system - 系统函数
+	 */
+	allowMultiSchedule(system: SystemFunction): void {
+		this.multiScheduleAllowed.add(system);
+	}
+
+	/**
+	 * 获取检测到的跨调度重复注册
+	 * @returns 重复注册记录，按检测顺序排列
+	 */
+	getCrossScheduleDuplicates(): ReadonlyArray<CrossScheduleDuplicate> {
+		return this.crossScheduleDuplicates;
+	}
+
+	/**
+	 * 检查系统是否已注册到指定调度
+	 * @param schedule - 调度标签
+	 * @param system - 系统函数
+	 * @returns 是否已注册
+	 */
+	isRegistered(schedule: ScheduleLabel, system: SystemFunction): boolean {
+		return this.registered.get(schedule)?.has(system) ?? false;
+	}
+
+	/**
+	 * 处理一次系统注册
+	 * 未设置策略时原样返回配置，由 Schedule 自身决定如何处理重复
+	 * @param schedule - 调度标签
+	 * @param config - 系统配置
+	 * @returns 需要注册的配置；返回 undefined 表示跳过本次注册
+	 */
+	process(schedule: ScheduleLabel, config: SystemConfig): SystemConfig | undefined {
+		if (!this.isRegistered(schedule, config.system)) {
+			this.checkCrossSchedule(schedule, config);
+			this.record(schedule, config.system);
+			return config;
+		}
+
+		if (this.policy === undefined) {
+			return config;
+		}
+
+		const systemName = getSystemDisplayName(config);
+
+		if (this.policy === DedupPolicy.Panic) {
+			error(`System "${systemName}" was registered more than once in schedule "${schedule}"`);
+		}
+
+		if (this.policy === DedupPolicy.Skip) {
+			return undefined;
+		}
+
+		// Warn: 只在首次检测到时警告
+		let warnedSystems = this.warned.get(schedule);
+		if (!warnedSystems) {
+			warnedSystems = new Set();
+			this.warned.set(schedule, warnedSystems);
+		}
+		if (!warnedSystems.has(config.system)) {
+			warnedSystems.add(config.system);
+			warn(`[SystemDedup] System "${systemName}" was registered more than once in schedule "${schedule}"`);
+		}
+
+		// Schedule 按函数引用拒绝重复，包装为新函数以保留额外的运行槽位
+		const originalSystem = config.system;
+		return {
+			...config,
+			name: config.name ?? systemName,
+			system: (world, context) => originalSystem(world, context),
+		};
+	}
+
+	/**
+	 * 将已注册的系统替换为新系统
+	 * @param schedule - 调度标签
+	 * @param oldSystem - 旧的系统函数
+	 * @param newSystem - 新的系统函数
+	 */
+	replace(schedule: ScheduleLabel, oldSystem: SystemFunction, newSystem: SystemFunction): void {
+		const systems = this.registered.get(schedule);
+		if (systems?.delete(oldSystem)) {
+			systems.add(newSystem);
+		}
+	}
+
+	/**
+	 * 检查系统是否已注册到其他调度
+	 * @param schedule - 本次注册的调度
+	 * @param config - 系统配置
+	 */
+	private checkCrossSchedule(schedule: ScheduleLabel, config: SystemConfig): void {
+		if (!this.crossScheduleCheck || this.multiScheduleAllowed.has(config.system)) {
+			return;
+		}
+
+		for (const [existing, systems] of this.registered) {
+			if (existing === schedule || !systems.has(config.system)) {
+				continue;
+			}
+
+			const name = getSystemDisplayName(config);
+			this.crossScheduleDuplicates.push({ name, existing, schedule });
+			warn(
+				`[SystemDedup] System "${name}" is registered in both schedule "${existing}" and "${schedule}", ` +
+					"call allowMultiSchedule if this is intended",
+			);
+			return;
+		}
+	}
+
+	/**
+	 * 记录系统注册
+	 * @param schedule - 调度标签
+	 * @param system - 系统函数
+	 */
+	private record(schedule: ScheduleLabel, system: SystemFunction): void {
+		let systems = this.registered.get(schedule);
+		if (!systems) {
+			systems = new Set();
+			this.registered.set(schedule, systems);
+		}
+		systems.add(system);
+	}
+}
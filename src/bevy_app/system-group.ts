@@ -0,0 +1,140 @@
+/**
+ * 系统组
+ * 以系统集为单位组织系统，避免在大量系统之间逐个书写 before/after
+ *
+ * 组内所有系统都会加入与组同名的系统集（对应 Rust 的 in_set），
+ * 组之间的顺序通过系统集配置表达（对应 Rust 的 configure_sets）。
+ *
+ * 互斥组（App.addSystemsExclusiveGroup）是按添加顺序链式执行的组：组内任意两个系统之间都有确定的先后约束，
+ * 因此不会被视为可以并行的歧义系统。
+ * 互斥组不需要锁：Matter Loop 在同一线程上逐个调用系统，系统函数也不允许 yield，
+ * 一个系统返回之前不会有其他系统开始执行，所以 Luau 中组内系统不可能重叠。
+ */
+
+import { intoSystemConfigs, SystemConfigs } from "../bevy_ecs/schedule/system-configs";
+import type { IntoSystemConfigs } from "../bevy_ecs/schedule";
+import type { ScheduleLabel, SystemFunction, SystemSet } from "../bevy_ecs/schedule/types";
+import type { SubApp } from "./sub-app";
+
+/**
+ * 系统组构建器
+ * 通过 App.systemGroup 获取，同一调度内同名的组返回同一个实例
+ */
+export class SystemGroup {
+	private chained = false;
+	private lastSystem?: SystemFunction;
+	private readonly systems: SystemFunction[] = [];
+	/**
+	 * 系统集配置
+	 * Schedule 持有该对象的引用并在编译时读取，因此 before/after 可以在注册后继续追加
+	 */
+	private readonly setConfig: { name: SystemSet; before: SystemSet[]; after: SystemSet[] };
+
+	/**
+	 * 创建系统组并注册对应的系统集
+	 * @param subApp - 所属 SubApp
+	 * @param schedule - 调度标签
+	 * @param label - 组标签，同时作为系统集名称
+	 */
+	constructor(
+		private readonly subApp: SubApp,
+		private readonly schedule: ScheduleLabel,
+		private readonly label: SystemSet,
+	) {
+		this.setConfig = { name: label, before: [], after: [] };
+		subApp.getSchedules().configureSetInSchedule(schedule, this.setConfig);
+	}
+
+	/**
+	 * 获取组标签
+	 * @returns 组标签
+	 */
+	getLabel(): SystemSet {
+		return this.label;
+	}
+
+	/**
+	 * 获取所属调度
+	 * @returns 调度标签
+	 */
+	getSchedule(): ScheduleLabel {
+		return this.schedule;
+	}
+
+	/**
+	 * 获取添加到组中的系统
+	 * @returns 系统函数列表，按添加顺序排列
+	 */
+	getSystems(): ReadonlyArray<SystemFunction> {
+		return this.systems;
+	}
+
+	/**
+	 * 链式执行 - 此后添加的系统按添加顺序依次执行
+	 * @returns 当前组，支持链式调用
+	 */
+	chain(): this {
+		this.chained = true;
+		return this;
+	}
+
+	/**
+	 * 添加系统到组中
+	 * 受 App.dedupSystems 策略影响：已注册到同一调度的系统不会被重复添加，
+	 * 被跳过的系统不会记录到组中，链式组中后续的系统也不会排在它之后
+	 * @param systems - 系统函数
+	 * @returns 当前组，支持链式调用
+	 */
+	add(...systems: SystemFunction[]): this {
+		for (const system of systems) {
+			let config = new SystemConfigs(system).inSet(this.label);
+			if (this.chained && this.lastSystem !== undefined) {
+				config = config.after(this.lastSystem);
+			}
+
+			const [systemConfig] = config.toSystemConfigs();
+			if (this.subApp.addSystemConfig(this.schedule, systemConfig) === undefined) {
+				continue;
+			}
+			this.systems.push(system);
+			this.lastSystem = system;
+		}
+		return this;
+	}
+
+	/**
+	 * 整个组在另一个组（或系统集）之前运行
+	 * @param other - 目标组标签
+	 * @returns 当前组，支持链式调用
+	 */
+	before(other: SystemSet): this {
+		this.setConfig.before.push(other);
+		return this;
+	}
+
+	/**
+	 * 整个组在另一个组（或系统集）之后运行
+	 * @param other - 目标组标签
+	 * @returns 当前组，支持链式调用
+	 */
+	after(other: SystemSet): this {
+		this.setConfig.after.push(other);
+		return this;
+	}
+}
+
+/**
+ * 强制系统在主线程上执行
+ * 对应 Rust 中使用 NonSend 参数把系统固定在主线程的做法。
+ * Matter Loop 在主线程上串行执行所有系统，不会把系统分发到 Actor 中并行执行，
+ * 因此这里不改变调度，只在系统配置上设置 singleThread 标记，标明系统依赖主线程（例如访问 DataModel 的实例），
+ * 注册后可以通过 Schedule.getSystemsInRegistrationOrder 读取，供将来引入的并行执行器使用
+ * @param system - 系统或系统配置
+ * @returns 设置了 singleThread 标记的系统配置
+ *
+ * @example
+ * app.addSystems(Update, forceSingleThread(syncCameraSystem));
+ */
+export function forceSingleThread(system: IntoSystemConfigs): SystemConfigs {
+	return intoSystemConfigs(system).singleThread();
+}
@@ -0,0 +1,125 @@
+/**
+ * 系统注册表
+ * 记录每个调度中注册的系统，供调试和工具在运行时查询
+ */
+
+import type { Resource } from "../bevy_ecs/resource";
+import type { ScheduleLabel, SystemConfig, SystemFunction, SystemSet } from "../bevy_ecs/schedule/types";
+import { getSystemDisplayName } from "./system-dedup";
+
+/**
+ * 系统信息
+ */
+export interface SystemInfo {
+	/** 系统名称 */
+	readonly name: string;
+	/** 调度内的系统标识符 */
+	readonly id: string;
+	/** 系统函数，作为系统的类型标识 */
+	readonly system: SystemFunction;
+	/** 所属调度 */
+	readonly schedule: ScheduleLabel;
+	/** 所属系统集 */
+	readonly sets: ReadonlyArray<SystemSet>;
+}
+
+/**
+ * 系统注册表资源
+ * 由 SubApp 在添加系统时填充，可在系统中通过 world.resources.getResource<SystemRegistry>() 访问
+ *
+ * 与去重策略保持一致：同一系统在同一调度中只记录一次
+ */
+export class SystemRegistry implements Resource {
+	readonly __brand = "Resource" as const;
+	private readonly systemsBySchedule = new Map<ScheduleLabel, SystemInfo[]>();
+
+	/**
+	 * 获取指定调度中的所有系统
+	 * @param schedule - 调度标签
+	 * @returns 系统信息列表，按注册顺序排列
+	 */
+	systemsIn(schedule: ScheduleLabel): SystemInfo[] {
+		const systems = this.systemsBySchedule.get(schedule);
+		return systems ? [...systems] : [];
+	}
+
+	/**
+	 * 获取所有包含系统的调度
+	 * @returns 调度标签列表
+	 */
+	schedules(): ScheduleLabel[] {
+		const labels: ScheduleLabel[] = [];
+		for (const [label] of this.systemsBySchedule) {
+			labels.push(label);
+		}
+		return labels;
+	}
+
+	/**
+	 * 查找系统信息
+	 * @param schedule - 调度标签
+	 * @param system - 系统函数
+	 * @returns 系统信息，未注册时返回 undefined
+	 */
+	find(schedule: ScheduleLabel, system: SystemFunction): SystemInfo | undefined {
+		return this.systemsBySchedule.get(schedule)?.find((info) => info.system === system);
+	}
+
+	/**
+	 * 通过标识符查找系统信息
+	 * @param id - 调度返回的系统标识符
+	 * @returns 系统信息，未注册时返回 undefined
+	 */
+	findById(id: string): SystemInfo | undefined {
+		for (const [, systems] of this.systemsBySchedule) {
+			const info = systems.find((candidate) => candidate.id === id);
+			if (info !== undefined) {
+				return info;
+			}
+		}
+		return undefined;
+	}
+
+	/**
+	 * 更新被替换系统的记录
+	 * 保留原有的名称、标识符和系统集
+	 * @param id - 系统标识符
+	 * @param system - 新的系统函数
+	 */
+	replace(id: string, system: SystemFunction): void {
+		for (const [, systems] of this.systemsBySchedule) {
+			const index = systems.findIndex((info) => info.id === id);
+			if (index !== -1) {
+				systems[index] = { ...systems[index], system };
+				return;
+			}
+		}
+	}
+
+	/**
+	 * 记录系统注册
+	 * 已记录的系统会被忽略
+	 * @param schedule - 调度标签
+	 * @param config - 系统配置
+	 * @param id - 调度返回的系统标识符
+	 */
+	record(schedule: ScheduleLabel, config: SystemConfig, id: string): void {
+		if (this.find(schedule, config.system) !== undefined) {
+			return;
+		}
+
+		let systems = this.systemsBySchedule.get(schedule);
+		if (!systems) {
+			systems = [];
+			this.systemsBySchedule.set(schedule, systems);
+		}
+
+		systems.push({
+			name: getSystemDisplayName(config),
+			id,
+			system: config.system,
+			schedule,
+			sets: config.inSet !== undefined ? [config.inSet] : [],
+		});
+	}
+}
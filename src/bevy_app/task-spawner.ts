@@ -0,0 +1,196 @@
+/**
+ * 异步任务
+ * 在系统中启动异步工作（I/O、网络请求等），完成后结果以 TaskCompleted 消息的形式回到 ECS
+ */
+
+import { Modding } from "@flamework/core";
+import type { World } from "../bevy_ecs/bevy-world";
+import type { Message, MessageRegistry, MessageWriter } from "../bevy_ecs/message";
+import type { Resource } from "../bevy_ecs/resource";
+
+/**
+ * 任务完成消息
+ * @template T - 任务结果类型
+ */
+export interface TaskCompleted<T> extends Message {
+	/** 任务句柄 ID，与 TaskHandle.id 对应 */
+	readonly handleId: number;
+	/** 任务是否成功完成 */
+	readonly success: boolean;
+	/** 任务结果，仅在成功时存在 */
+	readonly value?: T;
+	/** 任务失败的原因，仅在失败时存在 */
+	readonly err?: unknown;
+}
+
+/**
+ * 任务句柄
+ * @template T - 任务结果类型
+ */
+export class TaskHandle<T> {
+	private cancelled = false;
+	private finished = false;
+
+	/**
+	 * 创建任务句柄
+	 * @param id - 任务句柄 ID
+	 * @param future - 任务对应的 Promise
+	 */
+	constructor(
+		public readonly id: number,
+		private readonly future: Promise<T>,
+	) {}
+
+	/**
+	 * 取消任务
+	 * 已取消的任务不会发送完成消息；任务已完成时调用无效
+	 */
+	cancel(): void {
+		if (this.finished || this.cancelled) {
+			return;
+		}
+
+		this.cancelled = true;
+		this.future.cancel();
+	}
+
+	/**
+	 * 检查任务是否已被取消
+	 */
+	isCancelled(): boolean {
+		return this.cancelled;
+	}
+
+	/**
+	 * 检查任务的完成消息是否已发送
+	 */
+	isFinished(): boolean {
+		return this.finished;
+	}
+
+	/**
+	 * 标记任务完成消息已发送
+	 * @internal
+	 */
+	markFinished(): void {
+		this.finished = true;
+	}
+
+	/**
+	 * 获取任务对应的 Promise
+	 * @internal
+	 */
+	getFuture(): Promise<T> {
+		return this.future;
+	}
+}
+
+/**
+ * 等待轮询的任务
+ */
+interface PendingTask {
+	readonly handle: TaskHandle<unknown>;
+	readonly writer: MessageWriter<TaskCompleted<unknown>>;
+}
+
+/**
+ * 任务生成器资源
+ * 由 App 自动插入；任务完成后由 pollTasksSystem 在 PreUpdate 中写入 TaskCompleted<T> 消息
+ *
+ * @example
+ * ```typescript
+ * const handle = world.resources.getResource<TaskSpawner>()!.spawn<string>(fetchProfile(userId));
+ *
+ * // 之后的帧中
+ * for (const completed of reader.read()) {
+ *     if (completed.handleId === handle.id && completed.success) {
+ *         print(completed.value);
+ *     }
+ * }
+ * ```
+ */
+export class TaskSpawner implements Resource {
+	readonly __brand = "Resource" as const;
+	private nextHandleId = 1;
+	private readonly pending: PendingTask[] = [];
+
+	/**
+	 * 创建任务生成器
+	 * @param messages - 完成消息写入的消息注册表
+	 */
+	constructor(private readonly messages: MessageRegistry) {}
+
+	/**
+	 * 启动异步任务
+	 *
+	 * **注意**: @claudecode , @cursor ,  这是一个宏文件, 所有 Modding.* 的类型, 不要主动调用.
+	 * @metadata macro
+	 * @template T - 任务结果类型
+	 * @param future - 任务对应的 Promise
+	 * @param id - TaskCompleted<T> 类型标识符（由宏自动提供）
+	 * @param text - TaskCompleted<T> 类型文本描述（由宏自动提供）
+	 * @returns 任务句柄
+	 */
+	spawn<T>(
+		future: Promise<T>,
+		id?: Modding.Generic<TaskCompleted<T>, "id">,
+		text?: Modding.Generic<TaskCompleted<T>, "text">,
+	): TaskHandle<T> {
+		const handle = new TaskHandle<T>(this.nextHandleId++, future);
+		const writer = this.messages.createWriter<TaskCompleted<T>>(id, text);
+		this.pending.push({
+			handle: handle as TaskHandle<unknown>,
+			writer: writer as unknown as MessageWriter<TaskCompleted<unknown>>,
+		});
+		return handle;
+	}
+
+	/**
+	 * 获取尚未完成的任务数量
+	 */
+	pendingCount(): number {
+		return this.pending.size();
+	}
+
+	/**
+	 * 轮询任务，为已完成的任务写入完成消息
+	 * 已取消的任务直接丢弃
+	 */
+	poll(): void {
+		let index = 0;
+		while (index < this.pending.size()) {
+			const task = this.pending[index];
+			const handle = task.handle;
+			const future = handle.getFuture();
+			const status = future.getStatus();
+
+			if (handle.isCancelled() || status === Promise.Status.Cancelled) {
+				this.pending.remove(index);
+				continue;
+			}
+
+			if (status === Promise.Status.Started) {
+				index++;
+				continue;
+			}
+
+			// 已结束的 Promise 调用 await 会立即返回
+			const [success, result] = future.await();
+			if (success) {
+				task.writer.write({ handleId: handle.id, success: true, value: result });
+			} else {
+				task.writer.write({ handleId: handle.id, success: false, err: result });
+			}
+			handle.markFinished();
+			this.pending.remove(index);
+		}
+	}
+}
+
+/**
+ * 轮询异步任务的框架系统
+ * @param world - 游戏世界
+ */
+export function pollTasksSystem(world: World): void {
+	world.resources.getResource<TaskSpawner>()?.poll();
+}
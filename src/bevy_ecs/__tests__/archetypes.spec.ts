@@ -0,0 +1,100 @@
+/**
+ * Archetypes 单元测试
+ * 测试命名原型的注册、生成与组件覆盖
+ */
+
+import { component } from "@rbxts/matter";
+import { App } from "../../bevy_app/app";
+import { World } from "../bevy-world";
+import { Archetypes } from "../archetypes";
+
+const Health = component<{ value: number }>("ArchetypeHealth");
+const Position = component<{ x: number; y: number }>("ArchetypePosition");
+const Velocity = component<{ x: number; y: number }>("ArchetypeVelocity");
+const Boss = component<{}>("ArchetypeBoss");
+
+/**
+ * 获取实体拥有的全部组件名称
+ * @param world - 游戏世界
+ * @param entity - 实体
+ * @returns 按名称排序的组件名称
+ */
+function componentNamesOf(world: World, entity: number): string[] {
+	const names: string[] = [];
+	for (const [id, entityData] of world) {
+		if (id === entity) {
+			for (const [component] of entityData) {
+				names.push(tostring(component));
+			}
+		}
+	}
+	names.sort((a, b) => a < b);
+	return names;
+}
+
+export = () => {
+	describe("Archetypes", () => {
+		let world: World;
+		let archetypes: Archetypes;
+
+		beforeEach(() => {
+			world = new World();
+			archetypes = new Archetypes();
+			archetypes.register("enemy", (archetype) =>
+				archetype.add(Health, { value: 100 }).add(Position, { x: 0, y: 0 }).add(Velocity, { x: 1, y: 0 }),
+			);
+		});
+
+		it("生成的实体应恰好拥有原型声明的组件及默认值", () => {
+			const entity = archetypes.spawn(world, "enemy")!;
+
+			expect(archetypes.getComponents("enemy")!.size()).to.equal(3);
+			expect(world.get(entity, Health)!.value).to.equal(100);
+			expect(world.get(entity, Position)!.x).to.equal(0);
+			expect(world.get(entity, Velocity)!.x).to.equal(1);
+			expect(componentNamesOf(world, entity).join(",")).to.equal(
+				"ArchetypeHealth,ArchetypePosition,ArchetypeVelocity",
+			);
+		});
+
+		it("同一原型多次生成时每个实体都应拥有独立的默认组件", () => {
+			const first = archetypes.spawn(world, "enemy")!;
+			const second = archetypes.spawn(world, "enemy")!;
+
+			expect(first).never.to.equal(second);
+			expect(world.get(first, Health)!.value).to.equal(100);
+			expect(world.get(second, Health)!.value).to.equal(100);
+			expect(world.get(second, Velocity)!.x).to.equal(1);
+
+			world.insert(first, world.get(first, Health)!.patch({ value: 1 }));
+			expect(world.get(second, Health)!.value).to.equal(100);
+		});
+
+		it("覆盖的组件应替换默认值，额外组件应被一起添加", () => {
+			const entity = archetypes.spawn(world, "enemy", [Health({ value: 1000 }), Boss({})])!;
+
+			expect(world.get(entity, Health)!.value).to.equal(1000);
+			expect(world.get(entity, Position)).to.be.ok();
+			expect(world.get(entity, Boss)).to.be.ok();
+		});
+
+		it("未注册的原型不应生成实体", () => {
+			expect(archetypes.spawn(world, "missing")).to.equal(undefined);
+
+			let count = 0;
+			for (const _ of world.query(Health)) {
+				count++;
+			}
+			expect(count).to.equal(0);
+		});
+
+		it("App.spawnArchetype 应使用 registerArchetype 注册的原型", () => {
+			const app = App.create().registerArchetype("enemy", (archetype) => archetype.add(Health, { value: 50 }));
+
+			const entity = app.spawnArchetype("enemy")!;
+
+			expect(app.getWorld().get(entity, Health)!.value).to.equal(50);
+			expect(app.spawnArchetype("missing")).to.equal(undefined);
+		});
+	});
+};
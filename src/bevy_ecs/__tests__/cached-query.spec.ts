@@ -0,0 +1,106 @@
+/**
+ * CachedQuery 单元测试
+ * 测试查询结果缓存在生成、修改、移除组件和销毁实体后正确失效
+ */
+
+import { component } from "@rbxts/matter";
+import { World } from "../bevy-world";
+import { CachedQuery } from "../cached-query";
+
+const Enemy = component<{ damage: number }>("CachedQueryEnemy");
+const Stunned = component<{}>("CachedQueryStunned");
+const Decoration = component<{}>("CachedQueryDecoration");
+
+/**
+ * 计算未被眩晕的敌人的总伤害
+ * @param world - 游戏世界
+ */
+function totalDamage(world: World): number {
+	let total = 0;
+	for (const [, enemy] of world.queryWith(Enemy).without(Stunned).collect()) {
+		total += enemy.damage;
+	}
+	return total;
+}
+
+export = () => {
+	describe("CachedQuery", () => {
+		let world: World;
+		let cached: CachedQuery<number>;
+
+		beforeEach(() => {
+			world = new World();
+			cached = new CachedQuery([Enemy, Stunned], totalDamage);
+		});
+
+		it("没有变化时应返回缓存的结果", () => {
+			world.spawn(Enemy({ damage: 5 }));
+
+			expect(cached.getOrCompute(world)).to.equal(5);
+			expect(cached.getOrCompute(world)).to.equal(5);
+			expect(cached.getComputeCount()).to.equal(1);
+		});
+
+		it("生成匹配的实体后应重新计算", () => {
+			world.spawn(Enemy({ damage: 5 }));
+			cached.getOrCompute(world);
+
+			world.spawn(Enemy({ damage: 3 }));
+
+			expect(cached.getOrCompute(world)).to.equal(8);
+			expect(cached.getComputeCount()).to.equal(2);
+		});
+
+		it("组件数据修改后应重新计算", () => {
+			const entity = world.spawn(Enemy({ damage: 5 }));
+			cached.getOrCompute(world);
+
+			world.insert(entity, world.get(entity, Enemy)!.patch({ damage: 9 }));
+
+			expect(cached.getOrCompute(world)).to.equal(9);
+		});
+
+		it("过滤组件的添加和移除应使缓存失效", () => {
+			const entity = world.spawn(Enemy({ damage: 5 }));
+			cached.getOrCompute(world);
+
+			world.insert(entity, Stunned({}));
+			expect(cached.getOrCompute(world)).to.equal(0);
+
+			world.remove(entity, Stunned);
+			expect(cached.getOrCompute(world)).to.equal(5);
+		});
+
+		it("销毁匹配的实体后应重新计算", () => {
+			const entity = world.spawn(Enemy({ damage: 5 }));
+			world.spawn(Enemy({ damage: 2 }));
+			cached.getOrCompute(world);
+
+			world.despawn(entity);
+
+			expect(cached.getOrCompute(world)).to.equal(2);
+		});
+
+		it("无关组件的变化不应使缓存失效", () => {
+			world.spawn(Enemy({ damage: 5 }));
+			cached.getOrCompute(world);
+
+			world.spawn(Decoration({}));
+
+			expect(cached.getOrCompute(world)).to.equal(5);
+			expect(cached.getComputeCount()).to.equal(1);
+		});
+
+		it("invalidate 和更换 World 都应触发重新计算", () => {
+			world.spawn(Enemy({ damage: 5 }));
+			cached.getOrCompute(world);
+
+			cached.invalidate();
+			cached.getOrCompute(world);
+			expect(cached.getComputeCount()).to.equal(2);
+
+			expect(cached.getOrCompute(new World())).to.equal(0);
+			expect(cached.getComputeCount()).to.equal(3);
+		});
+	});
+};
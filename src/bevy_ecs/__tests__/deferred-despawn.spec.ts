@@ -0,0 +1,101 @@
+/**
+ * @fileoverview 延迟销毁队列测试
+ */
+
+import { component } from "@rbxts/matter";
+import { App } from "../../bevy_app/app";
+import { BuiltinSchedules } from "../../bevy_app/main-schedule";
+import { World } from "../bevy-world";
+import { DeferredDespawn } from "../deferred-despawn";
+
+const Target = component<{ hp: number }>("DeferredDespawnTarget");
+
+export = () => {
+	describe("DeferredDespawn", () => {
+		it("同一帧内被两个系统加入队列的实体应只销毁一次", () => {
+			const app = App.create();
+			const world = app.getWorld();
+			const entity = world.spawn(Target({ hp: 0 }));
+
+			let seenInLast = false;
+			const queueSystem = (world: World) => {
+				for (const [id] of world.query(Target)) {
+					world.resources.getResource<DeferredDespawn>()!.queue(id);
+				}
+			};
+			app.addSystems(BuiltinSchedules.UPDATE, queueSystem);
+			app.addSystems(BuiltinSchedules.POST_UPDATE, queueSystem);
+			app.addSystems(BuiltinSchedules.LAST, (world: World) => {
+				seenInLast = world.contains(entity);
+			});
+
+			expect(() => app.update()).never.to.throw();
+
+			// 销毁发生在 Last 之后
+			expect(seenInLast).to.equal(true);
+			expect(world.contains(entity)).to.equal(false);
+			expect(app.getResource<DeferredDespawn>()!.size()).to.equal(0);
+		});
+
+		it("通过 Loop 运行器执行时也应在 Last 末尾销毁队列中的实体", () => {
+			const app = App.create();
+			const world = app.getWorld();
+			const entity = world.spawn(Target({ hp: 0 }));
+
+			let seenInLast = false;
+			app.addSystems(BuiltinSchedules.UPDATE, (world: World) => {
+				world.resources.getResource<DeferredDespawn>()!.queue(entity);
+			});
+			app.addSystems(BuiltinSchedules.LAST, (world: World) => {
+				seenInLast = world.contains(entity);
+			});
+
+			// 与 RobloxRunnerPlugin 相同的调度映射，由 BindableEvent 代替 Heartbeat 驱动
+			const frame = new Instance("BindableEvent");
+			const events: { [scheduleLabel: string]: RBXScriptSignal } = {};
+			for (const label of [
+				"default",
+				BuiltinSchedules.PRE_STARTUP,
+				BuiltinSchedules.STARTUP,
+				BuiltinSchedules.POST_STARTUP,
+				BuiltinSchedules.FIRST,
+				BuiltinSchedules.PRE_UPDATE,
+				BuiltinSchedules.UPDATE,
+				BuiltinSchedules.POST_UPDATE,
+				BuiltinSchedules.LAST,
+				BuiltinSchedules.MAIN,
+			]) {
+				events[label] = frame.Event;
+			}
+
+			const buildError = app.tryBuild();
+			expect(buildError).to.equal(undefined);
+			app.main().startLoop(events);
+			frame.Fire();
+			task.wait();
+			app.main().stopLoop();
+			frame.Destroy();
+
+			expect(seenInLast).to.equal(true);
+			expect(world.contains(entity)).to.equal(false);
+			expect(app.getResource<DeferredDespawn>()!.size()).to.equal(0);
+		});
+
+		it("已被销毁的实体应被静默忽略", () => {
+			const world = new World();
+			const despawns = new DeferredDespawn();
+			const first = world.spawn(Target({ hp: 0 }));
+			const second = world.spawn(Target({ hp: 0 }));
+
+			despawns.queue(first);
+			despawns.queue(second);
+			despawns.queue(first);
+			world.despawn(second);
+
+			expect(despawns.size()).to.equal(2);
+			expect(despawns.flush(world)).to.equal(1);
+			expect(world.contains(first)).to.equal(false);
+			expect(despawns.isQueued(first)).to.equal(false);
+		});
+	});
+};
@@ -0,0 +1,74 @@
+/**
+ * EntityNames 单元测试
+ * 测试名称注册、覆盖以及销毁实体后的自动清理
+ */
+
+import { component } from "@rbxts/matter";
+import { App } from "../../bevy_app/app";
+import { World } from "../bevy-world";
+import { EntityNames } from "../entity-names";
+
+const SpawnPoint = component<{ team: number }>("NamedSpawnPoint");
+
+export = () => {
+	describe("EntityNames", () => {
+		let world: World;
+		let names: EntityNames;
+
+		beforeEach(() => {
+			world = new World();
+			names = new EntityNames();
+		});
+
+		it("应能按名称插入和查找实体", () => {
+			const entity = world.spawn(SpawnPoint({ team: 1 }));
+
+			expect(names.insert("spawn_red", entity)).to.equal(undefined);
+			expect(names.get("spawn_red")).to.equal(entity);
+			expect(names.nameOf(entity)).to.equal("spawn_red");
+			expect(names.get("missing")).to.equal(undefined);
+		});
+
+		it("同名插入应覆盖旧实体并返回旧实体", () => {
+			const first = world.spawn(SpawnPoint({ team: 1 }));
+			const second = world.spawn(SpawnPoint({ team: 2 }));
+
+			names.insert("camera", first);
+			expect(names.insert("camera", second)).to.equal(first);
+
+			expect(names.get("camera")).to.equal(second);
+			expect(names.nameOf(first)).to.equal(undefined);
+			expect(names.size()).to.equal(1);
+		});
+
+		it("重命名实体应移除旧名称", () => {
+			const entity = world.spawn(SpawnPoint({ team: 1 }));
+
+			names.insert("old", entity);
+			names.insert("new", entity);
+
+			expect(names.get("old")).to.equal(undefined);
+			expect(names.get("new")).to.equal(entity);
+		});
+
+		it("spawnNamed 生成的实体销毁后名称应被自动清理", () => {
+			const app = App.create();
+			const bevyWorld = app.getWorld();
+
+			bevyWorld.commands.spawnNamed("spawn_blue", [SpawnPoint({ team: 2 })]);
+			app.update();
+
+			const appNames = app.getResource<EntityNames>()!;
+			const entity = appNames.get("spawn_blue");
+			expect(entity).to.be.ok();
+			expect(bevyWorld.get(entity!, SpawnPoint)!.team).to.equal(2);
+
+			bevyWorld.commands.despawn(entity!);
+			app.update();
+			app.update();
+
+			expect(appNames.get("spawn_blue")).to.equal(undefined);
+			expect(appNames.nameOf(entity!)).to.equal(undefined);
+		});
+	});
+};
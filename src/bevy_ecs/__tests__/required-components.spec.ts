@@ -0,0 +1,65 @@
+/**
+ * RequiredComponents 单元测试
+ * 测试必需组件声明与违反检测
+ */
+
+import { component } from "@rbxts/matter";
+import { App } from "../../bevy_app/app";
+import { World } from "../bevy-world";
+import { RequiredComponents, RequirementPolicy } from "../required-components";
+
+const Position = component<{ x: number; y: number }>("RequiredPosition");
+const Velocity = component<{ x: number; y: number }>("RequiredVelocity");
+const Mass = component<{ value: number }>("RequiredMass");
+
+export = () => {
+	describe("RequiredComponents", () => {
+		let world: World;
+		let registry: RequiredComponents;
+
+		beforeEach(() => {
+			world = new World();
+			registry = new RequiredComponents();
+			registry.require(Velocity).with(Position).with(Mass);
+		});
+
+		it("应检测出缺少必需组件的实体及缺失的组件", () => {
+			world.spawn(Velocity({ x: 1, y: 0 }), Position({ x: 0, y: 0 }), Mass({ value: 1 }));
+			const violating = world.spawn(Velocity({ x: 1, y: 0 }), Mass({ value: 1 }));
+
+			const violations = registry.check(world);
+
+			expect(violations.size()).to.equal(1);
+			expect(violations[0].entity).to.equal(violating);
+			expect(violations[0].component).to.equal(tostring(Velocity));
+			expect(violations[0].missing).to.equal(tostring(Position));
+		});
+
+		it("没有声明依赖的组件不应被检查", () => {
+			world.spawn(Position({ x: 0, y: 0 }));
+
+			expect(registry.check(world).size()).to.equal(0);
+		});
+
+		it("Panic 策略下检查系统应报错", () => {
+			world.spawn(Velocity({ x: 1, y: 0 }));
+			registry.policy = RequirementPolicy.Panic;
+
+			expect(() => registry.validate(world)).to.throw();
+		});
+
+		it("validateRequirements 应在每帧记录违反", () => {
+			const app = App.create();
+			app.requireComponent(Velocity).with(Position);
+			app.validateRequirements();
+
+			const violating = app.getWorld().spawn(Velocity({ x: 1, y: 1 }));
+			app.update();
+
+			const violations = app.getResource<RequiredComponents>()!.getLastViolations();
+			expect(violations.size()).to.equal(1);
+			expect(violations[0].entity).to.equal(violating);
+			expect(violations[0].missing).to.equal(tostring(Position));
+		});
+	});
+};
@@ -0,0 +1,86 @@
+/**
+ * RollbackBuffer 单元测试
+ * 测试按 tick 保存快照、回滚与容量淘汰
+ */
+
+import { component } from "@rbxts/matter";
+import { World } from "../bevy-world";
+import { RollbackBuffer, RollbackErrorKind } from "../rollback";
+import { SnapshotRegistry } from "../snapshot";
+
+const Position = component<{ x: number; y: number }>("RollbackPosition");
+const Stunned = component<{ remaining: number }>("RollbackStunned");
+const LocalOnly = component<{ value: number }>("RollbackLocalOnly");
+
+export = () => {
+	describe("RollbackBuffer", () => {
+		let world: World;
+		let registry: SnapshotRegistry;
+
+		beforeEach(() => {
+			world = new World();
+			registry = new SnapshotRegistry();
+			registry.register(Position);
+			registry.register(Stunned);
+		});
+
+		it("回滚后已注册组件应与 checkpoint 一致", () => {
+			const buffer = new RollbackBuffer(registry, 8);
+			const player = world.spawn(Position({ x: 0, y: 0 }), LocalOnly({ value: 1 }));
+
+			for (let tick = 1; tick <= 5; tick++) {
+				world.insert(player, Position({ x: tick, y: tick * 2 }));
+				buffer.save(world, tick);
+			}
+
+			world.insert(player, Position({ x: 100, y: 100 }), Stunned({ remaining: 3 }), LocalOnly({ value: 2 }));
+			const spawnedLater = world.spawn(Position({ x: 9, y: 9 }));
+
+			expect(buffer.rollbackTo(world, 3)).to.equal(undefined);
+
+			expect(world.get(player, Position)!.x).to.equal(3);
+			expect(world.get(player, Position)!.y).to.equal(6);
+			expect(world.get(player, Stunned)).to.equal(undefined);
+			expect(world.get(spawnedLater, Position)).to.equal(undefined);
+			// 未注册的组件不参与回滚
+			expect(world.get(player, LocalOnly)!.value).to.equal(2);
+		});
+
+		it("回滚后应丢弃目标 tick 之后的快照", () => {
+			const buffer = new RollbackBuffer(registry, 8);
+			world.spawn(Position({ x: 0, y: 0 }));
+			for (let tick = 1; tick <= 4; tick++) {
+				buffer.save(world, tick);
+			}
+
+			buffer.rollbackTo(world, 2);
+
+			expect(buffer.size()).to.equal(2);
+			expect(buffer.has(2)).to.equal(true);
+			expect(buffer.has(3)).to.equal(false);
+		});
+
+		it("超出容量时应淘汰最早的快照，回滚到被淘汰的 tick 应返回错误", () => {
+			const buffer = new RollbackBuffer(registry, 3);
+			world.spawn(Position({ x: 0, y: 0 }));
+			for (let tick = 1; tick <= 5; tick++) {
+				buffer.save(world, tick);
+			}
+
+			expect(buffer.size()).to.equal(3);
+			expect(buffer.oldestTick()).to.equal(3);
+
+			const err = buffer.rollbackTo(world, 1);
+			expect(err).to.be.ok();
+			expect(err!.kind).to.equal(RollbackErrorKind.Evicted);
+			expect(err!.tick).to.equal(1);
+		});
+
+		it("回滚到从未保存的 tick 应返回错误", () => {
+			const buffer = new RollbackBuffer(registry, 3);
+			buffer.save(world, 1);
+
+			expect(buffer.rollbackTo(world, 7)!.kind).to.equal(RollbackErrorKind.NotSaved);
+		});
+	});
+};
@@ -0,0 +1,83 @@
+/**
+ * @fileoverview 运行条件组合测试
+ */
+
+import { App } from "../../bevy_app/app";
+import { BuiltinSchedules } from "../../bevy_app/main-schedule";
+import { runIfAll, runIfAny, runIfNot, system } from "../schedule/index";
+import type { RunCondition } from "../schedule/types";
+
+export = () => {
+	describe("运行条件组合", () => {
+		it("runIfAll 应只在所有条件都为 true 时运行系统", () => {
+			const app = App.create();
+			let first = false;
+			let second = false;
+			let runs = 0;
+
+			app.addSystems(
+				BuiltinSchedules.UPDATE,
+				system(() => {
+					runs++;
+				}).runIf(runIfAll([() => first, () => second])),
+			);
+
+			app.update();
+			first = true;
+			app.update();
+			first = false;
+			second = true;
+			app.update();
+			expect(runs).to.equal(0);
+
+			first = true;
+			app.update();
+			expect(runs).to.equal(1);
+		});
+
+		it("runIfAll 应在第一个 false 处短路", () => {
+			let evaluated = 0;
+			const counting: RunCondition = () => {
+				evaluated++;
+				return true;
+			};
+			const condition = runIfAll([counting, () => false, counting]);
+
+			expect(condition(App.create().getWorld())).to.equal(false);
+			expect(evaluated).to.equal(1);
+		});
+
+		it("runIfAny 应在第一个 true 处短路", () => {
+			let evaluated = 0;
+			const counting: RunCondition = () => {
+				evaluated++;
+				return false;
+			};
+			const condition = runIfAny([counting, () => true, counting]);
+
+			expect(condition(App.create().getWorld())).to.equal(true);
+			expect(evaluated).to.equal(1);
+			expect(runIfAny([])(App.create().getWorld())).to.equal(false);
+		});
+
+		it("runIfNot 应反转条件结果", () => {
+			const app = App.create();
+			let paused = true;
+			let runs = 0;
+
+			app.addSystems(
+				BuiltinSchedules.UPDATE,
+				system(() => {
+					runs++;
+				}).runIf(runIfNot(() => paused)),
+			);
+
+			app.update();
+			expect(runs).to.equal(0);
+
+			paused = false;
+			app.update();
+			expect(runs).to.equal(1);
+		});
+	});
+};
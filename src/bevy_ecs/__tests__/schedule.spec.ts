@@ -250,6 +250,29 @@ export = () => {
 				expect(graph.systems.size()).to.equal(1);
 				expect(graph.dependencies.size()).to.equal(1);
 			});
+
+			it("应该按注册顺序返回系统并解析系统与系统集的排序约束", () => {
+				const systemA = () => {};
+				const systemB = () => {};
+				const systemC = () => {};
+				const firstSet = "first_set";
+				const secondSet = "second_set";
+
+				schedule.configureSet({ name: secondSet, after: [firstSet] });
+				const idB = schedule.addSystem({ system: systemB, name: "system_b", after: [systemA], inSet: secondSet });
+				const idA = schedule.addSystem({ system: systemA, name: "system_a", inSet: firstSet });
+				const idC = schedule.addSystem({ system: systemC, name: "system_c", before: [systemB] });
+
+				const order = schedule.getSystemsInRegistrationOrder().map((system) => system.id);
+				expect(order.join(",")).to.equal([idB, idA, idC].join(","));
+
+				const edges = schedule
+					.getOrderingEdges()
+					.map((edge) => `${edge.from}>${edge.to}:${edge.kind}:${edge.viaSet}`);
+				expect(edges.join(",")).to.equal(
+					[`${idA}>${idB}:after:false`, `${idC}>${idB}:before:false`, `${idA}>${idB}:after:true`].join(","),
+				);
+			});
 		});
 
 		describe("错误处理", () => {
@@ -0,0 +1,55 @@
+/**
+ * Singleton 单元测试
+ * 测试单例实体的生成、重复拒绝与查找
+ */
+
+import { AnyEntity, component } from "@rbxts/matter";
+import { App } from "../../bevy_app/app";
+import { World } from "../bevy-world";
+import { singleEntity, SingletonError, SingletonErrorKind, spawnSingleton } from "../singleton";
+
+const LocalPlayer = component<{}>("SingletonLocalPlayer");
+const Health = component<{ value: number }>("SingletonHealth");
+
+export = () => {
+	describe("Singleton", () => {
+		let world: World;
+
+		beforeEach(() => {
+			world = new World();
+		});
+
+		it("应生成带标记组件的单例并可查找", () => {
+			const result = spawnSingleton(world, LocalPlayer, Health({ value: 100 }));
+
+			expect(result instanceof SingletonError).to.equal(false);
+			expect(singleEntity(world, LocalPlayer)).to.equal(result);
+			expect(world.get(result as AnyEntity, LocalPlayer)).to.be.ok();
+			expect(world.get(result as AnyEntity, Health)!.value).to.equal(100);
+		});
+
+		it("重复生成应返回 AlreadyExists 错误且不生成新实体", () => {
+			const first = spawnSingleton(world, LocalPlayer);
+			const second = spawnSingleton(world, LocalPlayer, Health({ value: 1 }));
+
+			expect(second instanceof SingletonError).to.equal(true);
+			expect((second as SingletonError).kind).to.equal(SingletonErrorKind.AlreadyExists);
+			expect((second as SingletonError).existing).to.equal(first);
+
+			let count = 0;
+			for (const _ of world.query(LocalPlayer)) {
+				count++;
+			}
+			expect(count).to.equal(1);
+		});
+
+		it("单例被销毁后查找应返回 undefined，并可重新生成", () => {
+			const app = App.create();
+			const player = app.spawnSingleton(LocalPlayer);
+			app.getWorld().despawn(player as AnyEntity);
+
+			expect(app.singleEntity(LocalPlayer)).to.equal(undefined);
+			expect(app.spawnSingleton(LocalPlayer) instanceof SingletonError).to.equal(false);
+		});
+	});
+};
@@ -0,0 +1,88 @@
+/**
+ * WorldSnapshot 单元测试
+ * 测试组件快照的捕获、恢复与 JSON 往返
+ */
+
+import { AnyEntity, component } from "@rbxts/matter";
+import { World } from "../bevy-world";
+import { SnapshotRegistry, WorldSnapshot } from "../snapshot";
+
+const Health = component<{ current: number; max: number }>("SnapshotHealth");
+const Follows = component<{ target: AnyEntity }>("SnapshotFollows");
+const Unregistered = component<{ value: number }>("SnapshotUnregistered");
+
+export = () => {
+	describe("WorldSnapshot", () => {
+		let world: World;
+		let registry: SnapshotRegistry;
+
+		beforeEach(() => {
+			world = new World();
+			registry = new SnapshotRegistry();
+			registry.register(Health);
+			registry.register(Follows);
+		});
+
+		it("清空后恢复应还原已注册组件及实体引用", () => {
+			const leader = world.spawn(Health({ current: 80, max: 100 }));
+			const follower = world.spawn(Health({ current: 50, max: 60 }), Follows({ target: leader }));
+
+			const snapshot = WorldSnapshot.capture(world, registry);
+			world.clear();
+			expect(world.contains(leader)).to.equal(false);
+
+			WorldSnapshot.restore(world, snapshot);
+
+			expect(world.get(leader, Health)!.current).to.equal(80);
+			expect(world.get(leader, Health)!.max).to.equal(100);
+			expect(world.get(follower, Health)!.current).to.equal(50);
+			expect(world.get(follower, Follows)!.target).to.equal(leader);
+		});
+
+		it("未注册的组件不应出现在快照中", () => {
+			const entity = world.spawn(Health({ current: 1, max: 1 }), Unregistered({ value: 7 }));
+
+			const snapshot = WorldSnapshot.capture(world, registry);
+			world.clear();
+			WorldSnapshot.restore(world, snapshot);
+
+			expect(world.get(entity, Health)).to.be.ok();
+			expect(world.get(entity, Unregistered)).to.equal(undefined);
+		});
+
+		it("JSON 往返应保持相同的数据", () => {
+			const leader = world.spawn(Health({ current: 10, max: 20 }));
+			const follower = world.spawn(Follows({ target: leader }));
+
+			const json = WorldSnapshot.capture(world, registry).toJson();
+			world.clear();
+			WorldSnapshot.restore(world, WorldSnapshot.fromJson(json, registry));
+
+			expect(world.get(leader, Health)!.max).to.equal(20);
+			expect(world.get(follower, Follows)!.target).to.equal(leader);
+			expect(WorldSnapshot.capture(world, registry).toJson()).to.equal(json);
+		});
+
+		it("不清空 World 直接恢复时应移除快照外的实体和组件", () => {
+			const kept = world.spawn(Health({ current: 30, max: 40 }));
+			const snapshot = WorldSnapshot.capture(world, registry);
+
+			world.insert(kept, Health({ current: 1, max: 40 }), Follows({ target: kept }), Unregistered({ value: 3 }));
+			const spawnedLater = world.spawn(Health({ current: 5, max: 5 }));
+			const unregisteredOnly = world.spawn(Unregistered({ value: 9 }));
+
+			WorldSnapshot.restore(world, snapshot);
+
+			expect(world.get(kept, Health)!.current).to.equal(30);
+			expect(world.get(kept, Follows)).to.equal(undefined);
+			expect(world.get(kept, Unregistered)!.value).to.equal(3);
+			expect(world.contains(spawnedLater)).to.equal(false);
+			expect(world.contains(unregisteredOnly)).to.equal(false);
+			expect(WorldSnapshot.capture(world, registry).toJson()).to.equal(snapshot.toJson());
+		});
+
+		it("重复注册不同组件到同一名称应报错", () => {
+			expect(() => registry.register(Unregistered, "SnapshotHealth")).to.throw();
+		});
+	});
+};
@@ -0,0 +1,90 @@
+/**
+ * @fileoverview 实体树命令测试
+ * 测试 spawnTree 的嵌套生成与 despawnTree 的递归销毁
+ */
+
+import { AnyEntity, component } from "@rbxts/matter";
+import { World } from "../bevy-world";
+import { CommandBuffer } from "../command-buffer";
+import { HierarchyUtils, Parent } from "../hierarchy";
+
+const Node = component<{ label: string }>("SpawnTreeNode");
+
+/**
+ * 按标签查找实体
+ * @param world - World 实例
+ * @param label - 节点标签
+ */
+function findByLabel(world: World, label: string): AnyEntity | undefined {
+	for (const [entity, node] of world.query(Node)) {
+		if (node.label === label) {
+			return entity;
+		}
+	}
+	return undefined;
+}
+
+export = () => {
+	describe("spawnTree / despawnTree", () => {
+		let world: World;
+		let commands: CommandBuffer;
+
+		beforeEach(() => {
+			world = new World();
+			commands = new CommandBuffer();
+		});
+
+		it("应生成三层实体树并在销毁根节点时移除所有后代", () => {
+			const unrelated = world.spawn(Node({ label: "unrelated" }));
+
+			commands.spawnTree([Node({ label: "root" })], (root) => {
+				root.spawn([Node({ label: "a" })], (a) => {
+					a.spawn([Node({ label: "a1" })]);
+					a.spawn([Node({ label: "a2" })]);
+				});
+				root.spawn([Node({ label: "b" })]);
+			});
+			commands.flush(world);
+
+			const root = findByLabel(world, "root")!;
+			const a = findByLabel(world, "a")!;
+			const a1 = findByLabel(world, "a1")!;
+			expect(HierarchyUtils.getParent(world, a)).to.equal(root);
+			expect(HierarchyUtils.getParent(world, a1)).to.equal(a);
+			expect(HierarchyUtils.getChildren(world, root).size()).to.equal(2);
+			expect(HierarchyUtils.getDescendants(world, root).size()).to.equal(4);
+
+			commands.despawnTree(root);
+			commands.flush(world);
+
+			for (const label of ["root", "a", "a1", "a2", "b"]) {
+				expect(findByLabel(world, label)).to.equal(undefined);
+			}
+			expect(world.contains(unrelated)).to.equal(true);
+		});
+
+		it("Parent 链成环时应安全结束", () => {
+			const first = world.spawn(Node({ label: "first" }));
+			const second = world.spawn(Node({ label: "second" }), Parent({ entity: first }));
+			// 绕过 setParent 的环检测，直接写入成环的 Parent
+			world.insert(first, Parent({ entity: second }));
+
+			expect(HierarchyUtils.despawnTree(world, first)).to.equal(2);
+			expect(world.contains(first)).to.equal(false);
+			expect(world.contains(second)).to.equal(false);
+		});
+
+		it("子实体已被销毁时应忽略并更新父级的 Children", () => {
+			const parent = world.spawn(Node({ label: "parent" }));
+			const root = world.spawn(Node({ label: "root" }));
+			const child = world.spawn(Node({ label: "child" }));
+			HierarchyUtils.setParent(world, root, parent);
+			HierarchyUtils.setParent(world, child, root);
+			world.despawn(child);
+
+			expect(HierarchyUtils.despawnTree(world, root)).to.equal(1);
+			expect(world.contains(parent)).to.equal(true);
+			expect(HierarchyUtils.getChildren(world, parent).size()).to.equal(0);
+		});
+	});
+};
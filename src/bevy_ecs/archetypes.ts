@@ -0,0 +1,140 @@
+/**
+ * @fileoverview 命名原型
+ * 为经常一起生成的组件组合注册一个名称，之后按名称生成实体，并可覆盖个别组件的默认值
+ *
+ * @example
+ * ```typescript
+ * app.registerArchetype("enemy", (archetype) =>
+ *     archetype.add(Health, { value: 100 }).add(Position, { x: 0, y: 0 }).add(Velocity, { x: 0, y: 0 }),
+ * );
+ * app.spawnArchetype("enemy", [Position({ x: 10, y: 5 })]);
+ * ```
+ */
+
+import type { AnyComponent, AnyEntity, Component } from "@rbxts/matter";
+import type { World } from "./bevy-world";
+import type { ComponentCtor } from "./query";
+import type { Resource } from "./resource";
+
+/**
+ * 原型中的单个组件
+ */
+export interface ArchetypeEntry {
+	/** 组件构造函数 */
+	readonly component: ComponentCtor;
+	/** 使用默认值创建组件实例 */
+	readonly create: () => AnyComponent;
+}
+
+/**
+ * 原型声明构建器
+ */
+export class ArchetypeBuilder {
+	private readonly entries: ArchetypeEntry[] = [];
+
+	/**
+	 * 向原型添加组件
+	 * 同一组件重复添加时以最后一次的默认值为准
+	 * @param component - 组件构造函数
+	 * @param defaults - 组件默认值，省略时使用组件自身的默认数据
+	 * @returns 当前构建器，支持链式添加
+	 */
+	add<T extends object>(component: (data?: T) => Component<T>, defaults?: T): this {
+		const ctor = component as unknown as ComponentCtor;
+		// Matter 会冻结传入的数据表，每次生成都需要新的副本
+		const entry: ArchetypeEntry = {
+			component: ctor,
+			create: () => component(defaults !== undefined ? table.clone(defaults) : undefined) as AnyComponent,
+		};
+
+		const index = this.entries.findIndex((existing) => existing.component === ctor);
+		if (index !== -1) {
+			this.entries[index] = entry;
+		} else {
+			this.entries.push(entry);
+		}
+		return this;
+	}
+
+	/**
+	 * 获取已添加的组件
+	 * @returns 组件列表，按添加顺序排列
+	 */
+	getEntries(): ReadonlyArray<ArchetypeEntry> {
+		return this.entries;
+	}
+}
+
+/**
+ * 原型注册表资源
+ * 由 App.registerArchetype 在首次调用时插入
+ */
+export class Archetypes implements Resource {
+	readonly __brand = "Resource" as const;
+	private readonly archetypes = new Map<string, ReadonlyArray<ArchetypeEntry>>();
+
+	/**
+	 * 注册原型
+	 * 名称已存在时覆盖旧的声明
+	 * @param name - 原型名称
+	 * @param build - 声明回调，通过 builder.add 添加组件
+	 */
+	register(name: string, build: (builder: ArchetypeBuilder) => void): void {
+		const builder = new ArchetypeBuilder();
+		build(builder);
+		this.archetypes.set(name, builder.getEntries());
+	}
+
+	/**
+	 * 检查原型是否已注册
+	 * @param name - 原型名称
+	 */
+	has(name: string): boolean {
+		return this.archetypes.has(name);
+	}
+
+	/**
+	 * 获取原型声明的组件
+	 * @param name - 原型名称
+	 * @returns 组件构造函数列表，原型未注册时返回 undefined
+	 */
+	getComponents(name: string): ReadonlyArray<ComponentCtor> | undefined {
+		return this.archetypes.get(name)?.map((entry) => entry.component);
+	}
+
+	/**
+	 * 按原型生成实体
+	 * overrides 中与原型组件类型相同的实例替换默认值，其余实例作为额外组件一起添加。
+	 * 原型未注册时输出警告且不生成实体
+	 * @param world - 游戏世界
+	 * @param name - 原型名称
+	 * @param overrides - 覆盖默认值的组件实例
+	 * @returns 新实体，原型未注册时返回 undefined
+	 */
+	spawn(world: World, name: string, overrides: ReadonlyArray<AnyComponent> = []): AnyEntity | undefined {
+		const entries = this.archetypes.get(name);
+		if (entries === undefined) {
+			warn(`[Archetypes] Archetype "${name}" is not registered, nothing was spawned`);
+			return undefined;
+		}
+
+		const overridesByCtor = new Map<ComponentCtor, AnyComponent>();
+		for (const override of overrides) {
+			overridesByCtor.set(getmetatable(override) as ComponentCtor, override);
+		}
+
+		const components: AnyComponent[] = [];
+		const declared = new Set<ComponentCtor>();
+		for (const entry of entries) {
+			declared.add(entry.component);
+			components.push(overridesByCtor.get(entry.component) ?? entry.create());
+		}
+		for (const override of overrides) {
+			if (!declared.has(getmetatable(override) as ComponentCtor)) {
+				components.push(override);
+			}
+		}
+
+		return world.spawn(...components);
+	}
+}
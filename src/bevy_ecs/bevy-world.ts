@@ -38,6 +38,12 @@ export class World extends MatterWorld {
 	eventPropagator: EventPropagator;
 	/** 变更跟踪器，用于检测组件的添加、修改和移除 */
 	changeTracker: ChangeTracker;
+	/** 结构版本号，每次组件插入、移除或实体销毁时递增 */
+	private structureVersion = 0;
+	/** 每种组件最后一次变化时的结构版本号 */
+	private readonly componentVersions = new Map<ComponentCtor, number>();
+	/** 最后一次无法确定涉及哪些组件的变化（销毁、替换、清空）时的结构版本号 */
+	private wildcardVersion = 0;
 
 	constructor() {
 		super();
@@ -60,6 +66,7 @@ export class World extends MatterWorld {
 
 		// 标记实体为已生成
 		this.changeTracker.markSpawned(entity as number);
+		this.bumpComponentVersions(componentBundle);
 
 		// 标记所有组件为已添加
 		for (const component of componentBundle) {
@@ -81,6 +88,7 @@ export class World extends MatterWorld {
 	 */
 	insert(entity: AnyEntity, ...componentBundle: ComponentBundle): void {
 		super.insert(entity, ...componentBundle);
+		this.bumpComponentVersions(componentBundle);
 
 		// 标记所有组件为已添加
 		for (const component of componentBundle) {
@@ -100,6 +108,78 @@ export class World extends MatterWorld {
 	despawn(entity: AnyEntity): void {
 		super.despawn(entity);
 		this.changeTracker.cleanupEntity(entity as number);
+		this.wildcardVersion = ++this.structureVersion;
+	}
+
+	/**
+	 * 覆盖 spawnAt 方法以更新组件版本号
+	 * @param id - 指定的实体 ID
+	 * @param componentBundle - 要添加到新实体的组件集合
+	 * @returns 新创建的实体
+	 */
+	spawnAt<T extends ComponentBundle>(id: number, ...componentBundle: T): Entity<T> {
+		const entity = super.spawnAt(id, ...componentBundle);
+		this.bumpComponentVersions(componentBundle);
+		return entity;
+	}
+
+	/**
+	 * 覆盖 replace 方法以更新组件版本号
+	 * 被替换掉的组件无法得知，因此视为所有组件都发生了变化
+	 * @param entity - 目标实体
+	 * @param componentBundle - 替换后的组件集合
+	 */
+	replace(entity: AnyEntity, ...componentBundle: ComponentBundle): void {
+		super.replace(entity, ...componentBundle);
+		this.wildcardVersion = ++this.structureVersion;
+	}
+
+	/**
+	 * 覆盖 remove 方法以更新组件版本号
+	 * @param args - 目标实体和要移除的组件构造函数
+	 * @returns 被移除的组件实例
+	 */
+	remove(...args: Parameters<MatterWorld["remove"]>): ReturnType<MatterWorld["remove"]> {
+		const removed = super.remove(...args);
+		const [, ...components] = args;
+		this.structureVersion++;
+		for (const component of components) {
+			this.componentVersions.set(component as unknown as ComponentCtor, this.structureVersion);
+		}
+		return removed;
+	}
+
+	/**
+	 * 覆盖 clear 方法以更新组件版本号
+	 */
+	clear(): void {
+		super.clear();
+		this.wildcardVersion = ++this.structureVersion;
+	}
+
+	/**
+	 * 获取组件的版本号
+	 * 拥有该组件的实体集合或组件数据自上次获取以来发生变化时，版本号一定变大。
+	 * 只跟踪通过 World 方法进行的修改
+	 * @param component - 组件构造函数
+	 * @returns 版本号
+	 */
+	getComponentVersion(component: ComponentCtor): number {
+		return math.max(this.componentVersions.get(component) ?? 0, this.wildcardVersion);
+	}
+
+	/**
+	 * 更新组件集合中每种组件的版本号
+	 * @param componentBundle - 组件集合
+	 */
+	private bumpComponentVersions(componentBundle: ComponentBundle): void {
+		this.structureVersion++;
+		for (const component of componentBundle) {
+			const componentCtor = getmetatable(component) as ComponentCtor;
+			if (componentCtor) {
+				this.componentVersions.set(componentCtor, this.structureVersion);
+			}
+		}
 	}
 
 	/**
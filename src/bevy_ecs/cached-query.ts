@@ -0,0 +1,91 @@
+/**
+ * @fileoverview 查询结果缓存
+ * 为每帧重复执行、但结果很少变化的昂贵只读查询缓存上一次的结果，只在相关组件变化后重新计算
+ *
+ * 缓存依据 World.getComponentVersion：声明的依赖组件中任意一种被插入、修改或移除，
+ * 或者有实体被销毁时，缓存即失效。因此依赖列表必须包含计算中读取和过滤的所有组件，
+ * 遗漏的组件发生变化时不会触发重新计算。
+ *
+ * @example
+ * ```typescript
+ * const hostiles = new CachedQuery([Enemy, Health, Stunned], (world) =>
+ *     world.queryWith(Enemy, Health).without(Stunned).collect(),
+ * );
+ * // 在系统中
+ * for (const [entity, enemy, health] of hostiles.getOrCompute(world)) { ... }
+ * ```
+ */
+
+import type { World } from "./bevy-world";
+import type { ComponentCtor } from "./query";
+
+/**
+ * 查询结果缓存
+ * @template R - 计算结果类型
+ */
+export class CachedQuery<R> {
+	private cachedWorld?: World;
+	private cachedVersion = -1;
+	private cachedResult?: R;
+	private computeCount = 0;
+
+	/**
+	 * 创建查询结果缓存
+	 * @param dependencies - 计算中读取和过滤的所有组件
+	 * @param compute - 计算查询结果，不应修改 World
+	 */
+	constructor(
+		private readonly dependencies: ReadonlyArray<ComponentCtor>,
+		private readonly compute: (world: World) => R,
+	) {
+		assert(dependencies.size() > 0, "CachedQuery requires at least one dependency component");
+	}
+
+	/**
+	 * 获取查询结果
+	 * 依赖的组件自上次计算以来没有变化时返回缓存的结果，否则重新计算
+	 * @param world - 游戏世界
+	 * @returns 查询结果
+	 */
+	getOrCompute(world: World): R {
+		const version = this.currentVersion(world);
+		if (this.cachedWorld === world && this.cachedVersion === version) {
+			return this.cachedResult as R;
+		}
+
+		this.cachedResult = this.compute(world);
+		this.cachedWorld = world;
+		this.cachedVersion = version;
+		this.computeCount++;
+		return this.cachedResult;
+	}
+
+	/**
+	 * 丢弃缓存的结果，下次 getOrCompute 时重新计算
+	 */
+	invalidate(): void {
+		this.cachedWorld = undefined;
+		this.cachedResult = undefined;
+		this.cachedVersion = -1;
+	}
+
+	/**
+	 * 获取实际计算的次数
+	 * 用于确认缓存是否生效
+	 */
+	getComputeCount(): number {
+		return this.computeCount;
+	}
+
+	/**
+	 * 计算依赖组件当前的版本号
+	 * @param world - 游戏世界
+	 */
+	private currentVersion(world: World): number {
+		let version = 0;
+		for (const component of this.dependencies) {
+			version = math.max(version, world.getComponentVersion(component));
+		}
+		return version;
+	}
+}
@@ -0,0 +1,139 @@
+/**
+ * @fileoverview 系统间通道
+ * 多生产者单消费者的类型化通道，通过 App.addChannel<T>() 创建
+ *
+ * 与消息不同，通道中的值会跨帧保留直到被消费，没有双缓冲语义，也不会因为无人读取而被丢弃。
+ */
+
+import type { Resource } from "./resource";
+
+/**
+ * 通道共享的 FIFO 队列
+ * 使用头部索引出队，避免每次 shift 移动整个数组
+ * @template T - 值类型
+ */
+class ChannelQueue<T> {
+	private items: T[] = [];
+	private head = 0;
+
+	/**
+	 * 入队
+	 * @param value - 值
+	 */
+	push(value: T): void {
+		this.items.push(value);
+	}
+
+	/**
+	 * 出队
+	 * @returns 队首的值，队列为空时返回 undefined
+	 */
+	pop(): T | undefined {
+		if (this.head >= this.items.size()) {
+			return undefined;
+		}
+
+		const value = this.items[this.head];
+		this.head++;
+		if (this.head >= this.items.size()) {
+			this.items = [];
+			this.head = 0;
+		}
+		return value;
+	}
+
+	/**
+	 * 取出所有值
+	 * @returns 按入队顺序排列的值
+	 */
+	drain(): T[] {
+		const result: T[] = [];
+		for (let index = this.head; index < this.items.size(); index++) {
+			result.push(this.items[index]);
+		}
+		this.items = [];
+		this.head = 0;
+		return result;
+	}
+
+	/**
+	 * 获取队列中值的数量
+	 */
+	size(): number {
+		return this.items.size() - this.head;
+	}
+}
+
+/**
+ * 通道发送端资源
+ * @template T - 值类型
+ */
+export class ChannelSender<T> implements Resource {
+	readonly __brand = "Resource" as const;
+
+	/**
+	 * @param queue - 通道共享的队列
+	 */
+	constructor(private readonly queue: ChannelQueue<T>) {}
+
+	/**
+	 * 发送值
+	 * @param value - 值
+	 */
+	send(value: T): void {
+		this.queue.push(value);
+	}
+}
+
+/**
+ * 通道接收端资源
+ * @template T - 值类型
+ */
+export class ChannelReceiver<T> implements Resource {
+	readonly __brand = "Resource" as const;
+
+	/**
+	 * @param queue - 通道共享的队列
+	 */
+	constructor(private readonly queue: ChannelQueue<T>) {}
+
+	/**
+	 * 接收一个值
+	 * @returns 最早发送的值，通道为空时返回 undefined
+	 */
+	tryRecv(): T | undefined {
+		return this.queue.pop();
+	}
+
+	/**
+	 * 取出当前所有值
+	 * @returns 按发送顺序排列的值
+	 */
+	tryIter(): T[] {
+		return this.queue.drain();
+	}
+
+	/**
+	 * 获取尚未消费的值的数量
+	 */
+	len(): number {
+		return this.queue.size();
+	}
+
+	/**
+	 * 检查通道是否为空
+	 */
+	isEmpty(): boolean {
+		return this.queue.size() === 0;
+	}
+}
+
+/**
+ * 创建通道
+ * @template T - 值类型
+ * @returns [发送端, 接收端]
+ */
+export function createChannel<T>(): [ChannelSender<T>, ChannelReceiver<T>] {
+	const queue = new ChannelQueue<T>();
+	return [new ChannelSender(queue), new ChannelReceiver(queue)];
+}
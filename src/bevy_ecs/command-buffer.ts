@@ -6,6 +6,9 @@
 
 import { AnyEntity, World, AnyComponent } from "@rbxts/matter";
 import { TypeDescriptor } from "../bevy_core";
+import type { World as BevyWorld } from "./bevy-world";
+import { EntityNames } from "./entity-names";
+import { HierarchyUtils } from "./hierarchy";
 
 /**
  * 组件构造函数类型
@@ -29,6 +32,7 @@ export type EntityId = AnyEntity;
 export enum CommandType {
 	Spawn = "spawn",
 	Despawn = "despawn",
+	DespawnTree = "despawn_tree",
 	AddComponent = "add_component",
 	RemoveComponent = "remove_component",
 	InsertResource = "insert_resource",
@@ -49,6 +53,10 @@ export interface SpawnCommand extends BaseCommand {
 	readonly type: CommandType.Spawn;
 	readonly components: Component[];
 	readonly entityId?: EntityId;
+	/** 生成后注册到 EntityNames 的名称 */
+	readonly name?: string;
+	/** 父实体，可以是同一批命令中生成的临时ID */
+	readonly parent?: EntityId;
 }
 
 /**
@@ -59,6 +67,14 @@ export interface DespawnCommand extends BaseCommand {
 	readonly entityId: EntityId;
 }
 
+/**
+ * 递归销毁实体及其子孙命令
+ */
+export interface DespawnTreeCommand extends BaseCommand {
+	readonly type: CommandType.DespawnTree;
+	readonly entityId: EntityId;
+}
+
 /**
  * 添加组件命令
  */
@@ -107,6 +123,7 @@ export interface CustomCommand extends Record<string, unknown> {
 export type Command =
 	| SpawnCommand
 	| DespawnCommand
+	| DespawnTreeCommand
 	| AddComponentCommand
 	| RemoveComponentCommand
 	| InsertResourceCommand
@@ -122,6 +139,32 @@ export interface CommandResult {
 	readonly error?: string;
 }
 
+/**
+ * 实体树构建器
+ * 由 CommandBuffer.spawnTree 传入构建回调，用于在当前实体下生成子实体
+ */
+export class TreeBuilder {
+	/**
+	 * 创建实体树构建器
+	 * @param commands 命令缓冲器
+	 * @param parent 当前节点的临时实体ID
+	 */
+	constructor(
+		private readonly commands: CommandBuffer,
+		public readonly parent: EntityId,
+	) {}
+
+	/**
+	 * 在当前节点下生成子实体
+	 * @param components 子实体的组件数组
+	 * @param build 可选的回调，用于继续在子实体下生成后代
+	 * @returns 子实体的临时ID
+	 */
+	public spawn(components: Component[], build?: (builder: TreeBuilder) => void): EntityId {
+		return this.commands.spawnChild(this.parent, components, build);
+	}
+}
+
 /**
  * 命令缓冲器 - Bevy ECS Commands系统的roblox-ts适配
  *
@@ -169,6 +212,84 @@ export class CommandBuffer {
 		return tempEntityId;
 	}
 
+	/**
+	 * 生成新实体并以名称注册到 EntityNames 资源
+	 * 名称已被使用时覆盖旧的映射
+	 * @param name 实体名称
+	 * @param components 要添加的组件数组
+	 * @returns 临时实体ID，在flush时会被替换为真实ID
+	 */
+	public spawnNamed(name: string, components: Component[]): EntityId {
+		const tempEntityId = this.getNextTempEntityId();
+
+		const command: SpawnCommand = {
+			type: CommandType.Spawn,
+			components,
+			entityId: tempEntityId,
+			name,
+		};
+
+		this.commands.push(command);
+		return tempEntityId;
+	}
+
+	/**
+	 * 生成一棵实体树
+	 * 根实体和回调中生成的子实体通过 Parent/Children 组件建立层次关系
+	 * @param rootComponents 根实体的组件数组
+	 * @param build 构建回调，通过 builder.spawn 生成子实体
+	 * @returns 根实体的临时ID，在flush时会被替换为真实ID
+	 *
+	 * @example
+	 * ```typescript
+	 * commands.spawnTree([Vehicle({})], (car) => {
+	 *     car.spawn([Wheel({ index: 0 })]);
+	 *     car.spawn([Seat({})], (seat) => seat.spawn([Passenger({})]));
+	 * });
+	 * ```
+	 */
+	public spawnTree(rootComponents: Component[], build: (builder: TreeBuilder) => void): EntityId {
+		const rootId = this.spawn(rootComponents);
+		build(new TreeBuilder(this, rootId));
+		return rootId;
+	}
+
+	/**
+	 * 生成子实体
+	 * @param parent 父实体ID，可以是本缓冲器返回的临时ID
+	 * @param components 要添加的组件数组
+	 * @param build 可选的回调，用于继续在子实体下生成后代
+	 * @returns 临时实体ID，在flush时会被替换为真实ID
+	 */
+	public spawnChild(parent: EntityId, components: Component[], build?: (builder: TreeBuilder) => void): EntityId {
+		const tempEntityId = this.getNextTempEntityId();
+
+		const command: SpawnCommand = {
+			type: CommandType.Spawn,
+			components,
+			entityId: tempEntityId,
+			parent,
+		};
+
+		this.commands.push(command);
+		build?.(new TreeBuilder(this, tempEntityId));
+		return tempEntityId;
+	}
+
+	/**
+	 * 递归销毁实体及其所有子孙
+	 * 层次结构不一致（子实体已不存在、Parent 链成环）时也能安全结束
+	 * @param entityId 根实体ID
+	 */
+	public despawnTree(entityId: EntityId): void {
+		const command: DespawnTreeCommand = {
+			type: CommandType.DespawnTree,
+			entityId,
+		};
+
+		this.commands.push(command);
+	}
+
 	/**
 	 * 销毁指定实体
 	 * @param entityId 要销毁的实体ID
@@ -330,6 +451,14 @@ export class CommandBuffer {
 					this.pendingEntityIds.set(spawnCmd.entityId as number, entityId);
 				}
 
+				if (spawnCmd.parent !== undefined) {
+					HierarchyUtils.setParent(world, entityId, this.resolveEntityId(spawnCmd.parent));
+				}
+
+				if (spawnCmd.name !== undefined) {
+					this.registerEntityName(world, spawnCmd.name, entityId);
+				}
+
 				return {
 					success: true,
 					entityId,
@@ -347,6 +476,17 @@ export class CommandBuffer {
 				};
 			}
 
+			case CommandType.DespawnTree: {
+				const despawnCmd = command as DespawnTreeCommand;
+				const realEntityId = this.resolveEntityId(despawnCmd.entityId);
+				HierarchyUtils.despawnTree(world, realEntityId);
+
+				return {
+					success: true,
+					entityId: realEntityId,
+				};
+			}
+
 			case CommandType.AddComponent: {
 				const addCmd = command as AddComponentCommand;
 				const realEntityId = this.resolveEntityId(addCmd.entityId);
@@ -414,6 +554,27 @@ export class CommandBuffer {
 		return mappedId ?? entityId;
 	}
 
+	/**
+	 * 将实体名称注册到 EntityNames 资源，资源不存在时自动插入
+	 * @param world 命令执行所在的世界
+	 * @param name 实体名称
+	 * @param entityId 真实的实体ID
+	 */
+	private registerEntityName(world: World, name: string, entityId: EntityId): void {
+		const resources = (world as BevyWorld).resources;
+		if (resources === undefined) {
+			warn(`[CommandBuffer] Cannot register entity name "${name}": world has no resource manager`);
+			return;
+		}
+
+		let names = resources.getResource<EntityNames>();
+		if (names === undefined) {
+			names = new EntityNames();
+			resources.insertResource(names);
+		}
+		names.insert(name, entityId);
+	}
+
 	/**
 	 * 获取下一个临时实体ID
 	 * @returns 临时实体ID
@@ -0,0 +1,76 @@
+/**
+ * @fileoverview 延迟销毁队列
+ * 系统在帧内把要销毁的实体放入队列，由框架注册在 Last 调度末尾的系统统一销毁，
+ * 避免其他系统在同一帧内持有的实体 ID 中途失效。手动 update 和 Loop 运行器下都会执行
+ */
+
+import type { AnyEntity } from "@rbxts/matter";
+import type { World } from "./bevy-world";
+import type { Resource } from "./resource";
+
+/**
+ * 延迟销毁队列资源
+ * 由 SubApp 自动插入
+ *
+ * @example
+ * ```typescript
+ * function killSystem(world: World) {
+ *     const despawns = world.resources.getResource<DeferredDespawn>()!;
+ *     for (const [entity, health] of world.query(Health)) {
+ *         if (health.current <= 0) despawns.queue(entity);
+ *     }
+ * }
+ * ```
+ */
+export class DeferredDespawn implements Resource {
+	readonly __brand = "Resource" as const;
+	private readonly pending: AnyEntity[] = [];
+	private readonly queued = new Set<AnyEntity>();
+
+	/**
+	 * 将实体加入销毁队列
+	 * 同一帧内重复加入同一实体只会销毁一次
+	 * @param entity - 要销毁的实体
+	 */
+	queue(entity: AnyEntity): void {
+		if (this.queued.has(entity)) {
+			return;
+		}
+		this.queued.add(entity);
+		this.pending.push(entity);
+	}
+
+	/**
+	 * 检查实体是否已在队列中
+	 * @param entity - 实体
+	 */
+	isQueued(entity: AnyEntity): boolean {
+		return this.queued.has(entity);
+	}
+
+	/**
+	 * 获取队列中的实体数量
+	 */
+	size(): number {
+		return this.pending.size();
+	}
+
+	/**
+	 * 销毁队列中的所有实体并清空队列
+	 * 已经被销毁的实体会被静默忽略
+	 * @param world - 实体所在的世界
+	 * @returns 实际销毁的实体数量
+	 */
+	flush(world: World): number {
+		let despawned = 0;
+		for (const entity of this.pending) {
+			if (world.contains(entity)) {
+				world.despawn(entity);
+				despawned++;
+			}
+		}
+		this.pending.clear();
+		this.queued.clear();
+		return despawned;
+	}
+}
@@ -0,0 +1,107 @@
+/**
+ * @fileoverview 实体名称注册表
+ * 通过字符串键查找实体，用于关卡中需要按名称引用的实体（出生点、摄像机等）
+ *
+ * 与 Name 组件不同，EntityNames 中的名称是唯一的：同一个名称只对应一个实体。
+ */
+
+import type { AnyEntity } from "@rbxts/matter";
+import type { World } from "./bevy-world";
+import type { Resource } from "./resource";
+
+/**
+ * 实体名称注册表资源
+ * 由 App 自动插入；已销毁实体的名称由 cleanupEntityNamesSystem 在每帧 First 阶段移除
+ */
+export class EntityNames implements Resource {
+	readonly __brand = "Resource" as const;
+	private readonly entitiesByName = new Map<string, AnyEntity>();
+	private readonly namesByEntity = new Map<AnyEntity, string>();
+
+	/**
+	 * 为实体注册名称
+	 * 名称已被其他实体使用时覆盖旧的映射；实体已有其他名称时旧名称被移除
+	 * @param name - 名称
+	 * @param entity - 实体
+	 * @returns 被覆盖的旧实体，没有时返回 undefined
+	 */
+	insert(name: string, entity: AnyEntity): AnyEntity | undefined {
+		const previous = this.entitiesByName.get(name);
+		if (previous !== undefined) {
+			this.namesByEntity.delete(previous);
+		}
+
+		const previousName = this.namesByEntity.get(entity);
+		if (previousName !== undefined) {
+			this.entitiesByName.delete(previousName);
+		}
+
+		this.entitiesByName.set(name, entity);
+		this.namesByEntity.set(entity, name);
+		return previous !== entity ? previous : undefined;
+	}
+
+	/**
+	 * 按名称查找实体
+	 * @param name - 名称
+	 * @returns 实体，不存在时返回 undefined
+	 */
+	get(name: string): AnyEntity | undefined {
+		return this.entitiesByName.get(name);
+	}
+
+	/**
+	 * 获取实体的名称
+	 * @param entity - 实体
+	 * @returns 名称，未注册时返回 undefined
+	 */
+	nameOf(entity: AnyEntity): string | undefined {
+		return this.namesByEntity.get(entity);
+	}
+
+	/**
+	 * 移除名称
+	 * @param name - 名称
+	 * @returns 名称对应的实体，不存在时返回 undefined
+	 */
+	remove(name: string): AnyEntity | undefined {
+		const entity = this.entitiesByName.get(name);
+		if (entity !== undefined) {
+			this.entitiesByName.delete(name);
+			this.namesByEntity.delete(entity);
+		}
+		return entity;
+	}
+
+	/**
+	 * 获取已注册的名称数量
+	 */
+	size(): number {
+		return this.entitiesByName.size();
+	}
+
+	/**
+	 * 移除已不存在于世界中的实体的名称
+	 * @param world - 游戏世界
+	 */
+	removeDespawned(world: World): void {
+		const stale: string[] = [];
+		for (const [name, entity] of this.entitiesByName) {
+			if (!world.contains(entity)) {
+				stale.push(name);
+			}
+		}
+
+		for (const name of stale) {
+			this.remove(name);
+		}
+	}
+}
+
+/**
+ * 清理已销毁实体名称的系统
+ * @param world - 游戏世界
+ */
+export function cleanupEntityNamesSystem(world: World): void {
+	world.resources.getResource<EntityNames>()?.removeDespawned(world);
+}
@@ -37,7 +37,7 @@ export const Children = component<{
  *
  * 功能分类：
  * - 查询：getChildren, getParent, getDescendants, getAncestors, getRoot
- * - 修改：setParent, reparent, despawnWithDescendants
+ * - 修改：setParent, reparent, despawnWithDescendants, despawnTree
  * - 判断：isAncestor
  * - 辅助：getSiblingIndex, getDepth
  */
@@ -236,6 +236,61 @@ export class HierarchyUtils {
 		world.despawn(entity as AnyEntity);
 	}
 
+	/**
+	 * 防御性地移除实体及其所有子孙
+	 * 与 despawnWithDescendants 不同，不依赖层次结构处于一致状态：
+	 * - 子实体同时从 Children 缓存和其他实体的 Parent 组件中查找，两者不一致时取并集
+	 * - 已经不存在的实体被跳过
+	 * - 每个实体只访问一次，Parent 链中存在环时不会无限循环
+	 * @param world - World 实例
+	 * @param root - 根实体 ID
+	 * @returns 被移除的实体数量
+	 */
+	static despawnTree(world: World, root: number): number {
+		if (!world.contains(root as AnyEntity)) {
+			return 0;
+		}
+
+		// Parent 组件是权威的父子关系，Children 只是缓存
+		const childrenByParent = new Map<number, number[]>();
+		for (const [entity, parent] of world.query(Parent)) {
+			let children = childrenByParent.get(parent.entity);
+			if (children === undefined) {
+				children = [];
+				childrenByParent.set(parent.entity, children);
+			}
+			children.push(entity as number);
+		}
+
+		const visited = new Set<number>();
+		const stack = [root];
+		while (stack.size() > 0) {
+			const current = stack.pop()!;
+			if (visited.has(current) || !world.contains(current as AnyEntity)) {
+				continue;
+			}
+			visited.add(current);
+
+			for (const child of this.getChildren(world, current)) {
+				stack.push(child);
+			}
+			for (const child of childrenByParent.get(current) ?? []) {
+				stack.push(child);
+			}
+		}
+
+		// 根实体的父级不在子树中，需要更新其 Children 缓存
+		const parent = this.getParent(world, root);
+		if (parent !== undefined && !visited.has(parent) && world.contains(parent as AnyEntity)) {
+			this.removeChildFromParent(world, parent, root);
+		}
+
+		for (const entity of visited) {
+			world.despawn(entity as AnyEntity);
+		}
+		return visited.size();
+	}
+
 	/**
 	 * 从一个父级移动到另一个父级
 	 * 保持子树完整
@@ -11,12 +11,21 @@ export * from "./resource";
 export * from "./bevy-world";
 export * from "./message";
 export * from "./name";
+export * from "./entity-names";
+export * from "./deferred-despawn";
+export * from "./required-components";
+export * from "./archetypes";
+export * from "./singleton";
+export * from "./channel";
 export * from "./types";
 export * from "./query";
+export * from "./cached-query";
 export * from "./change-detection";
 export * from "./hierarchy";
 export * from "./component"
 export * from "./ecs-provider"
+export * from "./snapshot";
+export * from "./rollback";
 
 
 import { World } from "./bevy-world";
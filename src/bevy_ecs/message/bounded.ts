@@ -0,0 +1,83 @@
+/**
+ * 有界消息 - 限制每帧消息缓冲区的容量
+ *
+ * Rust Bevy 的消息缓冲区没有上限，生产者每帧写入大量消息时内存会无限增长。
+ * 为消息类型设置容量后，超出容量的写入按 MessageOverflowPolicy 处理。
+ */
+
+import type { Resource } from "../resource";
+import { Message } from "./types";
+
+/**
+ * 被丢弃的消息返回的 MessageId.id
+ */
+export const DROPPED_MESSAGE_ID = -1;
+
+/**
+ * 消息溢出策略
+ */
+export enum MessageOverflowPolicy {
+	/** 丢弃本帧最早写入的消息，保留新消息 */
+	DropOldest = "DropOldest",
+	/** 丢弃新写入的消息，保留本帧已有的消息 */
+	DropNewest = "DropNewest",
+	/** 直接报错 */
+	Error = "Error",
+}
+
+/**
+ * 有界消息统计资源
+ * 由 App.addBoundedMessage 插入，同时记录容量配置和丢弃数量
+ *
+ * @template M - 消息类型
+ */
+export class BoundedMessageStats<M extends Message> implements Resource {
+	readonly __brand = "Resource" as const;
+	/** 当前帧丢弃的消息数量 */
+	private droppedThisFrame = 0;
+	/** 累计丢弃的消息数量 */
+	private droppedTotal = 0;
+	/** 类型标记 */
+	private readonly _marker?: M;
+
+	/**
+	 * 创建有界消息统计
+	 * @param capacity - 每帧消息缓冲区的容量
+	 * @param policy - 溢出策略
+	 */
+	constructor(
+		public readonly capacity: number,
+		public readonly policy: MessageOverflowPolicy,
+	) {
+		assert(capacity > 0, `Bounded message capacity must be positive, got ${capacity}`);
+	}
+
+	/**
+	 * 获取当前帧丢弃的消息数量
+	 */
+	getDroppedThisFrame(): number {
+		return this.droppedThisFrame;
+	}
+
+	/**
+	 * 获取累计丢弃的消息数量
+	 */
+	getDroppedTotal(): number {
+		return this.droppedTotal;
+	}
+
+	/**
+	 * 记录一次丢弃（内部使用）
+	 */
+	recordDrop(): void {
+		this.droppedThisFrame++;
+		this.droppedTotal++;
+	}
+
+	/**
+	 * 开始新的一帧，清零当前帧计数（内部使用）
+	 */
+	beginFrame(): void {
+		this.droppedThisFrame = 0;
+	}
+}
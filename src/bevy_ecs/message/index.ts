@@ -32,6 +32,9 @@ export { MessageCursor } from "./message-cursor";
 export { MessageWriter } from "./message-writer";
 export { MessageReader } from "./message-reader";
 
+// 导出有界消息
+export { BoundedMessageStats, MessageOverflowPolicy, DROPPED_MESSAGE_ID } from "./bounded";
+
 // 导出消息注册表
 export { MessageRegistry } from "./message-registry";
 
@@ -93,19 +93,16 @@ export class MessageCursor<M extends Message = Message> {
 	 * 对应 Rust 的 len
 	 */
 	public len(messages: Messages<M>): number {
-		const currentCount = messages.getMessageCount();
-		const oldestCount = messages.getOldestMessageCount();
-
-		if (this.lastMessageCount >= currentCount) {
-			return 0;
-		}
+		const messagesA = messages.getMessagesA();
+		const messagesB = messages.getMessagesB();
 
-		if (this.lastMessageCount < oldestCount) {
-			// 游标太旧，一些消息已被丢弃
-			return currentCount - oldestCount;
+		// 与 read 相同地分别统计两个缓冲区，游标太旧或消息因溢出被丢弃时不计入
+		let count = 0;
+		if (this.lastMessageCount < messagesB.startMessageCount) {
+			count += math.max(0, messagesA.size() - math.max(0, this.lastMessageCount - messagesA.startMessageCount));
 		}
-
-		return currentCount - this.lastMessageCount;
+		count += math.max(0, messagesB.size() - math.max(0, this.lastMessageCount - messagesB.startMessageCount));
+		return count;
 	}
 
 	/**
@@ -5,6 +5,7 @@
 
 import { Message, MessageId, MessageInstance, MessageSequence, WriteBatchIds } from "./types";
 import { MessageCursor } from "./message-cursor";
+import { BoundedMessageStats, DROPPED_MESSAGE_ID, MessageOverflowPolicy } from "./bounded";
 
 /**
  * 消息集合 - 表示最近两次 update 调用内发生的消息
@@ -29,7 +30,8 @@ import { MessageCursor } from "./message-cursor";
 export class Messages<M extends Message> {
 	/**
 	 * 保存最旧的仍然活跃的消息
-	 * 注意 a.startMessageCount + a.size() 应该总是等于 messagesB.startMessageCount
+	 * 注意 a.startMessageCount + a.size() 应该总是等于 messagesB.startMessageCount；
+	 * 唯一的例外是 DropOldest 溢出时 B 的起始计数前移，两者之间的 ID 对应被丢弃的消息
 	 */
 	private messagesA: MessageSequence<M>;
 
@@ -43,6 +45,11 @@ export class Messages<M extends Message> {
 	 */
 	private messageCount: number;
 
+	/**
+	 * 容量限制，未设置时缓冲区无上限
+	 */
+	private bound?: BoundedMessageStats<M>;
+
 	constructor() {
 		this.messagesA = new MessageSequence<M>();
 		this.messagesB = new MessageSequence<M>();
@@ -57,13 +64,36 @@ export class Messages<M extends Message> {
 		return this.messagesA.startMessageCount;
 	}
 
+	/**
+	 * 设置每帧消息缓冲区的容量限制
+	 * @param stats - 容量配置及丢弃统计
+	 */
+	public setBound(stats: BoundedMessageStats<M>): void {
+		this.bound = stats;
+	}
+
+	/**
+	 * 获取容量限制
+	 * @returns 容量配置及丢弃统计，未设置时返回 undefined
+	 */
+	public getBound(): BoundedMessageStats<M> | undefined {
+		return this.bound;
+	}
+
 	/**
 	 * 写入消息到当前消息缓冲区
 	 * MessageReader 可以读取该消息
 	 * 返回写入消息的 ID
 	 * 对应 Rust 的 write
+	 *
+	 * 设置了容量限制且本帧缓冲区已满时按溢出策略处理：
+	 * DropNewest 丢弃该消息并返回 id 为 DROPPED_MESSAGE_ID 的 MessageId
 	 */
 	public write(message: M): MessageId<M> {
+		if (this.bound !== undefined && this.messagesB.size() >= this.bound.capacity) {
+			return this.writeOverflow(message, this.bound);
+		}
+
 		const timestamp = os.clock();
 		const messageId: MessageId<M> = {
 			id: this.messageCount,
@@ -97,6 +127,13 @@ export class Messages<M extends Message> {
 	public writeBatch(messages: M[]): WriteBatchIds<M> {
 		const lastCount = this.messageCount;
 
+		if (this.bound !== undefined) {
+			for (const message of messages) {
+				this.write(message);
+			}
+			return new WriteBatchIds<M>(lastCount, this.messageCount);
+		}
+
 		for (const message of messages) {
 			const timestamp = os.clock();
 			const messageId: MessageId<M> = {
@@ -123,6 +160,41 @@ export class Messages<M extends Message> {
 		return new WriteBatchIds<M>(lastCount, this.messageCount);
 	}
 
+	/**
+	 * 处理超出容量的写入
+	 * @param message - 新消息
+	 * @param bound - 容量配置
+	 * @returns 消息 ID
+	 */
+	private writeOverflow(message: M, bound: BoundedMessageStats<M>): MessageId<M> {
+		bound.recordDrop();
+
+		if (bound.policy === MessageOverflowPolicy.Error) {
+			error(`Message buffer exceeded capacity ${bound.capacity} in a single frame`);
+		}
+
+		const timestamp = os.clock();
+
+		if (bound.policy === MessageOverflowPolicy.DropNewest) {
+			return { id: DROPPED_MESSAGE_ID, timestamp, _marker: message };
+		}
+
+		// DropOldest: 丢弃本帧最旧的消息并前移 B 的起始计数，新消息使用新的 ID，
+		// 因此已经读到本帧末尾的读取器仍能读到新消息，也不会重复读到已读过的消息
+		this.messagesB.messages.remove(0);
+		this.messagesB.startMessageCount++;
+
+		const messageId: MessageId<M> = { id: this.messageCount, timestamp, _marker: message };
+		const messageWithTimestamp = message as M & { timestamp?: number };
+		if (messageWithTimestamp.timestamp === undefined) {
+			messageWithTimestamp.timestamp = timestamp;
+		}
+		this.messagesB.push({ messageId, message });
+		this.messageCount++;
+
+		return messageId;
+	}
+
 	/**
 	 * 写入消息的默认值（当消息是空结构时很有用）
 	 * 对应 Rust 的 write_default
@@ -160,6 +232,8 @@ export class Messages<M extends Message> {
 	 * 对应 Rust 的 update
 	 */
 	public update(): void {
+		this.bound?.beginFrame();
+
 		// 交换缓冲区
 		const temp = this.messagesA;
 		this.messagesA = this.messagesB;
@@ -184,6 +258,8 @@ export class Messages<M extends Message> {
 	 * 对应 Rust 的 update_drain
 	 */
 	public updateDrain(): M[] {
+		this.bound?.beginFrame();
+
 		// 交换缓冲区
 		const temp = this.messagesA;
 		this.messagesA = this.messagesB;
@@ -305,6 +381,11 @@ export class Messages<M extends Message> {
 	 * 对应 Rust 的 Extend trait 实现
 	 */
 	public extend(messages: M[]): void {
+		if (this.bound !== undefined) {
+			this.writeBatch(messages);
+			return;
+		}
+
 		const oldCount = this.messageCount;
 		const instances: MessageInstance<M>[] = [];
 
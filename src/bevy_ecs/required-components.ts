@@ -0,0 +1,175 @@
+/**
+ * @fileoverview 必需组件
+ * 声明组件之间的依赖关系（拥有 A 的实体必须同时拥有 B），并在运行时检查违反声明的实体
+ *
+ * @example
+ * ```typescript
+ * app.requireComponent(Velocity).with(Position);
+ * app.validateRequirements(RequirementPolicy.Panic);
+ * ```
+ */
+
+import type { AnyEntity } from "@rbxts/matter";
+import type { World } from "./bevy-world";
+import type { ComponentCtor } from "./query";
+import type { Resource } from "./resource";
+
+/**
+ * 违反必需组件声明时的处理策略
+ */
+export enum RequirementPolicy {
+	/** 输出警告，每个违反只报告一次 */
+	Warn = "Warn",
+	/** 直接报错 */
+	Panic = "Panic",
+}
+
+/**
+ * 必需组件违反记录
+ */
+export interface RequirementViolation {
+	/** 违反声明的实体 */
+	readonly entity: AnyEntity;
+	/** 实体拥有的组件名称 */
+	readonly component: string;
+	/** 实体缺失的组件名称 */
+	readonly missing: string;
+}
+
+/**
+ * 必需组件声明构建器
+ */
+export class RequirementBuilder {
+	/**
+	 * 创建声明构建器
+	 * @param registry - 必需组件注册表
+	 * @param component - 声明依赖的组件
+	 */
+	constructor(
+		private readonly registry: RequiredComponents,
+		private readonly component: ComponentCtor,
+	) {}
+
+	/**
+	 * 声明必需的组件
+	 * @param required - 拥有 component 的实体必须同时拥有的组件
+	 * @returns 当前构建器，支持链式声明多个必需组件
+	 */
+	with(required: ComponentCtor): this {
+		this.registry.addRequirement(this.component, required);
+		return this;
+	}
+}
+
+/**
+ * 必需组件注册表资源
+ */
+export class RequiredComponents implements Resource {
+	readonly __brand = "Resource" as const;
+	/** 检查发现违反时的处理策略 */
+	policy = RequirementPolicy.Warn;
+	/** 检查系统是否已添加 */
+	validationEnabled = false;
+	private readonly requirements = new Map<ComponentCtor, ComponentCtor[]>();
+	private readonly reported = new Set<string>();
+	private lastViolations: RequirementViolation[] = [];
+
+	/**
+	 * 开始为组件声明必需组件
+	 * @param component - 声明依赖的组件
+	 * @returns 声明构建器
+	 */
+	require(component: ComponentCtor): RequirementBuilder {
+		return new RequirementBuilder(this, component);
+	}
+
+	/**
+	 * 添加一条必需组件声明
+	 * @param component - 声明依赖的组件
+	 * @param required - 必需的组件
+	 */
+	addRequirement(component: ComponentCtor, required: ComponentCtor): void {
+		let list = this.requirements.get(component);
+		if (!list) {
+			list = [];
+			this.requirements.set(component, list);
+		}
+
+		if (!list.includes(required)) {
+			list.push(required);
+		}
+	}
+
+	/**
+	 * 获取组件的必需组件
+	 * @param component - 组件构造函数
+	 */
+	getRequirements(component: ComponentCtor): ReadonlyArray<ComponentCtor> {
+		return this.requirements.get(component) ?? [];
+	}
+
+	/**
+	 * 检查世界中违反声明的实体
+	 * @param world - 游戏世界
+	 * @returns 违反记录
+	 */
+	check(world: World): RequirementViolation[] {
+		const violations: RequirementViolation[] = [];
+
+		for (const [component, requiredList] of this.requirements) {
+			for (const [entity] of world.query(component)) {
+				for (const required of requiredList) {
+					if (world.get(entity, required) === undefined) {
+						violations.push({ entity, component: tostring(component), missing: tostring(required) });
+					}
+				}
+			}
+		}
+
+		this.lastViolations = violations;
+		return violations;
+	}
+
+	/**
+	 * 获取最近一次检查发现的违反
+	 */
+	getLastViolations(): ReadonlyArray<RequirementViolation> {
+		return this.lastViolations;
+	}
+
+	/**
+	 * 检查违反并按策略处理
+	 * @param world - 游戏世界
+	 */
+	validate(world: World): void {
+		const violations = this.check(world);
+		if (violations.size() === 0) {
+			return;
+		}
+
+		if (this.policy === RequirementPolicy.Panic) {
+			const violation = violations[0];
+			error(
+				`[RequiredComponents] Entity ${violation.entity} has "${violation.component}" but is missing required "${violation.missing}"`,
+			);
+		}
+
+		for (const violation of violations) {
+			const key = `${violation.entity}|${violation.missing}`;
+			if (!this.reported.has(key)) {
+				this.reported.add(key);
+				warn(
+					`[RequiredComponents] Entity ${violation.entity} has "${violation.component}" but is missing required "${violation.missing}"`,
+				);
+			}
+		}
+	}
+}
+
+/**
+ * 检查必需组件的系统
+ * @param world - 游戏世界
+ */
+export function validateRequiredComponentsSystem(world: World): void {
+	world.resources.getResource<RequiredComponents>()?.validate(world);
+}
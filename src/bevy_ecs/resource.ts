@@ -31,6 +31,8 @@ export interface ResourceMetadata {
 	readonly created: number;
 	/** 资源最后更新时间 */
 	readonly updated: number;
+	/** 资源最后一次被插入或替换时的变更计数，每次插入都会递增，用于变更检测 */
+	readonly changeTick: number;
 }
 
 /**
@@ -48,6 +50,8 @@ export interface ResourceMetadata {
 export class ResourceManager {
 	private readonly resources = new Map<ComponentId, object>();
 	private readonly resourceMetadata = new Map<ComponentId, ResourceMetadata>();
+	/** 全局变更计数，每次插入资源时递增 */
+	private changeTick = 0;
 
 	/**
 	 * 创建资源管理器
@@ -203,6 +207,7 @@ export class ResourceManager {
 		this.resources.set(componentId, resource as object);
 
 		const now = os.clock();
+		this.changeTick++;
 
 		const existingMetadata = this.resourceMetadata.get(componentId);
 
@@ -212,16 +217,61 @@ export class ResourceManager {
 			this.resourceMetadata.set(componentId, {
 				...existingMetadata,
 				updated: now,
+				changeTick: this.changeTick,
 			});
 		} else {
 			this.resourceMetadata.set(componentId, {
 				typeDescriptor,
 				created: now,
 				updated: now,
+				changeTick: this.changeTick,
 			});
 		}
 	}
 
+	/**
+	 * 替换已存在的资源
+	 * 新值会被标记为已变更，使用 resourceChanged 条件的系统会在之后运行一次
+	 *
+	 * **注意**: @claudecode , @cursor ,  这是一个宏文件, 所有 Modding.* 的类型, 不要主动调用.
+	 * @metadata macro
+	 *
+	 * @param resource - 新的资源实例
+	 * @returns 被替换的旧资源实例；资源不存在时报错
+	 */
+	public swapResource<T extends object>(resource: T, id?: Modding.Generic<T, "id">, text?: Modding.Generic<T, "text">): T {
+		const descriptor = getTypeDescriptor(id, text);
+		if (!descriptor) {
+			error(`swapResource: can't get type descriptor for id=${id} text=${text}. This is likely a macro issue.`);
+		}
+		return this.swapResourceByTypeDescriptor(resource, descriptor);
+	}
+
+	/**
+	 * 通过 TypeDescriptor 替换已存在的资源
+	 * @param resource - 新的资源实例
+	 * @param descriptor - 资源的类型描述符
+	 * @returns 被替换的旧资源实例；资源不存在时报错
+	 */
+	public swapResourceByTypeDescriptor<T extends object>(resource: T, descriptor: TypeDescriptor): T {
+		const previous = this.getResourceByTypeDescriptor<T>(descriptor);
+		if (previous === undefined) {
+			error(`swapResource: resource "${descriptor.text}" does not exist, insert it before swapping`);
+		}
+
+		this.insertResourceByTypeDescriptor(resource, descriptor);
+		return previous;
+	}
+
+	/**
+	 * 获取资源的变更计数（通过 TypeDescriptor）
+	 * @param descriptor - 资源的类型描述符
+	 * @returns 资源最后一次被插入或替换时的变更计数，资源不存在时返回 undefined
+	 */
+	public getChangeTickByDescriptor(descriptor: TypeDescriptor): number | undefined {
+		return this.resourceMetadata.get(getComponentIdByDescriptor(descriptor))?.changeTick;
+	}
+
 
 	/**
 	 * 移除资源
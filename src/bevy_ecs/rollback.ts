@@ -0,0 +1,191 @@
+/**
+ * @fileoverview 回滚缓冲区
+ * 为客户端预测保存最近 K 个 tick 的 World 快照，需要纠正时回滚到过去的 tick 并重新模拟
+ *
+ * 只有通过 SnapshotRegistry 注册的组件参与保存和回滚。
+ * 回滚后这些组件与 checkpoint 完全一致：快照中的组件被恢复，checkpoint 之后才添加的已注册组件被移除；
+ * 未注册的组件不受影响，checkpoint 之后生成的实体也不会被销毁。
+ *
+ * @example
+ * ```typescript
+ * const rollback = new RollbackBuffer(registry, 64);
+ * rollback.save(world, tick);
+ * // 收到服务器的权威状态后
+ * const err = rollback.rollbackTo(world, serverTick);
+ * ```
+ */
+
+import type { AnyEntity } from "@rbxts/matter";
+import type { World } from "./bevy-world";
+import type { Resource } from "./resource";
+import type { SnapshotRegistry } from "./snapshot";
+import { WorldSnapshot } from "./snapshot";
+
+/**
+ * 回滚错误类型
+ */
+export enum RollbackErrorKind {
+	/** tick 的快照已因超出容量被淘汰 */
+	Evicted = "Evicted",
+	/** tick 从未被保存 */
+	NotSaved = "NotSaved",
+}
+
+/**
+ * 回滚错误
+ */
+export class RollbackError {
+	public name = "RollbackError";
+
+	/**
+	 * 创建回滚错误
+	 * @param kind - 错误类型
+	 * @param tick - 请求回滚的 tick
+	 * @param message - 错误信息
+	 */
+	constructor(
+		public readonly kind: RollbackErrorKind,
+		public readonly tick: number,
+		public readonly message: string,
+	) {}
+
+	/**
+	 * 将错误转换为字符串
+	 * @returns 错误信息字符串
+	 */
+	toString(): string {
+		return `${this.name}(${this.kind}): ${this.message}`;
+	}
+}
+
+/**
+ * 回滚缓冲区资源
+ * 按 tick 保存快照，超出容量时淘汰最早的快照
+ */
+export class RollbackBuffer implements Resource {
+	readonly __brand = "Resource" as const;
+	private readonly snapshots = new Map<number, WorldSnapshot>();
+	/** 已保存的 tick，按保存顺序排列 */
+	private readonly ticks: number[] = [];
+	/** 被淘汰过的最大 tick */
+	private evictedThrough?: number;
+
+	/**
+	 * 创建回滚缓冲区
+	 * @param registry - 快照组件注册表，决定哪些组件参与回滚
+	 * @param capacity - 最多保存的快照数量
+	 */
+	constructor(
+		private readonly registry: SnapshotRegistry,
+		private readonly capacity: number,
+	) {
+		assert(capacity > 0, `RollbackBuffer capacity must be positive, got ${capacity}`);
+	}
+
+	/**
+	 * 保存当前 World 的快照
+	 * 同一 tick 重复保存时覆盖旧快照；超出容量时淘汰最早保存的快照
+	 * @param world - 游戏世界
+	 * @param tick - 快照对应的 tick
+	 */
+	save(world: World, tick: number): void {
+		if (!this.snapshots.has(tick)) {
+			this.ticks.push(tick);
+		}
+		this.snapshots.set(tick, WorldSnapshot.capture(world, this.registry));
+
+		while (this.ticks.size() > this.capacity) {
+			const evicted = this.ticks.remove(0)!;
+			this.snapshots.delete(evicted);
+			this.evictedThrough = this.evictedThrough === undefined ? evicted : math.max(this.evictedThrough, evicted);
+		}
+	}
+
+	/**
+	 * 回滚到过去的 tick
+	 * 已注册组件恢复为该 tick 保存时的状态；该 tick 之后保存的快照被丢弃，重新模拟时重新保存
+	 * @param world - 游戏世界
+	 * @param tick - 目标 tick
+	 * @returns 快照已被淘汰或从未保存时返回错误
+	 */
+	rollbackTo(world: World, tick: number): RollbackError | undefined {
+		const snapshot = this.snapshots.get(tick);
+		if (snapshot === undefined) {
+			if (this.evictedThrough !== undefined && tick <= this.evictedThrough) {
+				return new RollbackError(
+					RollbackErrorKind.Evicted,
+					tick,
+					`Snapshot for tick ${tick} was evicted (capacity ${this.capacity})`,
+				);
+			}
+			return new RollbackError(RollbackErrorKind.NotSaved, tick, `No snapshot was saved for tick ${tick}`);
+		}
+
+		this.removeComponentsMissingFrom(world, snapshot);
+		WorldSnapshot.restore(world, snapshot);
+
+		for (let index = this.ticks.size() - 1; index >= 0; index--) {
+			const saved = this.ticks[index];
+			if (saved > tick) {
+				this.ticks.remove(index);
+				this.snapshots.delete(saved);
+			}
+		}
+		return undefined;
+	}
+
+	/**
+	 * 检查 tick 是否有可用的快照
+	 * @param tick - tick
+	 */
+	has(tick: number): boolean {
+		return this.snapshots.has(tick);
+	}
+
+	/**
+	 * 获取当前保存的快照数量
+	 */
+	size(): number {
+		return this.ticks.size();
+	}
+
+	/**
+	 * 获取最早的可用 tick
+	 * @returns tick，缓冲区为空时返回 undefined
+	 */
+	oldestTick(): number | undefined {
+		let oldest: number | undefined;
+		for (const tick of this.ticks) {
+			if (oldest === undefined || tick < oldest) {
+				oldest = tick;
+			}
+		}
+		return oldest;
+	}
+
+	/**
+	 * 移除快照中不存在的已注册组件
+	 * @param world - 游戏世界
+	 * @param snapshot - 目标快照
+	 */
+	private removeComponentsMissingFrom(world: World, snapshot: WorldSnapshot): void {
+		const present = new Set<string>();
+		for (const entitySnapshot of snapshot.entities) {
+			for (const componentSnapshot of entitySnapshot.components) {
+				present.add(`${entitySnapshot.id}|${componentSnapshot.name}`);
+			}
+		}
+
+		for (const [name, component] of this.registry.entries()) {
+			const stale: AnyEntity[] = [];
+			for (const [entity] of world.query(component)) {
+				if (!present.has(`${entity}|${name}`)) {
+					stale.push(entity);
+				}
+			}
+			for (const entity of stale) {
+				world.remove(entity, component);
+			}
+		}
+	}
+}
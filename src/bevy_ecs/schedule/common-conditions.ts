@@ -0,0 +1,129 @@
+/**
+ * @fileoverview 通用运行条件
+ * 提供与具体插件无关的运行条件，用于 runIf
+ */
+
+import { Modding } from "@flamework/core";
+import { getTypeDescriptor } from "../../bevy_core";
+import type { ComponentCtor } from "../query";
+import type { RunCondition } from "./types";
+
+/**
+ * 创建检测组件变更的运行条件
+ * 本帧有任意实体添加或修改了该组件时返回 true；仅移除组件不视为变更。
+ * 条件首次求值时，只要存在拥有该组件的实体就返回 true
+ *
+ * 基于 Matter 的 queryChanged，每个条件实例独立跟踪变更，因此不能在多个系统之间共享同一个实例
+ * @param component - 组件构造函数
+ * @returns 运行条件
+ *
+ * @example
+ * app.addSystems(Update, system(rebuildNavMesh).runIf(anyComponentChanged(Obstacle)));
+ */
+export function anyComponentChanged(component: ComponentCtor): RunCondition {
+	let firstRun = true;
+
+	return (world) => {
+		let changed = false;
+
+		// 必须完整遍历，否则未读取的变更会留到下一帧
+		for (const [, record] of world.queryChanged(component)) {
+			if (record.new !== undefined) {
+				changed = true;
+			}
+		}
+
+		if (firstRun) {
+			firstRun = false;
+			if (!changed) {
+				const [entity] = world.query(component).next();
+				changed = entity !== undefined;
+			}
+		}
+
+		return changed;
+	};
+}
+
+/**
+ * 创建检测资源变更的运行条件
+ * 资源自上次求值以来被插入或通过 swapResource 替换时返回 true；
+ * 条件首次求值时，只要资源存在就返回 true。原地修改资源的字段不会被检测到
+ *
+ * 每个条件实例独立记录上次看到的变更，因此不能在多个系统之间共享同一个实例
+ *
+ * **注意**: @claudecode, @cursor - 这是一个宏方法，Modding.* 类型参数由编译器自动提供
+ * @metadata macro
+ * @template T - 资源类型
+ * @param id - 资源类型标识符（由宏自动提供）
+ * @param text - 资源类型文本描述（由宏自动提供）
+ * @returns 运行条件
+ *
+ * @example
+ * app.addSystems(Update, system(rebuildLoot).runIf(resourceChanged<LootConfig>()));
+ */
+export function resourceChanged<T extends object>(
+	id?: Modding.Generic<T, "id">,
+	text?: Modding.Generic<T, "text">,
+): RunCondition {
+	const descriptor = getTypeDescriptor(id, text);
+	assert(descriptor, "resourceChanged: can't get type descriptor, this is likely a macro issue");
+
+	let lastSeenTick: number | undefined;
+
+	return (world) => {
+		const tick = world.resources.getChangeTickByDescriptor(descriptor);
+		if (tick === undefined || tick === lastSeenTick) {
+			return false;
+		}
+
+		lastSeenTick = tick;
+		return true;
+	};
+}
+
+/**
+ * 组合多个运行条件，所有条件都为 true 时返回 true
+ * 按顺序求值，遇到第一个 false 即停止，后面的条件不会被调用
+ * @param conditions - 运行条件列表
+ * @returns 组合后的运行条件
+ *
+ * @example
+ * app.addSystems(Update, system(spawnWave).runIf(runIfAll([hasPlayers, runIfNot(isPaused)])));
+ */
+export function runIfAll(conditions: ReadonlyArray<RunCondition>): RunCondition {
+	return (world) => {
+		for (const condition of conditions) {
+			if (!condition(world)) {
+				return false;
+			}
+		}
+		return true;
+	};
+}
+
+/**
+ * 组合多个运行条件，任意条件为 true 时返回 true
+ * 按顺序求值，遇到第一个 true 即停止，后面的条件不会被调用
+ * @param conditions - 运行条件列表
+ * @returns 组合后的运行条件
+ */
+export function runIfAny(conditions: ReadonlyArray<RunCondition>): RunCondition {
+	return (world) => {
+		for (const condition of conditions) {
+			if (condition(world)) {
+				return true;
+			}
+		}
+		return false;
+	};
+}
+
+/**
+ * 反转运行条件
+ * @param condition - 原始运行条件
+ * @returns 反转后的运行条件
+ */
+export function runIfNot(condition: RunCondition): RunCondition {
+	return (world) => !condition(world);
+}
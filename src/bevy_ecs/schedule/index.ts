@@ -10,7 +10,10 @@ export { Schedules } from "./schedules";
 export { SystemConfigs, extendSystemFunction, extendSystemArray, intoSystemConfigs } from "./system-configs";
 
 // 系统构建器导出
-export { system, systemArray, chain, when, after, before, inSet } from "./system-builder";
+export { system, systemArray, chain, when, after, before, inSet, inStage } from "./system-builder";
+
+// 通用运行条件导出
+export { anyComponentChanged, resourceChanged, runIfAll, runIfAny, runIfNot } from "./common-conditions";
 
 // 类型定义导出
 export type {
@@ -24,6 +27,8 @@ export type {
 	SchedulerState,
 	ScheduleGraph,
 	ScheduleStats,
+	SystemWrapper,
+	SystemWrapperInfo,
 } from "./types";
 
 // Loop 类型导出
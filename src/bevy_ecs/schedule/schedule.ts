@@ -11,9 +11,11 @@ import type {
 	SystemSet,
 	ScheduleLabel,
 	InternalSystemStruct,
+	OrderingEdge,
 	ScheduleGraph,
 	SchedulerState,
 	ScheduleStats,
+	SystemWrapper,
 } from "./types";
 import type { BevySystem, BevyWorld, Context } from "../";
 
@@ -40,6 +42,12 @@ export class Schedule {
 		lastExecutionTime: 0,
 	};
 	private context?: Context;
+	private systemWrappers: ReadonlyArray<SystemWrapper> = [];
+	/** 编译后每个系统实际执行的函数，replaceSystem 通过替换槽位中的函数实现热替换 */
+	private readonly runSlots = new Map<string, { run: SystemFunction }>();
+	/** 系统的注册顺序，Map 的遍历顺序不确定，确定性模式依赖这里的顺序 */
+	private readonly registrationOrder: string[] = [];
+	private deterministic = false;
 
 	/**
 	 * 创建新的调度器
@@ -58,6 +66,25 @@ export class Schedule {
 	}
 
 
+	/**
+	 * 设置系统包装器 - 由 Schedules 调用
+	 * 包装器在编译时按顺序应用到每个系统
+	 * @param wrappers - 系统包装器列表
+	 */
+	public setSystemWrappers(wrappers: ReadonlyArray<SystemWrapper>): void {
+		this.systemWrappers = wrappers;
+	}
+
+	/**
+	 * 设置确定性模式 - 由 Schedules 调用
+	 * 开启后系统严格按注册顺序执行，显式的 before/after 约束与注册顺序矛盾时编译报错
+	 * @param enabled - 是否开启
+	 */
+	public setDeterministic(enabled: boolean): void {
+		this.assertNotCompiled("Cannot change deterministic mode after compilation");
+		this.deterministic = enabled;
+	}
+
 	/**
 	 * 获取调度器标识符
 	 * @returns 调度阶段标识符
@@ -105,10 +132,67 @@ export class Schedule {
 
 		this.systems.set(systemId, internalSystem);
 		this.systemsByFunction.set(config.system, systemId);
+		this.registrationOrder.push(systemId);
 
 		return systemId;
 	}
 
+	/**
+	 * 检查调度中是否包含指定系统
+	 * @param systemId - 系统标识符
+	 * @returns 是否包含该系统
+	 */
+	public hasSystem(systemId: string): boolean {
+		return this.systems.has(systemId);
+	}
+
+	/**
+	 * 替换系统实现
+	 * 新系统沿用旧系统的标识符、系统集、运行条件和排序位置，
+	 * 其他系统中引用旧系统函数的 before/after 关系会指向新系统。
+	 * 编译前后均可调用，编译后替换在下一次运行时生效。
+	 * @param systemId - 被替换系统的标识符
+	 * @param newSystem - 新的系统函数
+	 * @returns 被替换的系统函数
+	 */
+	public replaceSystem(systemId: string, newSystem: SystemFunction): SystemFunction {
+		const existing = this.systems.get(systemId);
+		if (!existing) {
+			error(`System "${systemId}" does not exist in schedule "${this.label}"`);
+		}
+
+		if (this.systemsByFunction.has(newSystem)) {
+			error(`System "${this.getFunctionName(newSystem)}" has already been added to schedule "${this.label}"`);
+		}
+
+		const oldSystem = existing.system;
+		this.systems.set(systemId, { ...existing, system: newSystem });
+		this.systemsByFunction.delete(oldSystem);
+		this.systemsByFunction.set(newSystem, systemId);
+
+		// 更新其他系统中对旧系统函数的排序引用
+		const replaceReference = (target: SystemFunction | SystemSet) => (target === oldSystem ? newSystem : target);
+		for (const [otherId, other] of this.systems) {
+			const referencesOld =
+				(other.before?.includes(oldSystem) ?? false) || (other.after?.includes(oldSystem) ?? false);
+			if (referencesOld) {
+				this.systems.set(otherId, {
+					...other,
+					before: other.before?.map(replaceReference),
+					after: other.after?.map(replaceReference),
+				});
+			}
+		}
+
+		const slot = this.runSlots.get(systemId);
+		if (this.compiled && slot !== undefined) {
+			const displayName = existing.name || this.getFunctionName(newSystem);
+			slot.run = this.applySystemWrappers(systemId, displayName, newSystem);
+		}
+
+		return oldSystem;
+	}
+
 	/**
 	 * 配置系统集
 	 * @param config - 系统集配置
@@ -142,7 +226,7 @@ export class Schedule {
 		this.detectCircularDependencies();
 
 		// 4. 生成执行顺序
-		const sortedSystems = this.topologicalSort();
+		const sortedSystems = this.deterministic ? this.declarationOrder() : this.topologicalSort();
 
 		// 5. 创建最终的 Loop 系统结构，并分配优先级确保执行顺序
 		const compiledSystems = sortedSystems.map((systemId, index) => {
@@ -185,6 +269,84 @@ export class Schedule {
 		};
 	}
 
+	/**
+	 * 按注册顺序获取系统
+	 * @returns 系统结构列表
+	 */
+	public getSystemsInRegistrationOrder(): InternalSystemStruct[] {
+		return this.registrationOrder.map((systemId) => this.systems.get(systemId)!);
+	}
+
+	/**
+	 * 获取注册时声明的排序约束
+	 * 直接使用 before/after/inSet 配置解析，调度编译前后都可以调用。
+	 * 系统之间的约束按系统注册顺序排列，之后是系统集之间的约束展开为集合成员之间的边；
+	 * 同一条边可能因多个配置而重复出现
+	 * @returns 排序约束边列表
+	 */
+	public getOrderingEdges(): OrderingEdge[] {
+		const systems = this.getSystemsInRegistrationOrder();
+
+		const membersBySet = new Map<SystemSet, string[]>();
+		const setOrder: SystemSet[] = [];
+		for (const system of systems) {
+			if (system.inSet !== undefined) {
+				let members = membersBySet.get(system.inSet);
+				if (members === undefined) {
+					members = [];
+					membersBySet.set(system.inSet, members);
+					setOrder.push(system.inSet);
+				}
+				members.push(system.id);
+			}
+		}
+
+		const resolve = (target: SystemFunction | SystemSet): ReadonlyArray<string> => {
+			if (typeIs(target, "function")) {
+				const systemId = this.systemsByFunction.get(target);
+				return systemId !== undefined ? [systemId] : [];
+			}
+			return membersBySet.get(target) ?? [];
+		};
+
+		const edges: OrderingEdge[] = [];
+		for (const system of systems) {
+			for (const target of system.after ?? []) {
+				for (const dependency of resolve(target)) {
+					edges.push({ from: dependency, to: system.id, kind: "after", viaSet: false });
+				}
+			}
+			for (const target of system.before ?? []) {
+				for (const dependent of resolve(target)) {
+					edges.push({ from: system.id, to: dependent, kind: "before", viaSet: false });
+				}
+			}
+		}
+
+		for (const setName of setOrder) {
+			const setConfig = this.systemSets.get(setName);
+			if (setConfig === undefined) {
+				continue;
+			}
+			const members = membersBySet.get(setName)!;
+			for (const afterSet of setConfig.after ?? []) {
+				for (const dependency of membersBySet.get(afterSet) ?? []) {
+					for (const member of members) {
+						edges.push({ from: dependency, to: member, kind: "after", viaSet: true });
+					}
+				}
+			}
+			for (const beforeSet of setConfig.before ?? []) {
+				for (const dependent of membersBySet.get(beforeSet) ?? []) {
+					for (const member of members) {
+						edges.push({ from: member, to: dependent, kind: "before", viaSet: true });
+					}
+				}
+			}
+		}
+		return edges;
+	}
+
 	/**
 	 * 获取执行统计
 	 * @returns 调度器执行统计信息
@@ -200,6 +362,8 @@ export class Schedule {
 		this.systems.clear();
 		this.systemSets.clear();
 		this.systemsByFunction.clear();
+		this.runSlots.clear();
+		this.registrationOrder.clear();
 		this.compiled = false;
 		this.compiledSystems = undefined;
 		this.nextSystemId = 1;
@@ -254,6 +418,19 @@ export class Schedule {
 	private enhanceLoopSystem(system: InternalSystemStruct, sortIndex?: number): BevySystem {
 		const originalSystem = system.system;
 
+		// 生成系统的显示名称 - 只使用系统名称或函数名
+		const systemDisplayName = system.name || this.getFunctionName(originalSystem);
+
+		// 没有包装器时槽位直接保存原始系统，执行时不经过任何包装；
+		// 槽位由闭包直接持有，执行时无需查表，replaceSystem 只替换其中的函数
+		const slot = {
+			run:
+				this.systemWrappers.size() > 0
+					? this.applySystemWrappers(system.id, systemDisplayName, originalSystem)
+					: originalSystem,
+		};
+		this.runSlots.set(system.id, slot);
+
 		// 包装系统函数以添加统计和错误处理
 		const wrappedSystem = (world: BevyWorld, context: Context): void => {
 			const startTime = os.clock();
@@ -265,7 +442,7 @@ export class Schedule {
 				}
 
 				// 执行原始系统
-				originalSystem(world, context);
+				slot.run(world, context);
 
 				// 更新统计
 				const executionTime = (os.clock() - startTime) * 1000;
@@ -282,9 +459,6 @@ export class Schedule {
 
 		const finalPriority = sortIndex !== undefined ? sortIndex : (system.priority ?? 0);
 
-		// 生成系统的显示名称 - 只使用系统名称或函数名
-		const systemDisplayName = system.name || this.getFunctionName(originalSystem);
-
 		// 判断是否是启动调度（只运行一次）
 		const isStartupSchedule = this.label === "PreStartup" || this.label === "Startup" || this.label === "PostStartup";
 
@@ -305,6 +479,21 @@ export class Schedule {
 		};
 	}
 
+	/**
+	 * 按顺序应用已注册的系统包装器
+	 * @param systemId - 系统标识符
+	 * @param name - 系统显示名称
+	 * @param system - 系统函数
+	 * @returns 包装后的系统函数
+	 */
+	private applySystemWrappers(systemId: string, name: string, system: SystemFunction): SystemFunction {
+		let runSystem = system;
+		for (const wrapper of this.systemWrappers) {
+			runSystem = wrapper(runSystem, { id: systemId, name, schedule: this.label });
+		}
+		return runSystem;
+	}
+
 	/**
 	 * 解析系统集依赖关系
 	 */
@@ -388,6 +577,18 @@ export class Schedule {
 
 		// 处理 before 关系
 		for (const [systemId, system] of this.systems) {
+			// 系统集 before 关系 - 目标集合中的所有系统依赖本系统
+			if (system.inSet !== undefined) {
+				const setConfig = this.systemSets.get(system.inSet);
+				if (setConfig?.before) {
+					for (const beforeSet of setConfig.before) {
+						for (const setSystemId of this.getSystemsInSet(beforeSet)) {
+							this.addDependencyToSystem(setSystemId, systemId);
+						}
+					}
+				}
+			}
+
 			if (system.before) {
 				for (const beforeTarget of system.before) {
 					if (typeIs(beforeTarget, "function")) {
@@ -540,6 +741,34 @@ export class Schedule {
 		return this.sortByPriority(result);
 	}
 
+	/**
+	 * 按注册顺序排列系统（确定性模式）
+	 * 注册顺序即执行顺序，因此每个依赖都必须先于依赖它的系统注册
+	 * @returns 按注册顺序排列的系统ID列表
+	 */
+	private declarationOrder(): Array<string> {
+		const orderIndex = new Map<string, number>();
+		for (let index = 0; index < this.registrationOrder.size(); index++) {
+			orderIndex.set(this.registrationOrder[index], index);
+		}
+
+		for (const systemId of this.registrationOrder) {
+			const system = this.systems.get(systemId)!;
+			for (const dependency of system.dependencies) {
+				if (orderIndex.get(dependency)! > orderIndex.get(systemId)!) {
+					const dependencySystem = this.systems.get(dependency)!;
+					error(
+						`Deterministic mode: system "${system.name || this.getFunctionName(system.system)}" must run after ` +
+							`"${dependencySystem.name || this.getFunctionName(dependencySystem.system)}", ` +
+							`which is registered later in schedule "${this.label}"`,
+					);
+				}
+			}
+		}
+
+		return [...this.registrationOrder];
+	}
+
 	/**
 	 * 按优先级排序系统
 	 * @param systemIds - 系统ID列表
@@ -13,6 +13,7 @@ import type {
 	ScheduleLabel,
 	SchedulerState,
 	ScheduleGraph,
+	SystemWrapper,
 } from "./types";
 import { BevySystem, Context } from "..";
 
@@ -31,6 +32,8 @@ export class Schedules {
 	private readonly context: Context;
 	private compiled = false;
 	private runningSchedules = new Set<ScheduleLabel>();
+	private readonly systemWrappers: SystemWrapper[] = [];
+	private deterministic = false;
 
 	/**
 	 * 创建调度器管理器
@@ -52,11 +55,36 @@ export class Schedules {
 		if (!schedule) {
 			schedule = new Schedule(label);
 			schedule.setContext(this.context);
+			schedule.setSystemWrappers(this.systemWrappers);
+			schedule.setDeterministic(this.deterministic);
 			this.schedules.set(label, schedule);
 		}
 		return schedule;
 	}
 
+	/**
+	 * 添加系统包装器
+	 * 编译时应用到所有调度中的每个系统
+	 * @param wrapper - 系统包装器
+	 */
+	public addSystemWrapper(wrapper: SystemWrapper): void {
+		this.assertNotCompiled("Cannot add system wrapper after compilation");
+		this.systemWrappers.push(wrapper);
+	}
+
+	/**
+	 * 设置确定性模式
+	 * 应用到所有已存在和之后创建的调度
+	 * @param enabled - 是否开启
+	 */
+	public setDeterministic(enabled: boolean): void {
+		this.assertNotCompiled("Cannot change deterministic mode after compilation");
+		this.deterministic = enabled;
+		for (const [, schedule] of this.schedules) {
+			schedule.setDeterministic(enabled);
+		}
+	}
+
 	/**
 	 * 检查是否存在指定的调度器
 	 * @param label - 调度阶段标识符
@@ -100,6 +128,26 @@ export class Schedules {
 		return configs.map((config) => schedule.addSystem(config));
 	}
 
+	/**
+	 * 替换系统实现
+	 * 与添加系统不同，编译后仍可调用
+	 * @param systemId - 被替换系统的标识符
+	 * @param newSystem - 新的系统函数
+	 * @returns 被替换系统所在的调度标签和旧系统函数
+	 */
+	public replaceSystem(
+		systemId: string,
+		newSystem: SystemFunction,
+	): { schedule: ScheduleLabel; oldSystem: SystemFunction } {
+		for (const [label, schedule] of this.schedules) {
+			if (schedule.hasSystem(systemId)) {
+				return { schedule: label, oldSystem: schedule.replaceSystem(systemId, newSystem) };
+			}
+		}
+
+		error(`System "${systemId}" does not exist in any schedule`);
+	}
+
 	/**
 	 * 配置系统集
 	 * @param scheduleLabel - 调度阶段标识符
@@ -108,4 +108,20 @@ export function before(systemFn: SystemFunction, target: SystemFunction | string
  */
 export function inSet(systemFn: SystemFunction, set: string): SystemConfigs {
 	return new SystemConfigs(systemFn).inSet(set);
+}
+
+/**
+ * 创建属于某个自定义阶段的系统
+ * 阶段由 App.addStage 声明，本质上是按顺序串联的系统集
+ *
+ * @param systemFn - 系统函数
+ * @param stage - 阶段标签
+ * @returns 配置好的系统
+ *
+ * @example
+ * app.addStage("Simulate");
+ * app.addSystems(Update, inStage(integrate, "Simulate"));
+ */
+export function inStage(systemFn: SystemFunction, stage: string): SystemConfigs {
+	return new SystemConfigs(systemFn).inSet(stage);
 }
\ No newline at end of file
@@ -23,6 +23,8 @@ interface SystemMetadata {
 	chained: boolean;
 	/** 可以与这些系统集并行执行（忽略冲突） */
 	ambiguousWith: SystemSet[];
+	/** 是否要求在主线程执行 */
+	singleThread: boolean;
 }
 
 /**
@@ -46,6 +48,7 @@ export class SystemConfigs {
 			after: [],
 			chained: false,
 			ambiguousWith: [],
+			singleThread: false,
 		};
 	}
 
@@ -97,6 +100,14 @@ export class SystemConfigs {
 		return this;
 	}
 
+	/**
+	 * 要求在主线程执行
+	 */
+	singleThread(): SystemConfigs {
+		this.metadata.singleThread = true;
+		return this;
+	}
+
 	/**
 	 * 允许与所有系统并行执行（忽略所有冲突）
 	 */
@@ -302,6 +313,10 @@ export class SystemConfigs {
 			result.inSet = this.metadata.inSets[0]; // 暂时只支持一个
 		}
 
+		if (this.metadata.singleThread) {
+			result.singleThread = true;
+		}
+
 		// 应用顺序依赖（如果不跳过）
 		if (!skipOrdering && this.metadata.before.size() > 0) {
 			result.before = [...(result.before || []), ...this.metadata.before];
@@ -347,6 +362,10 @@ export class SystemConfigs {
 			};
 		}
 
+		if (this.metadata.singleThread) {
+			result = { ...result, singleThread: true };
+		}
+
 		return result;
 	}
 
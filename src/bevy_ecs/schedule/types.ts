@@ -16,6 +16,24 @@ export type SystemFunction = (world: BevyWorld, context: Context) => void;
  */
 export type RunCondition = (world: BevyWorld) => boolean;
 
+/**
+ * 系统包装器信息
+ */
+export interface SystemWrapperInfo {
+	/** 系统唯一标识 */
+	readonly id: string;
+	/** 系统显示名称 */
+	readonly name: string;
+	/** 所属调度阶段 */
+	readonly schedule: ScheduleLabel;
+}
+
+/**
+ * 系统包装器 - 在调度编译时包装系统函数
+ * 用于性能分析等横切功能，未注册包装器时系统不会被额外包装
+ */
+export type SystemWrapper = (system: SystemFunction, info: SystemWrapperInfo) => SystemFunction;
+
 /**
  * 系统集标识符
  */
@@ -46,6 +64,8 @@ export interface SystemConfig {
 	readonly priority?: number;
 	/** 是否为排他性系统 */
 	readonly exclusive?: boolean;
+	/** 是否要求在主线程执行（forceSingleThread） */
+	readonly singleThread?: boolean;
 }
 
 /**
@@ -100,6 +120,21 @@ export interface ScheduleGraph {
 	readonly dependencies: ReadonlyMap<string, Array<string>>;
 }
 
+/**
+ * 排序约束边
+ * 方向与执行顺序一致：from 必须在 to 之前执行
+ */
+export interface OrderingEdge {
+	/** 先执行的系统 ID */
+	readonly from: string;
+	/** 后执行的系统 ID */
+	readonly to: string;
+	/** 约束来源：after 或 before 配置 */
+	readonly kind: "before" | "after";
+	/** 约束是否来自系统集之间的配置 */
+	readonly viaSet: boolean;
+}
+
 /**
  * 调度器执行统计
  */
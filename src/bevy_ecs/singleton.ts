@@ -0,0 +1,95 @@
+/**
+ * @fileoverview 单例实体
+ * 某些概念天然只有一个实例，但又需要作为实体存在以便挂载子实体和组件，例如玩家角色和当前摄像机
+ *
+ * 单例由一个标记组件标识：同一时间最多只有一个实体拥有该标记组件。
+ * spawnSingleton 在标记已被占用时返回 SingletonError，而不是生成第二个实体。
+ *
+ * @example
+ * ```typescript
+ * const ActiveCamera = component("ActiveCamera");
+ * spawnSingleton(world, ActiveCamera, CameraRig({ fov: 70 }));
+ * const camera = singleEntity(world, ActiveCamera);
+ * ```
+ */
+
+import type { AnyComponent, AnyEntity } from "@rbxts/matter";
+import type { World } from "./bevy-world";
+import type { ComponentCtor } from "./query";
+
+/**
+ * 单例错误类型
+ */
+export enum SingletonErrorKind {
+	/** 已存在拥有该标记组件的实体 */
+	AlreadyExists = "AlreadyExists",
+}
+
+/**
+ * 单例错误
+ */
+export class SingletonError {
+	public name = "SingletonError";
+
+	/**
+	 * 创建单例错误
+	 * @param kind - 错误类型
+	 * @param message - 错误信息
+	 * @param existing - 已存在的单例实体
+	 */
+	constructor(
+		public readonly kind: SingletonErrorKind,
+		public readonly message: string,
+		public readonly existing: AnyEntity,
+	) {}
+
+	/**
+	 * 将错误转换为字符串
+	 * @returns 错误信息字符串
+	 */
+	toString(): string {
+		return `${this.name}(${this.kind}): ${this.message}`;
+	}
+}
+
+/**
+ * 查找单例实体
+ * @param world - 游戏世界
+ * @param marker - 标记组件
+ * @returns 拥有标记组件的实体，不存在时返回 undefined
+ */
+export function singleEntity(world: World, marker: ComponentCtor): AnyEntity | undefined {
+	for (const [entity] of world.query(marker)) {
+		return entity;
+	}
+	return undefined;
+}
+
+/**
+ * 生成单例实体
+ * components 中没有标记组件实例时自动添加 marker()
+ * @param world - 游戏世界
+ * @param marker - 标记组件
+ * @param components - 实体的其他组件
+ * @returns 新实体；已存在拥有标记组件的实体时返回 SingletonError
+ */
+export function spawnSingleton(
+	world: World,
+	marker: ComponentCtor,
+	...components: AnyComponent[]
+): AnyEntity | SingletonError {
+	const existing = singleEntity(world, marker);
+	if (existing !== undefined) {
+		return new SingletonError(
+			SingletonErrorKind.AlreadyExists,
+			`Singleton "${tostring(marker)}" already exists as entity ${existing}`,
+			existing,
+		);
+	}
+
+	const bundle = [...components];
+	if (!bundle.some((component) => getmetatable(component) === marker)) {
+		bundle.push(marker() as AnyComponent);
+	}
+	return world.spawn(...bundle);
+}
@@ -0,0 +1,276 @@
+/**
+ * @fileoverview World 快照
+ * 将 World 中已注册的组件序列化为快照，用于存档/读档与调试
+ *
+ * 只有通过 SnapshotRegistry.register 注册的组件类型会被序列化。
+ * 快照保留实体 ID，恢复时使用相同的 ID 重新生成实体，因此组件中保存的实体引用仍然有效。
+ * 恢复会让 World 与快照一致：快照外的实体被销毁，快照中实体上未被捕获的已注册组件被移除。
+ * 组件数据需要是 JSON 兼容的值（数字、字符串、布尔值及其组成的表）。
+ */
+
+import { HttpService } from "@rbxts/services";
+import type { AnyComponent, AnyEntity } from "@rbxts/matter";
+import type { World } from "./bevy-world";
+import type { Resource } from "./resource";
+import type { ComponentCtor } from "./query";
+
+/**
+ * 快照格式版本
+ */
+export const SNAPSHOT_FORMAT_VERSION = 1;
+
+/**
+ * 组件快照
+ */
+export interface ComponentSnapshot {
+	/** 组件注册名称 */
+	readonly name: string;
+	/** 组件数据 */
+	readonly data: Record<string, unknown>;
+}
+
+/**
+ * 实体快照
+ */
+export interface EntitySnapshot {
+	/** 实体 ID */
+	readonly id: number;
+	/** 组件列表，按名称排序 */
+	readonly components: ReadonlyArray<ComponentSnapshot>;
+}
+
+/**
+ * 快照组件注册表
+ * 记录可以被序列化的组件类型及其名称
+ */
+export class SnapshotRegistry implements Resource {
+	readonly __brand = "Resource" as const;
+	private readonly ctorsByName = new Map<string, ComponentCtor>();
+	private readonly namesByCtor = new Map<ComponentCtor, string>();
+
+	/**
+	 * 注册组件类型
+	 * @param component - 组件构造函数
+	 * @param name - 序列化使用的名称，默认使用组件名
+	 */
+	register(component: ComponentCtor, name: string = tostring(component)): void {
+		const existing = this.ctorsByName.get(name);
+		if (existing !== undefined && existing !== component) {
+			error(`Snapshot component name "${name}" is already registered by another component`);
+		}
+
+		this.ctorsByName.set(name, component);
+		this.namesByCtor.set(component, name);
+	}
+
+	/**
+	 * 检查组件类型是否已注册
+	 * @param component - 组件构造函数
+	 */
+	isRegistered(component: ComponentCtor): boolean {
+		return this.namesByCtor.has(component);
+	}
+
+	/**
+	 * 通过名称获取组件构造函数
+	 * @param name - 组件注册名称
+	 */
+	getComponent(name: string): ComponentCtor | undefined {
+		return this.ctorsByName.get(name);
+	}
+
+	/**
+	 * 获取所有已注册的组件，按名称排序
+	 * @returns [名称, 构造函数] 列表
+	 */
+	entries(): Array<[string, ComponentCtor]> {
+		const entries: Array<[string, ComponentCtor]> = [];
+		for (const [name, component] of this.ctorsByName) {
+			entries.push([name, component]);
+		}
+		entries.sort((a, b) => a[0] < b[0]);
+		return entries;
+	}
+}
+
+/**
+ * 深拷贝 JSON 兼容的值
+ * @param value - 要复制的值
+ */
+function copyValue(value: unknown): unknown {
+	if (!typeIs(value, "table")) {
+		return value;
+	}
+
+	const result: Record<string, unknown> = {};
+	for (const [key, field] of pairs(value as Record<string, unknown>)) {
+		result[key] = copyValue(field);
+	}
+	return result;
+}
+
+/**
+ * 以稳定的键顺序编码 JSON
+ * HttpService.JSONEncode 输出的对象键顺序不确定，这里对键排序以保证相同的快照总是得到相同的文本
+ * @param value - 要编码的值
+ */
+export function encodeStableJson(value: unknown): string {
+	if (!typeIs(value, "table")) {
+		return HttpService.JSONEncode(value);
+	}
+
+	const table = value as Record<string | number, unknown>;
+	const size = (value as Array<unknown>).size();
+	if (size > 0 || next(table)[0] === undefined) {
+		const items: string[] = [];
+		for (let index = 0; index < size; index++) {
+			items.push(encodeStableJson((value as Array<unknown>)[index]));
+		}
+		return `[${items.join(",")}]`;
+	}
+
+	const keys: string[] = [];
+	for (const [key] of pairs(table)) {
+		keys.push(tostring(key));
+	}
+	keys.sort((a, b) => a < b);
+
+	const fields = keys.map((key) => `${HttpService.JSONEncode(key)}:${encodeStableJson(table[key])}`);
+	return `{${fields.join(",")}}`;
+}
+
+/**
+ * World 快照
+ *
+ * @example
+ * ```typescript
+ * const registry = new SnapshotRegistry();
+ * registry.register(Position);
+ * const snapshot = WorldSnapshot.capture(world, registry);
+ * WorldSnapshot.restore(world, snapshot);
+ * ```
+ */
+export class WorldSnapshot {
+	/**
+	 * 创建快照
+	 * @param entities - 实体快照列表
+	 * @param registry - 捕获快照时使用的注册表，恢复时用于查找组件构造函数
+	 * @param version - 快照格式版本
+	 */
+	private constructor(
+		public readonly entities: ReadonlyArray<EntitySnapshot>,
+		private readonly registry: SnapshotRegistry,
+		public readonly version: number = SNAPSHOT_FORMAT_VERSION,
+	) {}
+
+	/**
+	 * 捕获 World 中已注册组件的快照
+	 * @param world - 要捕获的世界
+	 * @param registry - 快照组件注册表
+	 * @returns World 快照
+	 */
+	static capture(world: World, registry: SnapshotRegistry): WorldSnapshot {
+		const componentsByEntity = new Map<number, ComponentSnapshot[]>();
+
+		for (const [name, component] of registry.entries()) {
+			for (const [entity, instance] of world.query(component)) {
+				const id = entity as number;
+				let components = componentsByEntity.get(id);
+				if (!components) {
+					components = [];
+					componentsByEntity.set(id, components);
+				}
+				components.push({ name, data: copyValue(instance) as Record<string, unknown> });
+			}
+		}
+
+		const entities: EntitySnapshot[] = [];
+		for (const [id, components] of componentsByEntity) {
+			entities.push({ id, components });
+		}
+		entities.sort((a, b) => a.id < b.id);
+
+		return new WorldSnapshot(entities, registry);
+	}
+
+	/**
+	 * 将快照恢复到 World
+	 * 快照外的实体会被销毁；快照中的实体不存在时以相同 ID 生成，已存在时移除未被捕获的已注册组件，
+	 * 并覆盖快照中包含的组件。未注册的组件不受影响
+	 * @param world - 目标世界
+	 * @param snapshot - World 快照
+	 */
+	static restore(world: World, snapshot: WorldSnapshot): void {
+		const snapshotIds = new Set<number>();
+		for (const entitySnapshot of snapshot.entities) {
+			snapshotIds.add(entitySnapshot.id);
+		}
+
+		const staleEntities: AnyEntity[] = [];
+		for (const [entity] of world) {
+			if (!snapshotIds.has(entity as number)) {
+				staleEntities.push(entity);
+			}
+		}
+		for (const entity of staleEntities) {
+			world.despawn(entity);
+		}
+
+		const registered = snapshot.registry.entries();
+		for (const entitySnapshot of snapshot.entities) {
+			const entity = entitySnapshot.id as AnyEntity;
+			const components: AnyComponent[] = [];
+			const captured = new Set<string>();
+
+			for (const componentSnapshot of entitySnapshot.components) {
+				captured.add(componentSnapshot.name);
+				const component = snapshot.registry.getComponent(componentSnapshot.name);
+				if (component === undefined) {
+					warn(`[WorldSnapshot] Component "${componentSnapshot.name}" is not registered, skipping`);
+					continue;
+				}
+
+				const create = component as unknown as (data: object) => AnyComponent;
+				components.push(create(copyValue(componentSnapshot.data) as object));
+			}
+
+			if (!world.contains(entity)) {
+				world.spawnAt(entity);
+			} else {
+				for (const [name, component] of registered) {
+					if (!captured.has(name) && world.get(entity, component) !== undefined) {
+						world.remove(entity, component);
+					}
+				}
+			}
+
+			if (components.size() > 0) {
+				world.insert(entity, ...components);
+			}
+		}
+	}
+
+	/**
+	 * 从 JSON 文本解析快照
+	 * @param json - toJson 生成的 JSON 文本
+	 * @param registry - 快照组件注册表
+	 * @returns World 快照
+	 */
+	static fromJson(json: string, registry: SnapshotRegistry): WorldSnapshot {
+		const decoded = HttpService.JSONDecode(json) as { version: number; entities: EntitySnapshot[] };
+		if (decoded.version !== SNAPSHOT_FORMAT_VERSION) {
+			error(`Unsupported snapshot format version ${decoded.version}`);
+		}
+
+		return new WorldSnapshot(decoded.entities, registry, decoded.version);
+	}
+
+	/**
+	 * 序列化为 JSON 文本
+	 * 同一份快照总是生成相同的文本
+	 * @returns JSON 文本
+	 */
+	toJson(): string {
+		return encodeStableJson({ version: this.version, entities: this.entities });
+	}
+}
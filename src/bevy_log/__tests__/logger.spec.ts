@@ -0,0 +1,67 @@
+/**
+ * 日志后端选择单元测试
+ */
+
+/// <reference types="@rbxts/testez/globals" />
+
+import { HttpService } from "@rbxts/services";
+import { App } from "../../bevy_app/app";
+import { info, LogPlugin, logWarn } from "../lib";
+import { Level } from "../level";
+import { LoggerFormat } from "../logger";
+import { LogSubscriber } from "../roblox-tracing";
+
+export = () => {
+	describe("withLogger", () => {
+		afterEach(() => {
+			LogSubscriber.clearGlobal();
+		});
+
+		it("JSON 模式输出的每一行应包含 timestamp、level、target 和 message", () => {
+			const lines: string[] = [];
+			App.create().withLogger({
+				format: LoggerFormat.Json,
+				sink: (_level, line) => lines.push(line),
+			});
+
+			info("Player joined", "network", new Map<string, unknown>([["userId", 42]]));
+
+			expect(lines.size()).to.equal(1);
+			const entry = HttpService.JSONDecode(lines[0]) as {
+				timestamp: string;
+				level: string;
+				target: string;
+				message: string;
+				fields: { userId: number };
+			};
+			expect(entry.level).to.equal("INFO");
+			expect(entry.target).to.equal("network");
+			expect(entry.message).to.equal("Player joined");
+			expect(entry.timestamp.match("^%d+%-%d+%-%d+T")[0]).to.be.ok();
+			expect(entry.fields.userId).to.equal(42);
+		});
+
+		it("应按级别过滤并且不被之后添加的 LogPlugin 覆盖", () => {
+			const lines: string[] = [];
+			const app = App.create().withLogger({
+				format: LoggerFormat.Json,
+				level: Level.WARN,
+				sink: (_level, line) => lines.push(line),
+			});
+			app.addPlugin(new LogPlugin());
+
+			info("filtered out");
+			logWarn("kept");
+
+			expect(lines.size()).to.equal(1);
+			expect((HttpService.JSONDecode(lines[0]) as { message: string }).message).to.equal("kept");
+		});
+
+		it("Custom 模式应安装提供的订阅器", () => {
+			const subscriber = new LogSubscriber();
+			App.create().withLogger({ format: LoggerFormat.Custom, subscriber });
+
+			expect(LogSubscriber.getGlobal()).to.equal(subscriber);
+		});
+	});
+};
@@ -36,6 +36,10 @@
 // 导出核心插件
 export { LogPlugin, type LogPluginConfig } from "./lib";
 
+// 导出日志后端选择
+export { LoggerFormat, JsonLayer, createLoggerSubscriber, installLogger } from "./logger";
+export type { LoggerConfig, LogSink } from "./logger";
+
 // 导出日志级别
 export { Level } from "./level";
 
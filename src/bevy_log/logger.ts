@@ -0,0 +1,165 @@
+/**
+ * 日志后端选择
+ * 在 App 构建插件之前安装全局日志订阅器，可选择控制台格式、JSON 结构化格式或自定义订阅器
+ *
+ * 对应 Rust 中在 App 构建前调用 tracing_subscriber::fmt().json().init() 的做法。
+ * 已安装的订阅器不会被 LogPlugin 覆盖，因此插件构建期间的早期日志也会使用选定的格式。
+ */
+
+import { HttpService } from "@rbxts/services";
+import { Level, levelToString } from "./level";
+import { DEFAULT_FILTER, EnvFilter } from "./filter";
+import { Layer, LogRecord, LogSubscriber, RobloxLayer } from "./roblox-tracing";
+
+/**
+ * 日志输出格式
+ */
+export enum LoggerFormat {
+	/** 带时间戳和级别的可读控制台格式 */
+	Pretty = "Pretty",
+	/** 每条日志一行 JSON，用于日志聚合 */
+	Json = "Json",
+	/** 使用调用方提供的订阅器 */
+	Custom = "Custom",
+}
+
+/**
+ * 日志输出函数
+ * @param level - 日志级别
+ * @param line - 格式化后的一行日志
+ */
+export type LogSink = (level: Level, line: string) => void;
+
+/**
+ * 日志后端配置
+ */
+export interface LoggerConfig {
+	/** 输出格式 */
+	readonly format: LoggerFormat;
+	/** 最低日志级别，默认 INFO */
+	readonly level?: Level;
+	/** EnvFilter 格式的过滤器，默认 DEFAULT_FILTER */
+	readonly filter?: string;
+	/** JSON 格式的输出函数，默认 ERROR/WARN 使用 warn，其他使用 print */
+	readonly sink?: LogSink;
+	/** Custom 格式使用的订阅器 */
+	readonly subscriber?: LogSubscriber;
+}
+
+/**
+ * 默认的日志输出函数
+ * @param level - 日志级别
+ * @param line - 日志行
+ */
+function consoleSink(level: Level, line: string): void {
+	if (level === Level.ERROR || level === Level.WARN) {
+		warn(line);
+	} else {
+		print(line);
+	}
+}
+
+/**
+ * 将字段值转换为 JSON 兼容的值
+ * @param value - 字段值
+ */
+function toJsonValue(value: unknown): unknown {
+	if (typeIs(value, "number") || typeIs(value, "string") || typeIs(value, "boolean")) {
+		return value;
+	}
+	return tostring(value);
+}
+
+/**
+ * JSON 日志层
+ * 每条日志输出一行 JSON，包含 timestamp、level、target、message 以及可选的 fields
+ *
+ * @example
+ * ```typescript
+ * subscriber.addLayer(new JsonLayer(new EnvFilter("info")));
+ * // {"level":"INFO","message":"Player joined","target":"network","timestamp":"2024-01-01T12:00:00Z"}
+ * ```
+ */
+export class JsonLayer implements Layer {
+	/**
+	 * 创建 JSON 日志层
+	 * @param filter - 环境过滤器
+	 * @param sink - 输出函数
+	 */
+	constructor(
+		private readonly filter: EnvFilter,
+		private readonly sink: LogSink = consoleSink,
+	) {}
+
+	/**
+	 * 将日志记录编码为 JSON 并输出
+	 * @param record - 日志记录
+	 */
+	onEvent(record: LogRecord): void {
+		if (!this.filter.isEnabled(record.level, record.module)) {
+			return;
+		}
+
+		const entry: Record<string, unknown> = {
+			timestamp: os.date("!%Y-%m-%dT%H:%M:%SZ", record.timestamp),
+			level: levelToString(record.level),
+			target: record.module ?? "app",
+			message: record.message,
+		};
+
+		if (record.fields && record.fields.size() > 0) {
+			const fields: Record<string, unknown> = {};
+			record.fields.forEach((value, key) => {
+				fields[key] = toJsonValue(value);
+			});
+			entry.fields = fields;
+		}
+
+		this.sink(record.level, HttpService.JSONEncode(entry));
+	}
+
+	/**
+	 * 获取层名称
+	 * @returns 层标识名称 "JsonLayer"
+	 */
+	name(): string {
+		return "JsonLayer";
+	}
+}
+
+/**
+ * 根据配置创建日志订阅器
+ * @param config - 日志后端配置
+ * @returns 日志订阅器
+ */
+export function createLoggerSubscriber(config: LoggerConfig): LogSubscriber {
+	if (config.format === LoggerFormat.Custom) {
+		assert(config.subscriber, "LoggerFormat.Custom requires a subscriber");
+		return config.subscriber;
+	}
+
+	const level = config.level ?? Level.INFO;
+	const levelString = Level[level] as keyof typeof Level;
+	const filter = EnvFilter.tryFromDefaultEnv(`${levelString.lower()},${config.filter ?? DEFAULT_FILTER}`);
+
+	const subscriber = new LogSubscriber();
+	if (config.format === LoggerFormat.Json) {
+		subscriber.addLayer(new JsonLayer(filter, config.sink));
+	} else {
+		subscriber.addLayer(new RobloxLayer(filter));
+	}
+	return subscriber;
+}
+
+/**
+ * 安装全局日志订阅器
+ * 替换已存在的全局订阅器
+ * @param config - 日志后端配置
+ * @returns 安装的订阅器
+ */
+export function installLogger(config: LoggerConfig): LogSubscriber {
+	const subscriber = createLoggerSubscriber(config);
+	LogSubscriber.clearGlobal();
+	LogSubscriber.setGlobalDefault(subscriber);
+	return subscriber;
+}
@@ -0,0 +1,105 @@
+/**
+ * loading-phase.spec.ts - 加载阶段测试
+ */
+
+import { App } from "../../bevy_app/app";
+import { EnumStates } from "../states";
+import { State } from "../resources";
+import { initState } from "../app-impl";
+import { addLoadingPhase, addLoadTask, LoadingProgress } from "../loading-phase";
+
+/**
+ * 测试状态枚举
+ */
+class BootState extends EnumStates {
+	public static readonly Loading = new BootState("Loading");
+	public static readonly Ready = new BootState("Ready");
+}
+
+export = () => {
+	describe("LoadingPhase", () => {
+		let app: App;
+
+		/**
+		 * 获取当前状态
+		 */
+		function currentState(): BootState {
+			return app.world().world.resources.getResource<State<BootState>>()!.get();
+		}
+
+		beforeEach(() => {
+			app = App.create();
+			initState(app, () => BootState.Loading);
+			addLoadingPhase(app, BootState.Loading, BootState.Ready);
+		});
+
+		it("所有任务完成后进度应达到总数并切换状态", () => {
+			const log: string[] = [];
+			addLoadTask(app, "config", () => {
+				log.push("config");
+			});
+			addLoadTask(app, "assets", () => {
+				log.push("assets");
+			});
+
+			app.update();
+			const progress = app.getResource<LoadingProgress>()!;
+			expect(progress.total).to.equal(2);
+			expect(progress.completed).to.equal(2);
+			expect(progress.currentTask).to.equal(undefined);
+			expect(log.join(",")).to.equal("config,assets");
+
+			app.update();
+			expect(currentState()).to.equal(BootState.Ready);
+		});
+
+		it("异步任务未结束前不应开始后续任务也不应切换状态", () => {
+			let resolveAssets: (() => void) | undefined;
+			let audioStarted = false;
+			addLoadTask(app, "assets", () => {
+				return new Promise<void>((resolve) => {
+					resolveAssets = resolve;
+				});
+			});
+			addLoadTask(app, "audio", () => {
+				audioStarted = true;
+			});
+
+			app.update();
+			app.update();
+			const progress = app.getResource<LoadingProgress>()!;
+			expect(progress.completed).to.equal(0);
+			expect(progress.currentTask).to.equal("assets");
+			expect(progress.fraction()).to.equal(0);
+			expect(audioStarted).to.equal(false);
+			expect(currentState()).to.equal(BootState.Loading);
+
+			resolveAssets!();
+			app.update();
+			app.update();
+			expect(progress.completed).to.equal(2);
+			expect(audioStarted).to.equal(true);
+			expect(currentState()).to.equal(BootState.Ready);
+		});
+
+		it("任务失败时应记录错误并停止切换", () => {
+			let laterRan = false;
+			addLoadTask(app, "broken", () => {
+				error("missing asset");
+			});
+			addLoadTask(app, "later", () => {
+				laterRan = true;
+			});
+
+			app.update();
+			app.update();
+
+			const progress = app.getResource<LoadingProgress>()!;
+			expect(progress.error).to.be.ok();
+			expect(progress.error!.find("broken", 1, true)[0]).to.be.ok();
+			expect(progress.isComplete()).to.equal(false);
+			expect(laterRan).to.equal(false);
+			expect(currentState()).to.equal(BootState.Loading);
+		});
+	});
+};
@@ -0,0 +1,156 @@
+/**
+ * state-systems.spec.ts - 状态系统注册与状态栈测试
+ */
+
+import { App } from "../../bevy_app/app";
+import { EnumStates } from "../states";
+import { NextState, State } from "../resources";
+import { addStateSystems, initState } from "../app-impl";
+import { getStateStack, popState, pushState } from "../state-stack";
+import { addStateTransition, StateReachability } from "../state-reachability";
+
+/**
+ * 测试状态枚举
+ */
+class GameState extends EnumStates {
+	public static readonly Menu = new GameState("Menu");
+	public static readonly InGame = new GameState("InGame");
+	public static readonly Paused = new GameState("Paused");
+}
+
+export = () => {
+	describe("addStateSystems", () => {
+		let app: App;
+
+		beforeEach(() => {
+			app = App.create();
+		});
+
+		it("两个状态之间转换时应依次执行 enter/update/exit 系统", () => {
+			const log: string[] = [];
+
+			initState(app, () => GameState.Menu);
+			addStateSystems(
+				app,
+				GameState.Menu,
+				() => log.push("enter Menu"),
+				() => log.push("update Menu"),
+				() => log.push("exit Menu"),
+			);
+			addStateSystems(
+				app,
+				GameState.InGame,
+				() => log.push("enter InGame"),
+				() => log.push("update InGame"),
+				() => log.push("exit InGame"),
+			);
+
+			app.update();
+			expect(log.size()).to.equal(2);
+			expect(log[0]).to.equal("enter Menu");
+			expect(log[1]).to.equal("update Menu");
+
+			const resources = app.world().world.resources;
+			resources.getResource<NextState<GameState>>()!.set(GameState.InGame);
+			log.clear();
+			app.update();
+
+			expect(log.size()).to.equal(3);
+			expect(log[0]).to.equal("exit Menu");
+			expect(log[1]).to.equal("enter InGame");
+			expect(log[2]).to.equal("update InGame");
+		});
+
+		it("状态类型未初始化时 update 系统不应执行", () => {
+			let updateCount = 0;
+
+			addStateSystems(app, GameState.Menu, undefined, () => {
+				updateCount++;
+			});
+
+			app.update();
+			app.update();
+
+			expect(updateCount).to.equal(0);
+		});
+
+		it("状态类型未初始化时 finish 应警告一次", () => {
+			addStateSystems(app, GameState.Menu, undefined, () => {});
+			addStateSystems(app, GameState.InGame, undefined, () => {});
+
+			app.finish();
+
+			const warnings = app.getResource<StateReachability>()!.getWarnings();
+			expect(warnings.size()).to.equal(1);
+			expect(warnings[0].find("never initialized", 1, true)[0]).to.be.ok();
+			expect(warnings[0].find("InGame", 1, true)[0]).to.be.ok();
+		});
+
+		it("既不是初始状态也无法经由声明的转换到达的状态应被警告", () => {
+			initState(app, () => GameState.Menu);
+			addStateTransition(app, GameState.Menu, GameState.InGame);
+			addStateSystems(app, GameState.Menu, undefined, () => {});
+			addStateSystems(app, GameState.InGame, undefined, () => {});
+			addStateSystems(app, GameState.Paused, undefined, () => {});
+
+			app.finish();
+
+			const warnings = app.getResource<StateReachability>()!.getWarnings();
+			expect(warnings.size()).to.equal(1);
+			expect(warnings[0].find("Paused", 1, true)[0]).to.be.ok();
+		});
+	});
+
+	describe("StateStack", () => {
+		let app: App;
+
+		beforeEach(() => {
+			app = App.create();
+			initState(app, () => GameState.InGame);
+			app.update();
+		});
+
+		it("pushState 应切换状态并记录之前的状态", () => {
+			const world = app.world().world;
+
+			pushState(world, GameState.Paused);
+			app.update();
+
+			const state = world.resources.getResource<State<GameState>>();
+			expect(state!.get().equals(GameState.Paused)).to.equal(true);
+
+			const stack = getStateStack<GameState>(world);
+			expect(stack).to.be.ok();
+			expect(stack!.depth()).to.equal(1);
+			expect(stack!.peek()!.equals(GameState.InGame)).to.equal(true);
+		});
+
+		it("popState 应恢复 push 之前的状态", () => {
+			const world = app.world().world;
+
+			pushState(world, GameState.Paused);
+			app.update();
+			pushState(world, GameState.Menu);
+			app.update();
+
+			const restored = popState<GameState>(world);
+			app.update();
+			expect(restored!.equals(GameState.Paused)).to.equal(true);
+			expect(world.resources.getResource<State<GameState>>()!.get().equals(GameState.Paused)).to.equal(true);
+
+			popState<GameState>(world);
+			app.update();
+			expect(world.resources.getResource<State<GameState>>()!.get().equals(GameState.InGame)).to.equal(true);
+			expect(getStateStack<GameState>(world)!.isEmpty()).to.equal(true);
+		});
+
+		it("栈为空时 popState 应返回 undefined 且不改变状态", () => {
+			const world = app.world().world;
+
+			expect(popState<GameState>(world)).to.equal(undefined);
+			app.update();
+
+			expect(world.resources.getResource<State<GameState>>()!.get().equals(GameState.InGame)).to.equal(true);
+		});
+	});
+};
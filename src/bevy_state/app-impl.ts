@@ -13,6 +13,12 @@ import { FreelyMutableState, State } from "./resources";
 import { ComputedStates } from "./computed-states";
 import { SubStates } from "./sub-states";
 import { StatesPlugin, ComputedStatesPlugin, SubStatesPlugin } from "./plugin";
+import { addTransitionSystem, OnEnter, OnExit, StateTransitionSystem } from "./transitions";
+import { inState } from "./condition";
+import { getStateReachability } from "./state-reachability";
+import { BuiltinSchedules } from "../bevy_app/main-schedule";
+import { intoSystemConfigs } from "../bevy_ecs/schedule/system-configs";
+import type { SystemFunction } from "../bevy_ecs/schedule/types";
 
 /**
  * 初始化状态，使用默认值
@@ -55,6 +61,7 @@ export function initState<S extends FreelyMutableState>(
 	);
 
 	app.addPlugin(plugin);
+	getStateReachability(app).recordInitial(typeDescriptor, defaultState());
 	return app;
 }
 
@@ -83,6 +90,8 @@ export function insertState<S extends FreelyMutableState>(
 	const resourceManager = app.world().world.resources;
 	const existingState = resourceManager.getResourceByTypeDescriptor<State<S>>(typeDescriptor);
 
+	getStateReachability(app).recordInitial(typeDescriptor, state);
+
 	if (existingState) {
 		const stateResource = State.create(state, id, text);
 		resourceManager.insertResourceByTypeDescriptor(stateResource, typeDescriptor);
@@ -190,3 +199,59 @@ export function addSubState<TParent extends States, TSub extends SubStates<TPare
 	app.addPlugin(plugin);
 	return app;
 }
+
+/**
+ * 一次性注册某个状态的进入、更新和退出系统
+ *
+ * **用途**: 等价于分别向 OnEnter(state)、带 inState 运行条件的 Update、OnExit(state) 添加系统
+ *
+ * 注册了 onUpdate 时会在 App.finish（tryBuild）阶段检查状态是否可达，以下情况输出一次警告：
+ * - 状态类型从未通过 initState/insertState 初始化
+ * - 状态既不是初始状态，也不是通过 addStateTransition 或 addLoadingPhase 声明的转换能到达的状态
+ *
+ * @param app - App 实例
+ * @param state - 目标状态
+ * @param onEnter - 进入状态时执行的系统（可选）
+ * @param onUpdate - 处于该状态时每帧在 Update 中执行的系统（可选）
+ * @param onExit - 退出状态时执行的系统（可选）
+ * @param id - 状态类型标识符（由宏自动提供）
+ * @param text - 状态类型文本描述（由宏自动提供）
+ * @returns App 实例，支持链式调用
+ */
+export function addStateSystems<S extends FreelyMutableState>(
+	app: App,
+	state: S,
+	onEnter?: StateTransitionSystem,
+	onUpdate?: SystemFunction,
+	onExit?: StateTransitionSystem,
+	id?: Modding.Generic<S, "id">,
+	text?: Modding.Generic<S, "text">,
+): App {
+	const typeDescriptor = getTypeDescriptor(id, text);
+	assert(
+		typeDescriptor,
+		"Failed to get TypeDescriptor for state: type descriptor is required for state systems",
+	);
+
+	const world = app.world().world;
+
+	if (onEnter) {
+		addTransitionSystem(world, OnEnter(state), onEnter);
+	}
+
+	if (onExit) {
+		addTransitionSystem(world, OnExit(state), onExit);
+	}
+
+	if (onUpdate) {
+		getStateReachability(app).recordUpdateState(typeDescriptor, state);
+		const isInState = inState(typeDescriptor, state);
+
+		app.addSystems(
+			BuiltinSchedules.UPDATE,
+			intoSystemConfigs(onUpdate).runIf((worldParam) => isInState(worldParam, worldParam.resources)),
+		);
+	}
+
+	return app;
+}
@@ -197,4 +197,5 @@ export {
 	insertState as appInsertState,
 	addComputedState as appAddComputedState,
 	addSubState as appAddSubState,
+	addStateSystems as appAddStateSystems,
 } from "./app-impl";
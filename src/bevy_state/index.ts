@@ -43,6 +43,8 @@ export {
 	ExitSchedules,
 	TransitionSchedules,
 	StateTransitionManager,
+	StateTransitionSystem,
+	addTransitionSystem,
 	lastTransition,
 	getStateTransitionReader,
 } from "./transitions";
@@ -107,7 +109,25 @@ export {
 } from "./state-scoped";
 
 // App 扩展导出
-export { AppExtStates, appInitState, appInsertState, appAddComputedState, appAddSubState } from "./app";
+export {
+	AppExtStates,
+	appInitState,
+	appInsertState,
+	appAddComputedState,
+	appAddSubState,
+	appAddStateSystems,
+} from "./app";
+
+export { StateStack, getStateStack, pushState, popState } from "./state-stack";
+
+export {
+	StateReachability,
+	StateReachabilityPlugin,
+	getStateReachability,
+	addStateTransition,
+} from "./state-reachability";
+
+export { LoadTask, LoadingProgress, LoadingPhase, addLoadTask, addLoadingPhase } from "./loading-phase";
 
 // Prelude 导出
 export * as prelude from "./prelude";
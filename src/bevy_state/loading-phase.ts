@@ -0,0 +1,252 @@
+/**
+ * loading-phase.ts - 加载阶段
+ *
+ * 为需要大量初始化工作的应用提供统一的加载阶段：
+ * 插件通过 addLoadTask 登记加载任务，框架在加载状态中按登记顺序逐个执行，
+ * 并通过 LoadingProgress 资源报告进度，供进度条等界面读取。
+ * 所有任务完成后才请求切换到就绪状态；任务失败时记录错误并停止切换。
+ *
+ * 任务可以是同步函数，也可以返回 Promise。返回 Promise 的任务在 Promise 结束之前保持为当前任务，
+ * 之后的任务要等它完成才会开始；同一帧内会连续执行所有已经完成的任务。
+ */
+
+import { Modding } from "@flamework/core";
+import { getGenericTypeDescriptor, getTypeDescriptor } from "../bevy_core";
+import { App } from "../bevy_app/app";
+import { BuiltinSchedules } from "../bevy_app/main-schedule";
+import { World } from "../bevy_ecs/bevy-world";
+import type { Resource } from "../bevy_ecs/resource";
+import { intoSystemConfigs } from "../bevy_ecs/schedule/system-configs";
+import { FreelyMutableState, NextState } from "./resources";
+import { inState } from "./condition";
+import { getStateReachability } from "./state-reachability";
+
+/**
+ * 加载任务
+ * 同步任务返回后即视为完成；返回 Promise 时在 Promise 成功结束后完成。
+ * 抛出错误或 Promise 被拒绝时任务失败
+ */
+export type LoadTask = (world: World) => Promise<unknown> | void;
+
+/**
+ * 加载进度资源
+ *
+ * **用途**: 报告加载阶段的进度，由 addLoadTask 或 addLoadingPhase 自动插入
+ */
+export class LoadingProgress implements Resource {
+	readonly __brand = "Resource" as const;
+	/** 已完成的任务数量 */
+	public completed = 0;
+	/** 任务总数 */
+	public total = 0;
+	/** 正在执行的任务名称，没有任务在执行时为 undefined */
+	public currentTask?: string;
+	/** 失败的原因，出现错误后加载停止，不会切换到就绪状态 */
+	public error?: string;
+
+	/**
+	 * 获取完成比例
+	 *
+	 * @returns 0 到 1 之间的比例，没有任务时返回 1
+	 */
+	public fraction(): number {
+		return this.total === 0 ? 1 : this.completed / this.total;
+	}
+
+	/**
+	 * 检查所有任务是否都已完成
+	 *
+	 * @returns 全部完成且没有错误时返回 true
+	 */
+	public isComplete(): boolean {
+		return this.error === undefined && this.completed >= this.total;
+	}
+}
+
+/**
+ * 已登记的加载任务
+ */
+interface LoadTaskEntry {
+	readonly name: string;
+	readonly task: LoadTask;
+}
+
+/**
+ * 加载阶段资源
+ *
+ * **用途**: 按登记顺序保存加载任务并逐个执行，由 addLoadTask 或 addLoadingPhase 自动插入
+ */
+export class LoadingPhase implements Resource {
+	readonly __brand = "Resource" as const;
+	private readonly tasks: LoadTaskEntry[] = [];
+	private cursor = 0;
+	private running?: Promise<unknown>;
+
+	/**
+	 * 创建加载阶段
+	 *
+	 * @param progress - 进度资源，执行任务时同步更新
+	 */
+	constructor(private readonly progress: LoadingProgress) {}
+
+	/**
+	 * 登记加载任务
+	 *
+	 * @param name - 任务名称，显示在 LoadingProgress.currentTask 中
+	 * @param task - 任务函数
+	 */
+	public addTask(name: string, task: LoadTask): void {
+		this.tasks.push({ name, task });
+		this.progress.total = this.tasks.size();
+	}
+
+	/**
+	 * 执行加载任务
+	 *
+	 * **用途**: 从当前任务开始连续执行，直到遇到尚未结束的 Promise、任务失败或全部完成
+	 *
+	 * @param world - 游戏世界实例
+	 * @returns 所有任务都已完成时返回 true
+	 */
+	public poll(world: World): boolean {
+		const progress = this.progress;
+		if (progress.error !== undefined) {
+			return false;
+		}
+
+		while (this.cursor < this.tasks.size()) {
+			const entry = this.tasks[this.cursor];
+			progress.currentTask = entry.name;
+
+			if (this.running === undefined) {
+				const [success, result] = pcall(() => entry.task(world));
+				if (!success) {
+					this.fail(entry, result);
+					return false;
+				}
+				if (Promise.is(result)) {
+					this.running = result as Promise<unknown>;
+				}
+			}
+
+			if (this.running !== undefined) {
+				if (this.running.getStatus() === Promise.Status.Started) {
+					return false;
+				}
+
+				// 已结束的 Promise 调用 await 会立即返回
+				const [success, result] = this.running.await();
+				this.running = undefined;
+				if (!success) {
+					this.fail(entry, result);
+					return false;
+				}
+			}
+
+			this.cursor++;
+			progress.completed = this.cursor;
+		}
+
+		progress.currentTask = undefined;
+		return true;
+	}
+
+	/**
+	 * 记录任务失败
+	 *
+	 * @param entry - 失败的任务
+	 * @param reason - 失败原因
+	 */
+	private fail(entry: LoadTaskEntry, reason: unknown): void {
+		this.progress.error = `Load task "${entry.name}" failed: ${tostring(reason)}`;
+		warn(`[bevy_state] ${this.progress.error}`);
+	}
+}
+
+/**
+ * 获取或插入加载阶段资源
+ *
+ * @param app - App 实例
+ * @returns 加载阶段资源
+ */
+function getOrInsertLoadingPhase(app: App): LoadingPhase {
+	let phase = app.getResource<LoadingPhase>();
+	if (phase === undefined) {
+		const progress = new LoadingProgress();
+		phase = new LoadingPhase(progress);
+		app.insertResource(progress);
+		app.insertResource(phase);
+	}
+	return phase;
+}
+
+/**
+ * 登记加载任务
+ *
+ * **用途**: 供插件在 build 中登记初始化工作，任务在加载状态中按登记顺序执行
+ *
+ * @param app - App 实例
+ * @param name - 任务名称
+ * @param task - 任务函数
+ * @returns App 实例，支持链式调用
+ */
+export function addLoadTask(app: App, name: string, task: LoadTask): App {
+	getOrInsertLoadingPhase(app).addTask(name, task);
+	return app;
+}
+
+/**
+ * 添加加载阶段
+ *
+ * **用途**: 处于 loadingState 时每帧在 Update 中执行加载任务，全部完成后通过 NextState 请求切换到 readyState。
+ * 状态类型需要通过 initState/insertState 初始化，通常以 loadingState 作为初始状态
+ *
+ * **注意**: 此方法是宏文件，所有 Modding.* 类型参数不需要主动提供
+ *
+ * @metadata macro
+ * @param app - App 实例
+ * @param loadingState - 执行加载任务的状态
+ * @param readyState - 加载完成后切换到的状态
+ * @param id - 状态类型的唯一标识符（由宏自动提供）
+ * @param text - 状态类型的文本描述（由宏自动提供）
+ * @returns App 实例，支持链式调用
+ *
+ * @example
+ * ```typescript
+ * initState(app, () => AppState.Loading);
+ * addLoadingPhase(app, AppState.Loading, AppState.MainMenu);
+ * addLoadTask(app, "textures", () => preloadTextures());
+ * ```
+ */
+export function addLoadingPhase<S extends FreelyMutableState>(
+	app: App,
+	loadingState: S,
+	readyState: S,
+	id?: Modding.Generic<S, "id">,
+	text?: Modding.Generic<S, "text">,
+): App {
+	const typeDescriptor = getTypeDescriptor(id, text);
+	assert(typeDescriptor, "Failed to get TypeDescriptor for state: type descriptor is required for loading phase");
+
+	const phase = getOrInsertLoadingPhase(app);
+	getStateReachability(app).recordTransition(typeDescriptor, loadingState, readyState);
+	const isLoading = inState(typeDescriptor, loadingState);
+	const nextStateTypeDescriptor = getGenericTypeDescriptor<NextState<S>>(typeDescriptor);
+
+	app.addSystems(
+		BuiltinSchedules.UPDATE,
+		intoSystemConfigs((world: World) => {
+			if (!phase.poll(world)) {
+				return;
+			}
+
+			const nextState = world.resources.getResourceByTypeDescriptor<NextState<S>>(nextStateTypeDescriptor);
+			if (nextState === undefined) {
+				warn(`[bevy_state] State ${typeDescriptor.text} is not initialized. Loading phase cannot transition.`);
+				return;
+			}
+			nextState.set(readyState);
+		}).runIf((world) => isLoading(world, world.resources)),
+	);
+	return app;
+}
@@ -0,0 +1,250 @@
+/**
+ * state-reachability.ts - 状态可达性检查
+ *
+ * addStateSystems 为某个状态注册了 onUpdate 系统时，在 App.finish（tryBuild）阶段检查该状态能否被进入：
+ * - 状态类型从未通过 initState/insertState 初始化时，该类型的所有 onUpdate 都不会执行
+ * - 状态既不是初始状态，也无法经由声明的转换（addStateTransition、addLoadingPhase）从初始状态到达时，
+ *   该状态的 onUpdate 不会执行
+ *
+ * 两种情况都只输出一次警告。只在运行时通过 NextState.set 进入的状态应使用 addStateTransition 声明转换，
+ * 否则同样会收到警告。
+ */
+
+import { Modding } from "@flamework/core";
+import { getTypeDescriptor, TypeDescriptor } from "../bevy_core";
+import type { App } from "../bevy_app/app";
+import { BasePlugin } from "../bevy_app/plugin";
+import type { Resource } from "../bevy_ecs/resource";
+import type { States } from "./states";
+
+/**
+ * 单个状态类型的可达性记录
+ */
+interface StateTypeRecord {
+	readonly text: string;
+	/** 通过 initState/insertState 设置的初始状态 */
+	initial?: States;
+	/** 声明的转换 */
+	readonly transitions: Array<{ readonly from: States; readonly to: States }>;
+	/** 注册了 onUpdate 的状态 */
+	readonly updateStates: States[];
+}
+
+/**
+ * 检查状态是否在列表中
+ * @param list - 状态列表
+ * @param state - 目标状态
+ */
+function containsState(list: ReadonlyArray<States>, state: States): boolean {
+	return list.some((other) => other.equals(state));
+}
+
+/**
+ * 状态可达性资源
+ *
+ * **用途**: 记录各状态类型的初始状态、声明的转换和注册了 onUpdate 的状态，由 StateReachabilityPlugin 在 finish 时检查
+ */
+export class StateReachability implements Resource {
+	readonly __brand = "Resource" as const;
+	private readonly records = new Map<string, StateTypeRecord>();
+	private readonly warnings: string[] = [];
+	private validated = false;
+
+	/**
+	 * 记录初始状态
+	 *
+	 * @param descriptor - 状态类型描述符
+	 * @param state - 初始状态
+	 */
+	public recordInitial(descriptor: TypeDescriptor, state: States): void {
+		this.getRecord(descriptor).initial = state;
+	}
+
+	/**
+	 * 记录声明的转换
+	 *
+	 * @param descriptor - 状态类型描述符
+	 * @param from - 源状态
+	 * @param to - 目标状态
+	 */
+	public recordTransition(descriptor: TypeDescriptor, from: States, to: States): void {
+		this.getRecord(descriptor).transitions.push({ from, to });
+	}
+
+	/**
+	 * 记录注册了 onUpdate 的状态
+	 *
+	 * @param descriptor - 状态类型描述符
+	 * @param state - 状态
+	 */
+	public recordUpdateState(descriptor: TypeDescriptor, state: States): void {
+		const record = this.getRecord(descriptor);
+		if (!containsState(record.updateStates, state)) {
+			record.updateStates.push(state);
+		}
+	}
+
+	/**
+	 * 检查所有注册了 onUpdate 的状态是否可达
+	 *
+	 * **用途**: 对每个问题输出警告，重复调用时不会再次检查
+	 *
+	 * @returns 本次检查产生的警告
+	 */
+	public validate(): ReadonlyArray<string> {
+		if (this.validated) {
+			return [];
+		}
+		this.validated = true;
+
+		const produced: string[] = [];
+		for (const [, record] of this.records) {
+			if (record.updateStates.size() === 0) {
+				continue;
+			}
+
+			const initial = record.initial;
+			if (initial === undefined) {
+				const names = record.updateStates.map((state) => tostring(state.getStateId())).join(", ");
+				produced.push(
+					`State ${record.text} is never initialized with initState/insertState, update systems for ${names} will never run.`,
+				);
+				continue;
+			}
+
+			const reachable = this.collectReachable(record, initial);
+			for (const state of record.updateStates) {
+				if (!containsState(reachable, state)) {
+					produced.push(
+						`State ${record.text}::${tostring(state.getStateId())} is neither the initial state nor the target of any declared transition, its update system will never run.`,
+					);
+				}
+			}
+		}
+
+		for (const message of produced) {
+			warn(`[bevy_state] ${message}`);
+			this.warnings.push(message);
+		}
+		return produced;
+	}
+
+	/**
+	 * 获取已输出的警告
+	 *
+	 * @returns 警告列表，按输出顺序排列
+	 */
+	public getWarnings(): ReadonlyArray<string> {
+		return this.warnings;
+	}
+
+	/**
+	 * 从初始状态出发，沿声明的转换收集可达状态
+	 *
+	 * @param record - 状态类型记录
+	 * @param initial - 初始状态
+	 * @returns 可达状态列表
+	 */
+	private collectReachable(record: StateTypeRecord, initial: States): States[] {
+		const reachable: States[] = [initial];
+		for (let index = 0; index < reachable.size(); index++) {
+			const current = reachable[index];
+			for (const transition of record.transitions) {
+				if (transition.from.equals(current) && !containsState(reachable, transition.to)) {
+					reachable.push(transition.to);
+				}
+			}
+		}
+		return reachable;
+	}
+
+	/**
+	 * 获取或创建状态类型记录
+	 *
+	 * @param descriptor - 状态类型描述符
+	 */
+	private getRecord(descriptor: TypeDescriptor): StateTypeRecord {
+		let record = this.records.get(descriptor.id);
+		if (record === undefined) {
+			record = { text: descriptor.text, transitions: [], updateStates: [] };
+			this.records.set(descriptor.id, record);
+		}
+		return record;
+	}
+}
+
+/**
+ * 状态可达性检查插件
+ *
+ * **用途**: 在 finish 阶段调用 StateReachability.validate，由 getStateReachability 自动添加
+ */
+export class StateReachabilityPlugin extends BasePlugin {
+	/**
+	 * 创建状态可达性检查插件
+	 *
+	 * @param reachability - 状态可达性资源
+	 */
+	constructor(private readonly reachability: StateReachability) {
+		super();
+	}
+
+	build(_app: App): void {}
+
+	finish(_app: App): void {
+		this.reachability.validate();
+	}
+
+	name(): string {
+		return "StateReachabilityPlugin";
+	}
+}
+
+/**
+ * 获取或插入状态可达性资源
+ *
+ * @param app - App 实例
+ * @returns 状态可达性资源
+ */
+export function getStateReachability(app: App): StateReachability {
+	let reachability = app.getResource<StateReachability>();
+	if (reachability === undefined) {
+		reachability = new StateReachability();
+		app.insertResource(reachability);
+		app.addPlugin(new StateReachabilityPlugin(reachability));
+	}
+	return reachability;
+}
+
+/**
+ * 声明状态转换
+ *
+ * **用途**: 告知可达性检查 from 状态之后可能进入 to 状态，通常对应运行时某个系统中的 NextState.set(to)
+ *
+ * **注意**: 此方法是宏文件，所有 Modding.* 类型参数不需要主动提供
+ *
+ * @metadata macro
+ * @param app - App 实例
+ * @param from - 源状态
+ * @param to - 目标状态
+ * @param id - 状态类型标识符（由宏自动提供）
+ * @param text - 状态类型文本描述（由宏自动提供）
+ * @returns App 实例，支持链式调用
+ *
+ * @example
+ * ```typescript
+ * addStateTransition(app, GameState.Menu, GameState.InGame);
+ * ```
+ */
+export function addStateTransition<S extends States>(
+	app: App,
+	from: S,
+	to: S,
+	id?: Modding.Generic<S, "id">,
+	text?: Modding.Generic<S, "text">,
+): App {
+	const typeDescriptor = getTypeDescriptor(id, text);
+	assert(typeDescriptor, "Failed to get TypeDescriptor for state: type descriptor is required for state transitions");
+
+	getStateReachability(app).recordTransition(typeDescriptor, from, to);
+	return app;
+}
@@ -0,0 +1,221 @@
+/**
+ * state-stack.ts - 状态栈
+ *
+ * 提供可回退的状态切换：pushState 进入新状态时记录当前状态，popState 恢复上一个状态。
+ * 常用于暂停菜单、弹窗等需要返回之前状态的场景。
+ */
+
+import { Modding } from "@flamework/core";
+import { World } from "../bevy_ecs/bevy-world";
+import { getGenericTypeDescriptor, getTypeDescriptor, TypeDescriptor } from "../bevy_core";
+import { FreelyMutableState, NextState, State } from "./resources";
+
+/**
+ * 状态栈资源
+ *
+ * **用途**: 保存通过 pushState 离开的状态，栈顶为最近一次离开的状态
+ *
+ * @template S - 状态类型
+ */
+export class StateStack<S extends FreelyMutableState> {
+	private readonly stack: S[] = [];
+
+	/**
+	 * 私有构造函数 (外部调用使用 create())
+	 */
+	private constructor() {}
+
+	/**
+	 * 类型描述, 在 create() 时候添加.
+	 */
+	public typeDescriptor: TypeDescriptor = undefined as unknown as TypeDescriptor;
+
+	/**
+	 * 创建状态栈资源
+	 *
+	 * **注意**: @claudecode , @cursor ,  这是一个宏文件, 所有 Modding.* 的类型, 不要主动调用.
+	 * @metadata macro
+	 *
+	 * @returns StateStack 资源实例
+	 */
+	public static create<S extends FreelyMutableState>(
+		id?: Modding.Generic<S, "id">,
+		text?: Modding.Generic<S, "text">,
+	): StateStack<S> {
+		const typeDescriptor = getTypeDescriptor(id, text);
+		assert(typeDescriptor, "Failed to get TypeDescriptor for StateStack: type descriptor is required for state stack creation");
+		return StateStack.fromDescriptor<S>(typeDescriptor);
+	}
+
+	/**
+	 * 通过状态类型描述符创建状态栈资源
+	 *
+	 * @param typeDescriptor - 状态类型描述符
+	 * @returns StateStack 资源实例
+	 */
+	public static fromDescriptor<S extends FreelyMutableState>(typeDescriptor: TypeDescriptor): StateStack<S> {
+		const result = new StateStack<S>();
+		result.typeDescriptor = getGenericTypeDescriptor<StateStack<S>>(typeDescriptor);
+		return result;
+	}
+
+	/**
+	 * 压入状态
+	 *
+	 * @param state - 要保存的状态
+	 */
+	public push(state: S): void {
+		this.stack.push(state);
+	}
+
+	/**
+	 * 弹出栈顶状态
+	 *
+	 * @returns 栈顶状态，栈为空时返回 undefined
+	 */
+	public pop(): S | undefined {
+		return this.stack.pop();
+	}
+
+	/**
+	 * 查看栈顶状态
+	 *
+	 * @returns 栈顶状态，栈为空时返回 undefined
+	 */
+	public peek(): S | undefined {
+		return this.stack[this.stack.size() - 1];
+	}
+
+	/**
+	 * 获取栈深度
+	 *
+	 * @returns 栈中保存的状态数量
+	 */
+	public depth(): number {
+		return this.stack.size();
+	}
+
+	/**
+	 * 检查栈是否为空
+	 *
+	 * @returns 栈为空时返回 true
+	 */
+	public isEmpty(): boolean {
+		return this.stack.size() === 0;
+	}
+
+	/**
+	 * 清空状态栈
+	 */
+	public clear(): void {
+		this.stack.clear();
+	}
+}
+
+/**
+ * 获取 NextState<S> 资源
+ *
+ * @param world - 游戏世界实例
+ * @param typeDescriptor - 状态类型描述符
+ * @returns NextState 资源，不存在时返回 undefined
+ */
+function getNextState<S extends FreelyMutableState>(world: World, typeDescriptor: TypeDescriptor): NextState<S> | undefined {
+	const nextStateTypeDescriptor = getGenericTypeDescriptor<NextState<S>>(typeDescriptor);
+	return world.resources.getResourceByTypeDescriptor<NextState<S>>(nextStateTypeDescriptor);
+}
+
+/**
+ * 获取状态栈资源
+ *
+ * **注意**: 此方法是宏文件，所有 Modding.* 类型参数不需要主动提供
+ *
+ * @metadata macro
+ * @param world - 游戏世界实例
+ * @param id - 状态类型的唯一标识符（由宏自动提供）
+ * @param text - 状态类型的文本描述（由宏自动提供）
+ * @returns 状态栈资源，尚未压入过状态时返回 undefined
+ */
+export function getStateStack<S extends FreelyMutableState>(
+	world: World,
+	id?: Modding.Generic<S, "id">,
+	text?: Modding.Generic<S, "text">,
+): StateStack<S> | undefined {
+	const typeDescriptor = getTypeDescriptor(id, text);
+	assert(typeDescriptor, `Failed to get TypeDescriptor for state: ${tostring(text)}`);
+
+	const stackTypeDescriptor = getGenericTypeDescriptor<StateStack<S>>(typeDescriptor);
+	return world.resources.getResourceByTypeDescriptor<StateStack<S>>(stackTypeDescriptor);
+}
+
+/**
+ * 压入新状态
+ *
+ * **用途**: 将当前状态保存到 StateStack<S>，并通过 NextState<S> 请求切换到新状态
+ *
+ * **注意**: 此方法是宏文件，所有 Modding.* 类型参数不需要主动提供
+ *
+ * @metadata macro
+ * @param world - 游戏世界实例
+ * @param state - 目标状态实例
+ * @param id - 状态类型的唯一标识符（由宏自动提供）
+ * @param text - 状态类型的文本描述（由宏自动提供）
+ */
+export function pushState<S extends FreelyMutableState>(
+	world: World,
+	state: S,
+	id?: Modding.Generic<S, "id">,
+	text?: Modding.Generic<S, "text">,
+): void {
+	const typeDescriptor = getTypeDescriptor(id, text);
+	assert(typeDescriptor, `Failed to get TypeDescriptor for state: ${tostring(text)}`);
+
+	const currentState = world.resources.getResourceByTypeDescriptor<State<S>>(typeDescriptor);
+	const nextState = getNextState<S>(world, typeDescriptor);
+	if (currentState === undefined || nextState === undefined) {
+		warn(`[bevy_state] State ${typeDescriptor.text} is not initialized. pushState is ignored.`);
+		return;
+	}
+
+	const stackTypeDescriptor = getGenericTypeDescriptor<StateStack<S>>(typeDescriptor);
+	let stack = world.resources.getResourceByTypeDescriptor<StateStack<S>>(stackTypeDescriptor);
+	if (stack === undefined) {
+		stack = StateStack.fromDescriptor<S>(typeDescriptor);
+		world.resources.insertResourceByTypeDescriptor(stack, stackTypeDescriptor);
+	}
+
+	stack.push(currentState.get());
+	nextState.set(state);
+}
+
+/**
+ * 弹出并恢复上一个状态
+ *
+ * **用途**: 从 StateStack<S> 取出最近保存的状态，并通过 NextState<S> 请求切换回该状态
+ *
+ * **注意**: 此方法是宏文件，所有 Modding.* 类型参数不需要主动提供
+ *
+ * @metadata macro
+ * @param world - 游戏世界实例
+ * @param id - 状态类型的唯一标识符（由宏自动提供）
+ * @param text - 状态类型的文本描述（由宏自动提供）
+ * @returns 恢复的状态，栈为空时返回 undefined
+ */
+export function popState<S extends FreelyMutableState>(
+	world: World,
+	id?: Modding.Generic<S, "id">,
+	text?: Modding.Generic<S, "text">,
+): S | undefined {
+	const typeDescriptor = getTypeDescriptor(id, text);
+	assert(typeDescriptor, `Failed to get TypeDescriptor for state: ${tostring(text)}`);
+
+	const stackTypeDescriptor = getGenericTypeDescriptor<StateStack<S>>(typeDescriptor);
+	const stack = world.resources.getResourceByTypeDescriptor<StateStack<S>>(stackTypeDescriptor);
+	const nextState = getNextState<S>(world, typeDescriptor);
+	if (stack === undefined || stack.isEmpty() || nextState === undefined) {
+		return undefined;
+	}
+
+	const previous = stack.pop()!;
+	nextState.set(previous);
+	return previous;
+}
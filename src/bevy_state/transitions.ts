@@ -139,6 +139,35 @@ export function OnTransition<S extends States>(from: S, to: S): ScheduleLabel {
 	return `OnTransition_${from.getStateId()}_to_${to.getStateId()}` as ScheduleLabel;
 }
 
+/**
+ * 状态转换调度中的系统函数类型
+ *
+ * **用途**: OnEnter/OnExit/OnTransition 调度中的系统只接收 world 参数
+ */
+export type StateTransitionSystem = (world: World) => void;
+
+/**
+ * 向状态转换调度注册系统
+ *
+ * **用途**: 将系统存储到 StateTransitionManager 执行 OnEnter/OnExit/OnTransition 时读取的位置
+ *
+ * @param world - 游戏世界实例
+ * @param scheduleLabel - 调度标签，由 OnEnter/OnExit/OnTransition 生成
+ * @param system - 系统函数
+ */
+export function addTransitionSystem(world: World, scheduleLabel: ScheduleLabel, system: StateTransitionSystem): void {
+	const worldAsRecord = world as unknown as Record<string, unknown>;
+	const systemsKey = `systems_${scheduleLabel}`;
+	let systems = worldAsRecord[systemsKey] as Array<StateTransitionSystem> | undefined;
+
+	if (!typeIs(systems, "table")) {
+		systems = [];
+		worldAsRecord[systemsKey] = systems;
+	}
+
+	systems.push(system);
+}
+
 /**
  * 进入调度集合常量
  *
@@ -0,0 +1,67 @@
+/**
+ * runFixed 固定时间步运行测试
+ */
+
+import { component, World } from "@rbxts/matter";
+import { App } from "../../bevy_app/app";
+import { BuiltinSchedules } from "../../bevy_app";
+import { Duration, FixedTimeResource, TimePlugin, runFixed } from "../index";
+
+const Position = component<{ x: number }>("RunFixedPosition");
+const Velocity = component<{ x: number }>("RunFixedVelocity");
+
+export = () => {
+	describe("runFixed", () => {
+		it("匀速实体在 N 个固定步后的位置应恰好为 N * dt * velocity", () => {
+			const app = App.create().addPlugin(new TimePlugin());
+			const dt = Duration.fromMillis(125);
+			const velocity = 2;
+			const steps = 40;
+
+			app.addSystems(BuiltinSchedules.STARTUP, (world: World) => {
+				world.spawn(Position({ x: 0 }), Velocity({ x: velocity }));
+			});
+
+			app.addSystems(BuiltinSchedules.FIXED_UPDATE, (world: World) => {
+				const fixedDelta = app.getResource<FixedTimeResource>()!.value.getDeltaSecsF64();
+				for (const [entity, position, entityVelocity] of world.query(Position, Velocity)) {
+					world.insert(entity, Position({ x: position.x + entityVelocity.x * fixedDelta }));
+				}
+			});
+
+			runFixed(app, steps, dt);
+
+			let finalX: number | undefined;
+			for (const [, position] of app.world().world.query(Position)) {
+				finalX = position.x;
+			}
+
+			expect(finalX).to.equal(steps * dt.asSecsF64() * velocity);
+		});
+
+		it("每一步中 Time<Fixed> 的增量应恰好为 dt", () => {
+			const app = App.create().addPlugin(new TimePlugin());
+			const dt = Duration.fromMillis(20);
+			const observedDeltas: Duration[] = [];
+			let updateCount = 0;
+
+			app.addSystems(BuiltinSchedules.UPDATE, () => {
+				updateCount++;
+			});
+			app.addSystems(BuiltinSchedules.FIXED_UPDATE, () => {
+				observedDeltas.push(app.getResource<FixedTimeResource>()!.value.getDelta());
+			});
+
+			runFixed(app, 5, dt);
+
+			expect(updateCount).to.equal(5);
+			expect(observedDeltas.size()).to.equal(5);
+			for (const delta of observedDeltas) {
+				expect(delta.equals(dt)).to.equal(true);
+			}
+			expect(app.getResource<FixedTimeResource>()!.value.getElapsed().equals(Duration.fromMillis(100))).to.equal(
+				true,
+			);
+		});
+	});
+};
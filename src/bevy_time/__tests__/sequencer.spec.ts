@@ -0,0 +1,83 @@
+/**
+ * 序列调度器测试
+ */
+
+import { App } from "../../bevy_app/app";
+import { Duration, SeqStep, Sequencer, TimePlugin, addSequencer, runFixed } from "../index";
+import { World } from "../../bevy_ecs/bevy-world";
+
+export = () => {
+	describe("Sequencer", () => {
+		let app: App;
+		let sequencer: Sequencer;
+
+		beforeEach(() => {
+			app = App.create().addPlugin(new TimePlugin());
+			addSequencer(app);
+			sequencer = app.getResource<Sequencer>()!;
+		});
+
+		it("第二个动作应在等待结束后才执行", () => {
+			const fired: string[] = [];
+			const id = sequencer.play([
+				SeqStep.action(() => fired.push("first")),
+				SeqStep.wait(Duration.fromSecs(2)),
+				SeqStep.action(() => fired.push("second")),
+			]);
+
+			runFixed(app, 1, Duration.fromMillis(100));
+			expect(fired.join(",")).to.equal("first");
+
+			// 累计 1.9 秒
+			runFixed(app, 18, Duration.fromMillis(100));
+			expect(fired.join(",")).to.equal("first");
+			expect(sequencer.isPlaying(id)).to.equal(true);
+
+			// 累计 2 秒
+			runFixed(app, 1, Duration.fromMillis(100));
+			expect(fired.join(",")).to.equal("first,second");
+			expect(sequencer.isPlaying(id)).to.equal(false);
+		});
+
+		it("多个序列应各自独立推进", () => {
+			const fired: string[] = [];
+			sequencer.play([SeqStep.wait(Duration.fromSecs(1)), SeqStep.action(() => fired.push("slow"))]);
+			runFixed(app, 5, Duration.fromMillis(100));
+			sequencer.play([SeqStep.wait(Duration.fromMillis(200)), SeqStep.action(() => fired.push("fast"))]);
+
+			runFixed(app, 2, Duration.fromMillis(100));
+			expect(fired.join(",")).to.equal("fast");
+
+			runFixed(app, 3, Duration.fromMillis(100));
+			expect(fired.join(",")).to.equal("fast,slow");
+			expect(sequencer.activeCount()).to.equal(0);
+		});
+
+		it("等待结束后剩余的时间应计入后续等待", () => {
+			const standalone = new Sequencer();
+			const world = new World();
+			let fired = 0;
+			standalone.play([
+				SeqStep.wait(Duration.fromMillis(300)),
+				SeqStep.wait(Duration.fromMillis(300)),
+				SeqStep.action(() => fired++),
+			]);
+
+			standalone.advance(world, Duration.fromMillis(500));
+			expect(fired).to.equal(0);
+
+			standalone.advance(world, Duration.fromMillis(100));
+			expect(fired).to.equal(1);
+		});
+
+		it("停止的序列不应继续执行", () => {
+			let fired = 0;
+			const id = sequencer.play([SeqStep.wait(Duration.fromMillis(100)), SeqStep.action(() => fired++)]);
+
+			expect(sequencer.stop(id)).to.equal(true);
+			runFixed(app, 3, Duration.fromMillis(100));
+
+			expect(fired).to.equal(0);
+		});
+	});
+};
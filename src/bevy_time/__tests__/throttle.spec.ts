@@ -0,0 +1,69 @@
+/**
+ * 节流系统测试
+ */
+
+import { App } from "../../bevy_app/app";
+import { BuiltinSchedules } from "../../bevy_app";
+import { Duration, ThrottleTimers, TimePlugin, addThrottledSystem, runFixed } from "../index";
+
+export = () => {
+	describe("addThrottledSystem", () => {
+		it("固定步进下应按间隔运行", () => {
+			const app = App.create().addPlugin(new TimePlugin());
+			let runs = 0;
+
+			// 2 秒内每 500 毫秒运行一次
+			addThrottledSystem(
+				app,
+				BuiltinSchedules.FIXED_UPDATE,
+				() => {
+					runs++;
+				},
+				Duration.fromMillis(500),
+			);
+
+			runFixed(app, 20, Duration.fromMillis(100));
+
+			expect(runs).to.equal(4);
+		});
+
+		it("每个节流系统应有独立的计时器", () => {
+			const app = App.create().addPlugin(new TimePlugin());
+			let fastRuns = 0;
+			let slowRuns = 0;
+
+			addThrottledSystem(
+				app,
+				BuiltinSchedules.FIXED_UPDATE,
+				() => {
+					fastRuns++;
+				},
+				Duration.fromMillis(200),
+			);
+			addThrottledSystem(
+				app,
+				BuiltinSchedules.FIXED_UPDATE,
+				() => {
+					slowRuns++;
+				},
+				Duration.fromSecs(1),
+			);
+
+			runFixed(app, 30, Duration.fromMillis(100));
+
+			expect(fastRuns).to.equal(15);
+			expect(slowRuns).to.equal(3);
+		});
+
+		it("一帧内累计多个间隔时应只运行一次", () => {
+			const timers = new ThrottleTimers();
+			const system = () => {};
+			timers.register(system, Duration.fromMillis(100));
+
+			expect(timers.tick(system, Duration.fromMillis(50))).to.equal(false);
+			expect(timers.tick(system, Duration.fromMillis(1000))).to.equal(true);
+			expect(timers.getAccumulated(system)!.isZero()).to.equal(true);
+			expect(timers.tick(system, Duration.fromMillis(50))).to.equal(false);
+		});
+	});
+};
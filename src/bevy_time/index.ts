@@ -7,7 +7,9 @@
 export { Duration, durationRem } from "./duration";
 export { Time, type TimeContext, type Real, type Virtual, type Fixed, type Empty } from "./time";
 export { TimeFixed, runFixedMainSchedule } from "./fixed";
-export { TimePlugin, type TimeUpdateStrategy, advanceTime } from "./time-plugin";
+export { TimePlugin, type TimeUpdateStrategy, advanceTime, runFixed } from "./time-plugin";
+export { ThrottleTimers, addThrottledSystem } from "./throttle";
+export { Sequencer, SeqStep, addSequencer } from "./sequencer";
 export type { TimePluginExtension } from "./extension";
 export {
 	RealTimeResource,
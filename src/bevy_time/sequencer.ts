@@ -0,0 +1,186 @@
+/**
+ * 序列调度器
+ * 用于过场动画、补间等脚本化流程，以 "执行 X，等待 2 秒，执行 Y" 的步骤列表代替手写状态机
+ *
+ * 等待使用 Time 资源的增量计时，因此暂停、时间缩放和 runFixed 都会被正确考虑。
+ * 一帧内会连续执行到下一个未结束的等待为止；等待结束时本帧剩余的时间计入后续的等待，
+ * 因此多个等待的总时长与帧率无关。多个序列互不影响，各自推进。
+ */
+
+import type { App } from "../bevy_app/app";
+import { BuiltinSchedules } from "../bevy_app/main-schedule";
+import type { World } from "../bevy_ecs/bevy-world";
+import type { Resource } from "../bevy_ecs/resource";
+import type { ScheduleLabel } from "../bevy_ecs/schedule/types";
+import { Duration } from "./duration";
+import { GenericTimeResource } from "./time-resources";
+
+/**
+ * 序列步骤
+ */
+export type SeqStep =
+	| { readonly kind: "action"; readonly run: (world: World) => void }
+	| { readonly kind: "wait"; readonly duration: Duration };
+
+/**
+ * 序列步骤构造函数
+ */
+export const SeqStep = {
+	/**
+	 * 立即执行的动作
+	 * @param run - 动作回调
+	 */
+	action(run: (world: World) => void): SeqStep {
+		return { kind: "action", run };
+	},
+
+	/**
+	 * 等待一段时间
+	 * @param duration - 等待时长
+	 */
+	wait(duration: Duration): SeqStep {
+		return { kind: "wait", duration };
+	},
+};
+
+/**
+ * 正在播放的序列
+ */
+interface ActiveSequence {
+	readonly steps: ReadonlyArray<SeqStep>;
+	/** 当前步骤的下标 */
+	index: number;
+	/** 当前等待已经经过的时间 */
+	waited: Duration;
+}
+
+/**
+ * 序列调度器资源
+ * 由 addSequencer 插入，通过 play 启动序列
+ */
+export class Sequencer implements Resource {
+	readonly __brand = "Resource" as const;
+	private readonly sequences = new Map<number, ActiveSequence>();
+	private nextId = 1;
+
+	/**
+	 * 播放序列
+	 * 序列从下一次推进开始执行
+	 * @param steps - 步骤列表
+	 * @returns 序列 ID，可用于 stop 和 isPlaying
+	 */
+	play(steps: ReadonlyArray<SeqStep>): number {
+		const id = this.nextId++;
+		this.sequences.set(id, { steps: [...steps], index: 0, waited: Duration.ZERO });
+		return id;
+	}
+
+	/**
+	 * 停止序列，剩余步骤不再执行
+	 * @param id - 序列 ID
+	 * @returns 找到并移除了正在播放的序列时返回 true，序列不存在或已结束时返回 false
+	 */
+	stop(id: number): boolean {
+		return this.sequences.delete(id);
+	}
+
+	/**
+	 * 检查序列是否仍在播放
+	 * @param id - 序列 ID
+	 */
+	isPlaying(id: number): boolean {
+		return this.sequences.has(id);
+	}
+
+	/**
+	 * 获取正在播放的序列数量
+	 */
+	activeCount(): number {
+		return this.sequences.size();
+	}
+
+	/**
+	 * 推进所有正在播放的序列
+	 * 推进期间通过 play 启动的序列从下一次推进开始执行
+	 * @param world - 游戏世界
+	 * @param delta - 本帧的时间增量
+	 */
+	advance(world: World, delta: Duration): void {
+		const ids: number[] = [];
+		for (const [id] of this.sequences) {
+			ids.push(id);
+		}
+		ids.sort((a, b) => a < b);
+
+		for (const id of ids) {
+			const sequence = this.sequences.get(id);
+			if (sequence === undefined) {
+				continue;
+			}
+			if (this.advanceSequence(world, sequence, delta)) {
+				this.sequences.delete(id);
+			}
+		}
+	}
+
+	/**
+	 * 推进单个序列
+	 * @param world - 游戏世界
+	 * @param sequence - 序列
+	 * @param delta - 本帧的时间增量
+	 * @returns 序列是否已经结束
+	 */
+	private advanceSequence(world: World, sequence: ActiveSequence, delta: Duration): boolean {
+		let remaining = delta;
+		while (sequence.index < sequence.steps.size()) {
+			const step = sequence.steps[sequence.index];
+			if (step.kind === "action") {
+				sequence.index++;
+				step.run(world);
+				continue;
+			}
+
+			const waited = sequence.waited.add(remaining);
+			if (waited.lessThan(step.duration)) {
+				sequence.waited = waited;
+				return false;
+			}
+			remaining = waited.saturatingSub(step.duration);
+			sequence.waited = Duration.ZERO;
+			sequence.index++;
+		}
+		return true;
+	}
+}
+
+/**
+ * 添加序列调度器
+ * 插入 Sequencer 资源，并添加每帧用 Time 增量推进序列的系统。重复调用不会重复添加
+ * @param app - 应用程序实例（需要已添加 TimePlugin）
+ * @param schedule - 推进序列的调度，默认为 Update
+ * @returns 应用程序实例
+ *
+ * @example
+ * ```typescript
+ * addSequencer(app);
+ * app.getResource<Sequencer>()!.play([
+ *     SeqStep.action((world) => showTitle(world)),
+ *     SeqStep.wait(Duration.fromSecs(2)),
+ *     SeqStep.action((world) => hideTitle(world)),
+ * ]);
+ * ```
+ */
+export function addSequencer(app: App, schedule: ScheduleLabel = BuiltinSchedules.UPDATE): App {
+	if (app.getResource<Sequencer>() !== undefined) {
+		return app;
+	}
+
+	const sequencer = new Sequencer();
+	app.insertResource(sequencer);
+	return app.addSystems(schedule, (world) => {
+		const time = world.resources.getResource<GenericTimeResource>();
+		if (time !== undefined) {
+			sequencer.advance(world, time.value.getDelta());
+		}
+	});
+}
@@ -0,0 +1,114 @@
+/**
+ * 节流系统
+ * 让系统按时间间隔运行而不是每帧运行，例如网络同步和自动存档
+ *
+ * 计时使用 Time 资源的增量而不是墙钟时间：在普通调度中累加 Time<Virtual> 的增量，
+ * 在固定更新调度中累加 Time<Fixed> 的增量，因此暂停、时间缩放和 runFixed 都会被正确考虑。
+ */
+
+import type { App } from "../bevy_app/app";
+import type { Resource } from "../bevy_ecs/resource";
+import { intoSystemConfigs } from "../bevy_ecs/schedule/system-configs";
+import type { ScheduleLabel, SystemFunction } from "../bevy_ecs/schedule/types";
+import { Duration } from "./duration";
+import { GenericTimeResource } from "./time-resources";
+
+/**
+ * 单个节流系统的计时器
+ */
+interface ThrottleTimer {
+	readonly interval: Duration;
+	accumulated: Duration;
+}
+
+/**
+ * 节流计时器资源
+ * 按系统函数记录每个节流系统累计的时间，由 addThrottledSystem 自动插入
+ */
+export class ThrottleTimers implements Resource {
+	readonly __brand = "Resource" as const;
+	private readonly timers = new Map<SystemFunction, ThrottleTimer>();
+
+	/**
+	 * 注册节流系统
+	 * @param system - 系统函数
+	 * @param interval - 运行间隔
+	 */
+	register(system: SystemFunction, interval: Duration): void {
+		this.timers.set(system, { interval, accumulated: Duration.ZERO });
+	}
+
+	/**
+	 * 累加时间并检查系统本帧是否应该运行
+	 * 累计时间达到间隔时返回 true 并扣除一个间隔；
+	 * 一帧内最多运行一次，剩余时间不超过一个间隔，长时间卡顿后不会连续补跑
+	 * @param system - 系统函数
+	 * @param delta - 本帧的时间增量
+	 * @returns 是否应该运行
+	 */
+	tick(system: SystemFunction, delta: Duration): boolean {
+		const timer = this.timers.get(system);
+		if (timer === undefined) {
+			return true;
+		}
+
+		timer.accumulated = timer.accumulated.add(delta);
+		if (timer.accumulated.lessThan(timer.interval)) {
+			return false;
+		}
+
+		timer.accumulated = timer.accumulated.saturatingSub(timer.interval);
+		if (timer.accumulated.greaterThanOrEqual(timer.interval)) {
+			timer.accumulated = Duration.ZERO;
+		}
+		return true;
+	}
+
+	/**
+	 * 获取系统当前累计的时间
+	 * @param system - 系统函数
+	 * @returns 累计时间，系统未注册时返回 undefined
+	 */
+	getAccumulated(system: SystemFunction): Duration | undefined {
+		return this.timers.get(system)?.accumulated;
+	}
+}
+
+/**
+ * 添加节流系统
+ * 系统只在累计的 Time 增量达到 interval 时运行，运行后扣除一个间隔
+ * @param app - 应用程序实例（需要已添加 TimePlugin）
+ * @param schedule - 调度标签
+ * @param system - 系统函数
+ * @param interval - 运行间隔
+ * @returns 应用程序实例
+ *
+ * @example
+ * ```typescript
+ * addThrottledSystem(app, BuiltinSchedules.UPDATE, autosaveSystem, Duration.fromSecs(30));
+ * ```
+ */
+export function addThrottledSystem(
+	app: App,
+	schedule: ScheduleLabel,
+	system: SystemFunction,
+	interval: Duration,
+): App {
+	assert(!interval.isZero(), "addThrottledSystem: interval must be greater than zero");
+
+	let timers = app.getResource<ThrottleTimers>();
+	if (timers === undefined) {
+		timers = new ThrottleTimers();
+		app.insertResource(timers);
+	}
+	timers.register(system, interval);
+
+	const throttleTimers = timers;
+	return app.addSystems(
+		schedule,
+		intoSystemConfigs(system).runIf((world) => {
+			const time = world.resources.getResource<GenericTimeResource>();
+			return time !== undefined && throttleTimers.tick(system, time.value.getDelta());
+		}),
+	);
+}
@@ -446,6 +446,35 @@ export function advanceTime(app: App, seconds: number): void {
 	}
 }
 
+/**
+ * 以固定时间步运行应用（用于无头确定性模拟）
+ * 同步推进 steps 次，不进行任何实时等待。每一步 Real/Virtual 时间推进 dt，
+ * 先运行完整的主调度序列（首次调用时包含启动调度），再运行一次 FixedMain，
+ * 期间 Time<Fixed> 的增量恰好为 dt
+ * @param app - 应用程序实例（需要已添加 TimePlugin）
+ * @param steps - 推进的步数
+ * @param dt - 每步的固定时间增量
+ */
+export function runFixed(app: App, steps: number, dt: Duration): void {
+	const fixedTimeResource = app.getResource<FixedTimeResource>();
+	assert(fixedTimeResource, "runFixed requires TimePlugin to be added");
+	fixedTimeResource.value.setTimestep(dt);
+
+	for (let step = 0; step < steps; step++) {
+		advanceTime(app, dt.asSecsF64());
+		app.update();
+
+		const virtualTime = app.getResource<VirtualTimeResource>()!.value;
+		const fixedTime = app.getResource<FixedTimeResource>()!.value;
+
+		// 直接推进一个时间步而不经过累加器，避免浮点误差导致跳步或多步
+		fixedTime.advanceBy(dt);
+		app.insertResource(new GenericTimeResource(fixedTime.asGeneric()));
+		app.runSchedule(BuiltinSchedules.FIXED_MAIN);
+		app.insertResource(new GenericTimeResource(virtualTime.asGeneric()));
+	}
+}
+
 /**
  * 运行固定主循环系统
  * 对应 Rust run_fixed_main_schedule (fixed.rs:239-252)